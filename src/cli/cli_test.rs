@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::{Mode, Options};
+
+fn parse(args: &str) -> Result<Options, String> {
+    let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+    Options::parse(&args)
+}
+
+#[test]
+fn test_defaults() {
+    let options = parse("").unwrap();
+    assert_eq!(options, Options::default());
+    assert_eq!(options.mode, Mode::Run);
+    assert_eq!(options.iterations, 10);
+    assert_eq!(options.steps_per_second, 8);
+}
+
+#[test]
+fn test_commands_and_flags() {
+    let options = parse("headless -i 300 --width 30 --depth 12 --height 2 --level 1 --seed 42 --scenario a.json --save b.json --clear").unwrap();
+    assert_eq!(options.mode, Mode::Headless);
+    assert_eq!(options.iterations, 300);
+    assert_eq!(
+        (options.width, options.depth, options.height),
+        (Some(30), Some(12), Some(2))
+    );
+    assert_eq!(options.level, 1);
+    assert_eq!(options.seed, Some(42));
+    assert_eq!(options.scenario, Some("a.json".into()));
+    assert_eq!(options.save, Some("b.json".into()));
+    assert!(options.clear_on_exit);
+
+    let options = parse("run --record recording.json").unwrap();
+    assert_eq!(options.record, Some("recording.json".into()));
+
+    let options = parse("replay recording.json --speed 2").unwrap();
+    assert_eq!(options.mode, Mode::Replay);
+    assert_eq!(options.recording, Some("recording.json".into()));
+    assert_eq!(options.load, None);
+    assert_eq!(options.steps_per_second, 2);
+
+    assert!(parse("inspect --help").unwrap().help);
+}
+
+#[test]
+fn test_helpful_errors() {
+    assert!(parse("--iterations").unwrap_err().contains("Missing value"));
+    assert!(parse("--iterations many")
+        .unwrap_err()
+        .contains("expected a number"));
+    assert!(parse("--speed 0").unwrap_err().contains("bigger than 0"));
+    assert!(parse("--fly").unwrap_err().contains("Unknown option"));
+    assert!(parse("run somewhere").is_err());
+    assert!(parse("replay").is_err());
+    assert!(parse("replay recording.json --seed 3").is_err());
+    assert!(parse("inspect world.json --record recording.json").is_err());
+    assert!(parse("inspect").is_err());
+    assert!(parse("--scenario a.json --load b.json").is_err());
+    assert!(parse("--load b.json --width 3").is_err());
+    assert!(parse("--height 2 --level 2").is_err());
+    assert!(parse("headless --width 100000 --depth 100000")
+        .unwrap_err()
+        .contains("too large"));
+    assert!(parse("--width 4294967295 --depth 4294967295 --height 4294967295").is_err());
+}
@@ -0,0 +1,240 @@
+//! # Command Line Interface
+//!
+//! Parse and check the command line arguments, before the world is started.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+mod cli_test;
+
+/// Help text, shown on `--help` and on wrong arguments.
+pub const USAGE: &str = "\
+Usage: wusel [COMMAND] [OPTIONS]
+
+Commands:
+  run       Watch the wusels live in the terminal (default).
+  headless  Let the wusels live without rendering, print the world state per tick.
+  replay    Watch a recorded simulation again: replay <RECORDING>.
+  inspect   Print a summary of a world, without letting time pass: inspect <SAVE>.
+
+Options:
+  -i, --iterations <N>   Ticks to simulate (default: 10).
+  -s, --speed <N>        Ticks per second while rendering (default: 8).
+      --width <N>        Width of the world (overrides the scenario).
+      --depth <N>        Depth of the world (overrides the scenario).
+      --height <N>       Levels of the world (overrides the scenario).
+      --level <N>        Level to render, from 0 (ground) to height - 1 (default: 0).
+      --seed <N>         Seed for the world's randomness.
+      --scenario <PATH>  Start with the world described in the scenario file.
+      --load <PATH>      Start with a saved world.
+      --save <PATH>      Save the world, when the simulation ends.
+      --record <PATH>    Record the simulation, to watch it again with replay.
+      --clear            Clear the terminal on exit.
+  -h, --help             Show this help.";
+
+/// Default world width, if neither the scenario nor the options define it.
+pub const DEFAULT_WIDTH: u32 = 60;
+
+/// Default world depth, if neither the scenario nor the options define it.
+pub const DEFAULT_DEPTH: u32 = 20;
+
+/// What to do with the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Run,
+    Headless,
+    Replay,
+    Inspect,
+}
+
+/// All options given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub mode: Mode,
+    pub help: bool,
+
+    pub iterations: usize,
+    pub steps_per_second: u64,
+    pub clear_on_exit: bool,
+
+    pub width: Option<u32>,
+    pub depth: Option<u32>,
+    pub height: Option<u32>,
+    pub level: u32,
+    pub seed: Option<u64>,
+
+    pub scenario: Option<std::path::PathBuf>,
+    pub load: Option<std::path::PathBuf>,
+    pub save: Option<std::path::PathBuf>,
+    pub record: Option<std::path::PathBuf>,
+    pub recording: Option<std::path::PathBuf>, // to replay.
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            mode: Mode::Run,
+            help: false,
+            iterations: 10,
+            steps_per_second: 8,
+            clear_on_exit: false,
+            width: None,
+            depth: None,
+            height: None,
+            level: 0,
+            seed: None,
+            scenario: None,
+            load: None,
+            save: None,
+            record: None,
+            recording: None,
+        }
+    }
+}
+
+impl Options {
+    /// Parse the arguments (without the program name).
+    /// Returns a helpful message, if the arguments are wrong.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter().peekable();
+
+        // Optional command first.
+        if let Some(command) = args.peek() {
+            let mode = match command.as_str() {
+                "run" => Some(Mode::Run),
+                "headless" => Some(Mode::Headless),
+                "replay" => Some(Mode::Replay),
+                "inspect" => Some(Mode::Inspect),
+                _ => None,
+            };
+            if let Some(mode) = mode {
+                options.mode = mode;
+                args.next();
+            }
+        }
+
+        while let Some(arg) = args.next() {
+            let mut value_for = |flag: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("Missing value for {}.", flag))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => options.help = true,
+                "--clear" => options.clear_on_exit = true,
+                "-i" | "--iterations" => {
+                    options.iterations = parse_number(arg, &value_for(arg)?)?;
+                }
+                "-s" | "--speed" => {
+                    options.steps_per_second = parse_positive(arg, &value_for(arg)?)?;
+                }
+                "--width" => options.width = Some(parse_positive(arg, &value_for(arg)?)?),
+                "--depth" => options.depth = Some(parse_positive(arg, &value_for(arg)?)?),
+                "--height" => options.height = Some(parse_positive(arg, &value_for(arg)?)?),
+                "--level" => options.level = parse_number(arg, &value_for(arg)?)?,
+                "--seed" => options.seed = Some(parse_number(arg, &value_for(arg)?)?),
+                "--scenario" => options.scenario = Some(value_for(arg)?.into()),
+                "--load" => options.load = Some(value_for(arg)?.into()),
+                "--save" => options.save = Some(value_for(arg)?.into()),
+                "--record" => options.record = Some(value_for(arg)?.into()),
+                flag if flag.starts_with('-') => {
+                    return Err(format!("Unknown option '{}'.", flag));
+                }
+                path if options.mode == Mode::Replay && options.recording.is_none() => {
+                    options.recording = Some(path.into());
+                }
+                path if options.mode == Mode::Inspect && options.load.is_none() => {
+                    options.load = Some(path.into());
+                }
+                other => return Err(format!("Unexpected argument '{}'.", other)),
+            }
+        }
+
+        if !options.help {
+            options.validate()?;
+        }
+
+        Ok(options)
+    }
+
+    /// Check, if the options fit together.
+    fn validate(&self) -> Result<(), String> {
+        if self.scenario.is_some() && self.load.is_some() {
+            return Err("Either start with a scenario or with a save, not both.".to_string());
+        }
+
+        let has_size = self.width.is_some() || self.depth.is_some() || self.height.is_some();
+        if has_size && self.load.is_some() {
+            return Err("A saved world keeps its size, --width, --depth and --height cannot be used with a save.".to_string());
+        }
+
+        let too_large = crate::life::world::World::position_count(
+            self.width.unwrap_or(DEFAULT_WIDTH),
+            self.depth.unwrap_or(DEFAULT_DEPTH),
+            self.height.unwrap_or(1),
+        )
+        .is_none();
+        if too_large {
+            return Err(format!(
+                "The world is too large, it may have at most {} positions (width * depth * height).",
+                crate::life::world::World::MAX_POSITIONS
+            ));
+        }
+
+        if self.height.is_some_and(|height| self.level >= height) {
+            return Err("The --level needs to be below the --height of the world.".to_string());
+        }
+
+        if self.mode == Mode::Replay {
+            if self.recording.is_none() {
+                return Err("Replay needs a recording: replay <RECORDING>.".to_string());
+            }
+            if has_size
+                || self.seed.is_some()
+                || self.scenario.is_some()
+                || self.load.is_some()
+                || self.record.is_some()
+            {
+                return Err(
+                    "A replay shows the recorded world only, the world cannot be changed."
+                        .to_string(),
+                );
+            }
+        }
+
+        if self.mode == Mode::Inspect && self.record.is_some() {
+            return Err("Inspect lets no time pass, there is nothing to record.".to_string());
+        }
+
+        if self.mode == Mode::Inspect && self.load.is_none() && self.scenario.is_none() {
+            return Err("Inspect needs a saved world or a scenario: inspect <SAVE>.".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a number for the flag, or explain what went wrong.
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{}' for {}: expected a number.", value, flag))
+}
+
+/// Parse a number bigger than zero for the flag, or explain what went wrong.
+fn parse_positive<T: std::str::FromStr + Default + PartialOrd>(
+    flag: &str,
+    value: &str,
+) -> Result<T, String> {
+    let number: T = parse_number(flag, value)?;
+    if number > T::default() {
+        Ok(number)
+    } else {
+        Err(format!(
+            "Invalid value '{}' for {}: expected a number bigger than 0.",
+            value, flag
+        ))
+    }
+}
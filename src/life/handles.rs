@@ -0,0 +1,110 @@
+//! # Handles
+//!
+//! Stable references to the wusels and objects of a world, given out by the world.
+//!
+//! A handle works like a generational index: It keeps the slot (the index in the world),
+//! where the wusel or object was, and their identifier, which is never given again.
+//! When others die or are destroyed, the slots shift, and the world finds them again by the identifier.
+//! A handle to a gone wusel or object is stale: The world detects it, answers with nothing and changes nothing.
+//!
+//! The one exception are ghosts: A buried wusel, who returns as ghost
+//! (see [World::dead_wusel_return_as_ghost](crate::life::world::World::dead_wusel_return_as_ghost)),
+//! keeps their identifier, and with it their relations and memorials.
+//! So handles to them, which were stale since their death, are valid again.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+/// A handle to a thing of type `T` in the world, see the [module](self).
+///
+/// Handles are equal, if they refer to the same thing (no matter which slot they remember).
+pub struct Handle<T> {
+    slot: usize,
+    id: usize,
+    kind: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> Handle<T> {
+    /// Create a new handle, only the world knows, where its things are.
+    pub(crate) fn new(slot: usize, id: usize) -> Self {
+        Self {
+            slot,
+            id,
+            kind: std::marker::PhantomData,
+        }
+    }
+
+    /// Create a new handle without a known slot, the world finds the thing by the identifier.
+    pub(crate) fn from_id(id: usize) -> Self {
+        Self::new(usize::MAX, id)
+    }
+
+    /// Get the slot, where the thing was, when the handle was given.
+    pub(crate) fn get_slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Get the identifier of the thing, which is unique within the world.
+    pub fn get_id(&self) -> usize {
+        self.id
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> PartialOrd for Handle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Handle<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T> std::hash::Hash for Handle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> std::fmt::Debug for Handle<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "Handle({} @ {})", self.id, self.slot)
+    }
+}
+
+/// A handle is saved by the identifier only, the slot is found again on the first use.
+impl<T> serde::Serialize for Handle<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id.serialize(serializer)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Handle<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        usize::deserialize(deserializer).map(Self::from_id)
+    }
+}
+
+impl<T> std::fmt::Display for Handle<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.id)
+    }
+}
@@ -9,6 +9,8 @@ pub mod wusels;
 
 pub mod objects;
 
+pub mod handles;
+
 /// Default ticks per minute
 const MINUTE: u32 = 2; // ticks
 
@@ -4,37 +4,77 @@
 //! They can store other objects (or be put into the storages).
 //! Also they can be consumed and used up or created, or just put into the world.
 //!
+//! Every object is an entity: It has its own identifier, type and whereabouts,
+//! it is placeable (maybe blocking, stackable or portable) and it may have components:
+//! It may be [consumable](world::items::Consumable) or a [storage](world::items::Storage).
+//! Objects and constructions share one identifier space, given by the world.
+//!
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
-//  TODO (2023-06-13) check if this is redundant.
+use crate::life::world;
+use crate::life::wusels;
 
 /// Types of an object.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ObjectType {
-    Furniture(ObjectSubtype),
-    Miscellaneous(ObjectSubtype),
-    Food(ObjectSubtype),
+    Furniture(
+        #[serde(deserialize_with = "crate::life::world::save::deserialize_static_str")]
+        ObjectSubtype,
+    ),
+    Miscellaneous(
+        #[serde(deserialize_with = "crate::life::world::save::deserialize_static_str")]
+        ObjectSubtype,
+    ),
+    Food(
+        #[serde(deserialize_with = "crate::life::world::save::deserialize_static_str")]
+        ObjectSubtype,
+    ),
+}
+
+impl ObjectType {
+    /// Get the subtype, like "Bread" of a food.
+    pub fn get_subtype(&self) -> ObjectSubtype {
+        match self {
+            Self::Furniture(subtype) | Self::Miscellaneous(subtype) | Self::Food(subtype) => {
+                subtype
+            }
+        }
+    }
 }
 
 /// Subtype or Subcategory of an Object
 pub type ObjectSubtype = &'static str; // String doesn't support Copy Trait, what is used for the TaskTag.
 
-/// Identifier type (tuple) for an object.
-pub type ObjectId = usize;
+/// Identifier of a non-living thing in the world (an object or a construction).
+pub type EntityId = usize;
+
+/// Identifier for an object.
+pub type ObjectId = EntityId;
+
+/// Handle to an object in the world (see [handles](crate::life::handles)).
+pub type ObjectHandle = crate::life::handles::Handle<Object>;
+
+/// Where an object is: On a position, in a storage, held by a wusel or nowhere (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Whereabouts {
+    OnPositionIndex(usize),
+    InStorageId(ObjectId),
+    HeldByWuselId(wusels::WuselId),
+    Nowhere,
+}
 
 /// A world object indicates an object in the world which is not a wusel.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Object {
+    id: ObjectId,
     name: String,
-    id: usize,
     object_type: ObjectType,
+    whereabouts: Whereabouts,
     object_attributes: u8,
-    consumable_bites: u16,
-    storage_capacity: u16, // items that can be stored 0
 
-    consumable_bites_left: u16,
-    storage_capacity_left: u16,
+    consumable: Option<world::items::Consumable>, // if it can be eaten (and spoils).
+    storage: Option<world::items::Storage>,       // if it keeps other objects.
 }
 
 impl Object {
@@ -42,11 +82,11 @@ impl Object {
     pub const OBJECT_IS_STACKABLE: u8 = 0b010; // can be under or on top of another object.
     pub const OBJECT_IS_PORTABLE: u8 = 0b100; // can be carried or stored.
 
-    fn get_items_created() -> usize {
-        0
-    }
-
+    /// Create a new object, which is nowhere yet.
+    /// It is consumable, if it has bites, and a storage, if it has a capacity.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        id: ObjectId,
         name: String,
         object_type: ObjectType,
         is_solid: bool,
@@ -56,32 +96,34 @@ impl Object {
         storage_capacity: u16,
     ) -> Self {
         Self {
+            id,
             name,
             object_type,
-            id: Self::get_items_created(),
+            whereabouts: Whereabouts::Nowhere,
             object_attributes: Self::to_object_attributes(is_solid, is_stackable, is_portable),
-            consumable_bites,
-            storage_capacity,
-            consumable_bites_left: consumable_bites,
-            storage_capacity_left: storage_capacity,
+            consumable: (consumable_bites > 0)
+                .then(|| world::items::Consumable::new(consumable_bites, 0)),
+            storage: (storage_capacity > 0).then(|| world::items::Storage::new(storage_capacity)),
         }
     }
 
     /// Initiate new object (but as new) like the given. New Object: Not consumed and empty.
-    pub fn clone_as_new(other: &Self) -> Self {
+    pub fn clone_as_new(other: &Self, id: ObjectId) -> Self {
         Self {
+            id, // new
             name: other.name.clone(),
             object_type: other.object_type,
-            id: Self::get_items_created(), // new
+            whereabouts: Whereabouts::Nowhere,
             object_attributes: other.object_attributes,
-            consumable_bites: other.consumable_bites,
-            storage_capacity: other.storage_capacity,
-            consumable_bites_left: other.consumable_bites,
-            storage_capacity_left: other.storage_capacity,
+            consumable: other
+                .consumable
+                .as_ref()
+                .map(|consumable| consumable.fresh()),
+            storage: other.storage.as_ref().map(|storage| storage.empty()),
         }
     }
 
-    pub fn get_object_id(&self) -> usize {
+    pub fn get_object_id(&self) -> ObjectId {
         self.id
     }
 
@@ -97,6 +139,14 @@ impl Object {
         self.name = name;
     }
 
+    pub fn get_whereabouts(&self) -> Whereabouts {
+        self.whereabouts
+    }
+
+    pub fn set_whereabouts(&mut self, whereabouts: Whereabouts) {
+        self.whereabouts = whereabouts;
+    }
+
     pub fn to_object_attributes(is_blocking: bool, is_stackable: bool, is_portable: bool) -> u8 {
         (if is_blocking {
             Self::OBJECT_IS_BLOCKING
@@ -129,27 +179,73 @@ impl Object {
         (self.object_attributes & Self::OBJECT_IS_PORTABLE) != 0
     }
 
+    pub fn get_consumable_component(&self) -> Option<&world::items::Consumable> {
+        self.consumable.as_ref()
+    }
+
+    pub fn get_consumable_component_mut(&mut self) -> Option<&mut world::items::Consumable> {
+        self.consumable.as_mut()
+    }
+
+    pub fn set_consumable_component(&mut self, consumable: Option<world::items::Consumable>) {
+        self.consumable = consumable;
+    }
+
+    pub fn get_storage_component(&self) -> Option<&world::items::Storage> {
+        self.storage.as_ref()
+    }
+
+    pub fn get_storage_component_mut(&mut self) -> Option<&mut world::items::Storage> {
+        self.storage.as_mut()
+    }
+
     pub fn get_consumable(&self) -> u16 {
-        self.consumable_bites
+        self.consumable
+            .as_ref()
+            .map_or(0, |consumable| consumable.get_bites())
     }
 
     pub fn get_consumable_left(&self) -> u16 {
-        self.consumable_bites_left
+        self.consumable
+            .as_ref()
+            .map_or(0, |consumable| consumable.get_bites_left())
     }
 
     pub fn set_consumable_left(&mut self, consumable_bites_left: u16) {
-        self.consumable_bites_left = consumable_bites_left;
+        if let Some(consumable) = self.consumable.as_mut() {
+            consumable.set_bites_left(consumable_bites_left);
+        }
+    }
+
+    /// Check if the object is consumable, but spoiled.
+    pub fn is_spoiled(&self) -> bool {
+        self.consumable
+            .as_ref()
+            .is_some_and(|consumable| consumable.is_spoiled())
+    }
+
+    /// Get how fast stored objects age in percent (100, if it is no storage).
+    pub fn get_aging_percent(&self) -> u32 {
+        self.storage
+            .as_ref()
+            .map_or(100, |storage| storage.get_aging_percent())
     }
 
     pub fn get_storage_capacity(&self) -> u16 {
-        self.storage_capacity
+        self.storage
+            .as_ref()
+            .map_or(0, |storage| storage.get_capacity())
     }
 
     pub fn get_storage_capacity_left(&self) -> u16 {
-        self.storage_capacity_left
+        self.storage
+            .as_ref()
+            .map_or(0, |storage| storage.get_capacity_left())
     }
 
     pub fn set_storage_capacity_left(&mut self, storage_capacity_left: u16) {
-        self.storage_capacity_left = storage_capacity_left;
+        if let Some(storage) = self.storage.as_mut() {
+            storage.set_capacity_left(storage_capacity_left);
+        }
     }
 }
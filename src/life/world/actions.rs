@@ -0,0 +1,90 @@
+//! # Actions
+//!
+//! Actions are the verbs, a wusel can do with an object, like viewing, taking or consuming it.
+//!
+//! An action is pure data: It is only done, if all its [preconditions](Precondition) hold,
+//! it applies its [step effects](Effect) on every step and its completion effects on the last step.
+//! What an action offers for the wusel's needs on certain objects is additionally
+//! [advertised](crate::life::world::advertisements) by the objects.
+//!
+//! New verbs are registered in the world (see `World::action_register`), their ID is the index.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::wusels;
+
+/// A condition, which must hold to do an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Precondition {
+    NotHeld,         // the object is placed or stored, but held by nobody.
+    NotHeldByOthers, // the object is not held by another wusel.
+    HeldByActor,     // the actor holds the object.
+    Consumable,      // the object has bites left.
+    Ability(wusels::abilities::Ability, u32), // the actor has at least this ability.
+}
+
+/// What an action changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Effect {
+    Need(wusels::needs::Need, i16),      // change a need of the actor.
+    Improve(wusels::abilities::Ability), // the actor practices an ability.
+    Take,                                // the actor holds the object.
+    Drop,                                // the actor puts the object down, where it is.
+    ConsumeBite,                         // one bite is consumed, the last destroys the object.
+}
+
+/// An action, a wusel can do with an object.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Action {
+    name: String,
+    preconditions: Vec<Precondition>,
+    step_effects: Vec<Effect>,
+    completion_effects: Vec<Effect>,
+}
+
+impl Action {
+    /// Create a new action without preconditions and effects.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            preconditions: vec![],
+            step_effects: vec![],
+            completion_effects: vec![],
+        }
+    }
+
+    /// Add a condition, which must hold to do the action.
+    pub fn with_precondition(mut self, precondition: Precondition) -> Self {
+        self.preconditions.push(precondition);
+        self
+    }
+
+    /// Add an effect, which is applied on every step of the action.
+    pub fn with_step_effect(mut self, effect: Effect) -> Self {
+        self.step_effects.push(effect);
+        self
+    }
+
+    /// Add an effect, which is applied when the action is completed.
+    pub fn with_completion_effect(mut self, effect: Effect) -> Self {
+        self.completion_effects.push(effect);
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_preconditions(&self) -> &[Precondition] {
+        &self.preconditions
+    }
+
+    pub fn get_step_effects(&self) -> &[Effect] {
+        &self.step_effects
+    }
+
+    pub fn get_completion_effects(&self) -> &[Effect] {
+        &self.completion_effects
+    }
+}
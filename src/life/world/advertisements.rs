@@ -0,0 +1,185 @@
+//! # Advertisements
+//!
+//! Objects advertise, what they offer: Which action on which object type affects which needs,
+//! which abilities a wusel needs to do it, and how long it takes.
+//!
+//! The registry is queried by the task manager (to apply the effects of a used object),
+//! by the wusels' decisions (which object would satisfy which need)
+//! and by views (what does an object offer).
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::objects;
+use crate::life::wusels;
+use crate::life::wusels::tasks;
+
+/// Which objects advertise: All of an object type, or only one exact subtype.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Advertiser {
+    AnyFurniture,
+    AnyMiscellaneous,
+    AnyFood,
+    Exactly(objects::ObjectType),
+}
+
+impl Advertiser {
+    /// Check, if an object of the given type is advertised.
+    pub fn advertises(&self, object_type: objects::ObjectType) -> bool {
+        match (self, object_type) {
+            (Self::AnyFurniture, objects::ObjectType::Furniture(_)) => true,
+            (Self::AnyMiscellaneous, objects::ObjectType::Miscellaneous(_)) => true,
+            (Self::AnyFood, objects::ObjectType::Food(_)) => true,
+            (Self::Exactly(advertised), _) => *advertised == object_type,
+            _ => false,
+        }
+    }
+
+    /// Check, if only one exact subtype is advertised.
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Self::Exactly(_))
+    }
+}
+
+/// What an action on an object offers.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Advertisement {
+    advertiser: Advertiser,
+    action_id: tasks::ActionId,
+    effects: Vec<(wusels::needs::Need, i16)>, // changed needs per tick of use.
+    requirements: Vec<(wusels::abilities::Ability, u32)>, // minimal abilities to use it.
+    duration: usize,                          // ticks of one use.
+}
+
+impl Advertisement {
+    /// Create a new advertisement for an action, which takes one tick without requirements.
+    pub fn new(advertiser: Advertiser, action_id: tasks::ActionId) -> Self {
+        Self {
+            advertiser,
+            action_id,
+            effects: vec![],
+            requirements: vec![],
+            duration: 1,
+        }
+    }
+
+    /// Add a change of a need, for every tick of use.
+    pub fn with_effect(mut self, need: wusels::needs::Need, change: i16) -> Self {
+        self.effects.push((need, change));
+        self
+    }
+
+    /// Add a minimal ability, a wusel needs to use it.
+    pub fn with_requirement(mut self, ability: wusels::abilities::Ability, level: u32) -> Self {
+        self.requirements.push((ability, level));
+        self
+    }
+
+    /// Set the ticks of one use.
+    pub fn with_duration(mut self, duration: usize) -> Self {
+        self.duration = usize::max(1, duration);
+        self
+    }
+
+    pub fn get_advertiser(&self) -> Advertiser {
+        self.advertiser
+    }
+
+    pub fn get_action_id(&self) -> tasks::ActionId {
+        self.action_id
+    }
+
+    pub fn get_effects(&self) -> &[(wusels::needs::Need, i16)] {
+        &self.effects
+    }
+
+    pub fn get_requirements(&self) -> &[(wusels::abilities::Ability, u32)] {
+        &self.requirements
+    }
+
+    pub fn get_duration(&self) -> usize {
+        self.duration
+    }
+
+    /// Get how much a need changes by one complete use (all ticks).
+    pub fn get_effect_on(&self, need: wusels::needs::Need) -> i64 {
+        self.effects
+            .iter()
+            .filter(|(effect_need, _)| *effect_need == need)
+            .map(|&(_, change)| change as i64 * self.duration as i64)
+            .sum()
+    }
+
+    /// Check, if the need is improved by using it.
+    pub fn satisfies(&self, need: wusels::needs::Need) -> bool {
+        self.get_effect_on(need) > 0
+    }
+
+    /// Check, if the wusel has all required abilities.
+    pub fn may_be_used_by(&self, wusel: &wusels::Wusel) -> bool {
+        self.requirements
+            .iter()
+            .all(|&(ability, level)| wusel.get_ability(ability) >= level)
+    }
+}
+
+/// Registry of all advertisements in a world.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Advertisements {
+    advertisements: Vec<Advertisement>,
+}
+
+impl Advertisements {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an advertisement.
+    /// An earlier advertisement of the same advertiser and action is replaced.
+    pub fn register(&mut self, advertisement: Advertisement) {
+        self.advertisements.retain(|registered| {
+            registered.advertiser != advertisement.advertiser
+                || registered.action_id != advertisement.action_id
+        });
+        self.advertisements.push(advertisement);
+    }
+
+    /// Get all registered advertisements.
+    pub fn get_all(&self) -> &[Advertisement] {
+        &self.advertisements
+    }
+
+    /// Get what the action on an object of the given type offers.
+    /// An advertisement for the exact subtype wins over one for the whole object type.
+    pub fn find(
+        &self,
+        object_type: objects::ObjectType,
+        action_id: tasks::ActionId,
+    ) -> Option<&Advertisement> {
+        self.advertisements
+            .iter()
+            .filter(|advertisement| {
+                advertisement.action_id == action_id
+                    && advertisement.advertiser.advertises(object_type)
+            })
+            .max_by_key(|advertisement| advertisement.advertiser.is_exact())
+    }
+
+    /// Get what an object of the given type offers, one advertisement per action.
+    pub fn for_object_type(&self, object_type: objects::ObjectType) -> Vec<&Advertisement> {
+        let mut action_ids: Vec<tasks::ActionId> = self
+            .advertisements
+            .iter()
+            .filter(|advertisement| advertisement.advertiser.advertises(object_type))
+            .map(|advertisement| advertisement.action_id)
+            .collect();
+        action_ids.sort_unstable();
+        action_ids.dedup();
+
+        action_ids
+            .into_iter()
+            .filter_map(|action_id| self.find(object_type, action_id))
+            .collect()
+    }
+}
@@ -7,7 +7,7 @@
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
 /// Simple position in world.
-#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Position {
     pub x: u32, // left to right (width)
     pub y: u32, // front to back (depth)
@@ -17,6 +17,15 @@ pub struct Position {
 impl Position {
     pub const ROOT: Self = Self { x: 0, y: 0, z: 0 };
 
+    /// Cost of a step to a direct (orthogonal) neighbour.
+    pub const STEP_COST_STRAIGHT: u32 = 10;
+
+    /// Cost of a step to a diagonal neighbour (about sqrt(2) times a straight step).
+    pub const STEP_COST_DIAGONAL: u32 = 14;
+
+    /// Cost of a step to another level (climbing stairs).
+    pub const STEP_COST_LEVEL: u32 = 20;
+
     /// Simple constructor.
     pub fn new(x: u32, y: u32, z: u32) -> Self {
         Self { x, y, z }
@@ -24,13 +33,76 @@ impl Position {
 
     /// Get the distance between two positions.
     pub fn distance_to(&self, other: &Self) -> f32 {
-        (((self.x as i64 - other.x as i64).pow(2) + (self.y as i64 - other.y as i64).pow(2)) as f32)
+        (((self.x as i64 - other.x as i64).pow(2)
+            + (self.y as i64 - other.y as i64).pow(2)
+            + (self.z as i64 - other.z as i64).pow(2)) as f32)
             .sqrt()
     }
+
+    /// Get the estimated cost for walking to the other position,
+    /// if nothing is in the way (octile distance, plus the levels to climb).
+    pub fn step_cost_estimate(&self, other: &Self) -> u32 {
+        let dx = (self.x as i64 - other.x as i64).unsigned_abs() as u32;
+        let dy = (self.y as i64 - other.y as i64).unsigned_abs() as u32;
+        let dz = (self.z as i64 - other.z as i64).unsigned_abs() as u32;
+        let (long, short) = (u32::max(dx, dy), u32::min(dx, dy));
+        Self::STEP_COST_DIAGONAL * short
+            + Self::STEP_COST_STRAIGHT * (long - short)
+            + Self::STEP_COST_LEVEL * dz
+    }
+}
+
+/// Identifier for a Room.
+pub type RoomId = usize;
+
+/// A Room is an enclosed region on one level,
+/// bounded by walls, doors and windows.
+#[derive(Debug, PartialEq, Clone, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Room {
+    id: RoomId,
+    name: String,
+    positions: Vec<Position>,
+}
+
+impl Room {
+    pub fn new(id: RoomId, name: String, positions: Vec<Position>) -> Self {
+        Self {
+            id,
+            name,
+            positions,
+        }
+    }
+
+    pub fn id(&self) -> RoomId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Get all positions inside the room, without the bounding walls.
+    pub fn get_positions(&self) -> Vec<Position> {
+        self.positions.clone()
+    }
+
+    /// Replace the positions, eg. if the walls around were changed.
+    pub fn set_positions(&mut self, positions: Vec<Position>) {
+        self.positions = positions;
+    }
+
+    /// Get the count of positions inside the room.
+    pub fn size(&self) -> usize {
+        self.positions.len()
+    }
 }
 
 /// Simple position in world.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub struct Area {
     anchor: Position,
     width: u32,  // left to right
@@ -83,11 +155,11 @@ impl Area {
     }
 
     /// Get a random position within this area.
-    pub fn position_random(&self) -> Position {
+    pub fn position_random<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Position {
         Position {
-            x: self.anchor.x + (rand::random::<u32>() % (self.anchor.x + self.width)),
-            y: self.anchor.y + (rand::random::<u32>() % (self.anchor.y + self.depth)),
-            z: self.anchor.z + (rand::random::<u32>() % (self.anchor.z + self.height)),
+            x: self.anchor.x + rng.gen_range(0..u32::max(1, self.width)),
+            y: self.anchor.y + rng.gen_range(0..u32::max(1, self.depth)),
+            z: self.anchor.z + rng.gen_range(0..u32::max(1, self.height)),
         }
     }
 
@@ -119,7 +191,7 @@ impl Area {
         }
 
         // On east border => No east neighbours. (None)
-        if pos.x >= box_width && change.0 > 0 {
+        if pos.x + 1 >= box_width && change.0 > 0 {
             return None;
         }
 
@@ -129,7 +201,7 @@ impl Area {
         }
 
         // On north border => No north neighbours. (None)
-        if pos.y >= box_depth && change.1 > 0 {
+        if pos.y + 1 >= box_depth && change.1 > 0 {
             return None;
         }
 
@@ -139,7 +211,7 @@ impl Area {
         }
 
         // On north border => No north neighbours. (None)
-        if pos.z >= box_height && change.2 > 0 {
+        if pos.z + 1 >= box_height && change.2 > 0 {
             return None;
         }
 
@@ -150,13 +222,206 @@ impl Area {
         ))
     }
 
+    /// Find the shortest path from start to goal within this area (A*).
+    ///
+    /// Only positions accepted by `is_passable` and `is_free` are walked on, but the goal itself
+    /// is always accepted, so something blocking can also be approached.
+    /// A diagonal step is only taken, if both touched orthogonal neighbours are passable,
+    /// positions which are just not free (eg. taken by others) can be squeezed by.
+    /// Other levels are only reached by the extra links of a position (eg. stairs).
+    ///
+    /// Returns the steps in walking order, without the start but with the goal,
+    /// or None, if the goal cannot be reached.
+    pub fn find_path(
+        &self,
+        start: Position,
+        goal: Position,
+        is_passable: &dyn Fn(&Position) -> bool,
+        is_free: &dyn Fn(&Position) -> bool,
+        get_links: &dyn Fn(&Position) -> Vec<Position>,
+    ) -> Option<Vec<Position>> {
+        if !self.contains_position(&start) || !self.contains_position(&goal) {
+            return None;
+        }
+
+        let can_enter = |p: &Position| *p == goal || (is_passable(p) && is_free(p));
+
+        // open list, ordered by the estimated full cost (and the position for a stable order).
+        let mut open: std::collections::BinaryHeap<std::cmp::Reverse<(u32, u32, u32, u32)>> =
+            std::collections::BinaryHeap::new();
+        let mut costs: std::collections::HashMap<Position, u32> = std::collections::HashMap::new();
+        let mut came_from: std::collections::HashMap<Position, Position> =
+            std::collections::HashMap::new();
+
+        costs.insert(start, 0);
+        open.push(std::cmp::Reverse((
+            start.step_cost_estimate(&goal),
+            start.x,
+            start.y,
+            start.z,
+        )));
+
+        while let Some(std::cmp::Reverse((_, x, y, z))) = open.pop() {
+            let current = Position::new(x, y, z);
+
+            if current == goal {
+                // Walk back the path.
+                let mut path = vec![current];
+                let mut step = current;
+                while let Some(&previous) = came_from.get(&step) {
+                    if previous == start {
+                        break;
+                    }
+                    path.push(previous);
+                    step = previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let current_cost = costs[&current];
+
+            let mut neighbours = self.get_all_neighbours_xy(current);
+            neighbours.extend(
+                get_links(&current)
+                    .into_iter()
+                    .filter(|link| self.contains_position(link)),
+            );
+
+            for neighbour in neighbours {
+                if !can_enter(&neighbour) {
+                    continue;
+                }
+
+                let is_level_change = neighbour.z != current.z;
+                let is_diagonal = neighbour.x != current.x && neighbour.y != current.y;
+
+                // Do not cut corners.
+                if is_diagonal
+                    && (!is_passable(&Position::new(neighbour.x, current.y, current.z))
+                        || !is_passable(&Position::new(current.x, neighbour.y, current.z)))
+                {
+                    continue;
+                }
+
+                let cost = current_cost
+                    + match (is_level_change, is_diagonal) {
+                        (true, _) => Position::STEP_COST_LEVEL,
+                        (false, true) => Position::STEP_COST_DIAGONAL,
+                        (false, false) => Position::STEP_COST_STRAIGHT,
+                    };
+
+                if costs.get(&neighbour).is_none_or(|&known| cost < known) {
+                    costs.insert(neighbour, cost);
+                    came_from.insert(neighbour, current);
+                    open.push(std::cmp::Reverse((
+                        cost + neighbour.step_cost_estimate(&goal),
+                        neighbour.x,
+                        neighbour.y,
+                        neighbour.z,
+                    )));
+                }
+            }
+        }
+
+        None // goal not reachable.
+    }
+
+    /// Find the nearest position (in steps) to the start, which is accepted.
+    /// The start itself is the nearest, if accepted.
+    pub fn find_nearest(
+        &self,
+        start: Position,
+        accept: &dyn Fn(&Position) -> bool,
+    ) -> Option<Position> {
+        if !self.contains_position(&start) {
+            return None;
+        }
+
+        let mut visited: std::collections::HashSet<Position> = std::collections::HashSet::new();
+        let mut queue: std::collections::VecDeque<Position> = std::collections::VecDeque::new();
+
+        visited.insert(start);
+        queue.push_back(start);
+
+        while let Some(current) = queue.pop_front() {
+            if accept(&current) {
+                return Some(current);
+            }
+
+            for neighbour in self.get_all_neighbours_xy(current) {
+                if visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        None // nothing accepted.
+    }
+
+    /// Find all enclosed regions of this area, level by level.
+    ///
+    /// A region is flood-filled from a position over its orthogonal neighbours,
+    /// until positions accepted by `is_boundary` are met.
+    /// Regions, that reach the border of the area, are not enclosed (outdoors) and are dropped.
+    /// The regions are sorted by their first position (index order).
+    pub fn find_enclosed_regions(
+        &self,
+        is_boundary: &dyn Fn(&Position) -> bool,
+    ) -> Vec<Vec<Position>> {
+        let mut visited: std::collections::HashSet<Position> = std::collections::HashSet::new();
+        let mut regions = vec![];
+
+        for start in *self {
+            if visited.contains(&start) || is_boundary(&start) {
+                continue;
+            }
+
+            let mut region = vec![];
+            let mut is_enclosed = true;
+            let mut open = vec![start];
+            visited.insert(start);
+
+            while let Some(current) = open.pop() {
+                region.push(current);
+
+                let neighbours: Vec<Position> = self
+                    .get_all_neighbours_xy(current)
+                    .into_iter()
+                    .filter(|n| n.x == current.x || n.y == current.y)
+                    .collect();
+
+                // Less than four orthogonal neighbours: On the border.
+                if neighbours.len() < 4 {
+                    is_enclosed = false;
+                }
+
+                for neighbour in neighbours {
+                    if !visited.contains(&neighbour) && !is_boundary(&neighbour) {
+                        visited.insert(neighbour);
+                        open.push(neighbour);
+                    }
+                }
+            }
+
+            if is_enclosed {
+                regions.push(region);
+            }
+        }
+
+        regions
+    }
+
     /// Get the optional position, which is on the given index.
+    /// The index goes through the levels: x + width * (y + depth * z).
     pub fn position_from_index(&self, index: u32) -> Option<Position> {
-        if index < self.width * self.depth {
+        if (index as usize) < self.position_index_bound {
+            let level_size = self.width * self.depth;
+            let on_level = index % level_size;
             Some(Position::new(
-                index % self.width + self.anchor.x,
-                index / self.width + self.anchor.y,
-                0u32, // XXX
+                on_level % self.width + self.anchor.x,
+                on_level / self.width + self.anchor.y,
+                index / level_size + self.anchor.z,
             ))
         } else {
             None
@@ -0,0 +1,176 @@
+//! # Communication
+//!
+//! How well two meeting wusels get along, step by step.
+//!
+//! The quality of a meeting step is given by both wusels' communication ability,
+//! their mood (how fun and loved they currently feel), how well they already know each other
+//! and a bit of chance (from the world's random generator, so it is reproducible).
+//! A rude actor always spoils the step.
+//!
+//! A good step brings them closer and lets them feel loved and have fun,
+//! a bad step estranges them and is no fun at all.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::world;
+use crate::life::wusels;
+
+use rand::Rng;
+
+/// Quality of a meeting step between average strangers, without any chance.
+const BASE_QUALITY: f32 = 0.2;
+
+/// Influence of the communication abilities (both at the highest level).
+const ABILITY_WEIGHT: f32 = 0.4;
+
+/// Influence of the mood (both completely unhappy or happy).
+const MOOD_WEIGHT: f32 = 0.2;
+
+/// Influence of the existing relation (hostile or familiar).
+const RELATION_WEIGHT: f32 = 0.2;
+
+/// Influence of chance.
+const RANDOM_WEIGHT: f32 = 0.3;
+
+/// A relation value, from which on two wusels are familiar (or hostile, if negative).
+const FAMILIAR_RELATION: f32 = 100.0;
+
+/// The highest change of the relation by one meeting step.
+const MAX_RELATION_CHANGE: f32 = 3.0;
+
+/// Love, both feel by a perfect meeting step (twice for a romantic one).
+const LOVE_PER_STEP: f32 = 400.0;
+
+/// Fun, both have by a perfect meeting step (or lose by the worst).
+const FUN_PER_STEP: f32 = 200.0;
+
+/// The outcome of one meeting step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outcome {
+    pub quality: f32,         // from -1 (worst) to 1 (perfect).
+    pub relation_change: i32, // change of the friendship (or romance, if romantically).
+    pub love_change: i16,     // change of LOVE for both.
+    pub fun_change: i16,      // change of FUN for both.
+}
+
+/// Get the quality of a meeting step, clamped from -1 (worst) to 1 (perfect).
+///
+/// The ability and mood are given from 0 to 1, the familiarity from -1 (hostile) to 1,
+/// the chance from -1 to 1.
+pub fn quality(
+    ability: f32,
+    mood: f32,
+    familiarity: f32,
+    chance: f32,
+    intention_good: bool,
+) -> f32 {
+    let quality = BASE_QUALITY
+        + ABILITY_WEIGHT * ability
+        + MOOD_WEIGHT * (2.0 * mood - 1.0)
+        + RELATION_WEIGHT * familiarity
+        + RANDOM_WEIGHT * chance;
+
+    let quality = quality.clamp(-1.0, 1.0);
+
+    if intention_good {
+        quality
+    } else {
+        -quality.abs().max(BASE_QUALITY) // the better they communicate, the more it hurts.
+    }
+}
+
+/// Get the outcome of a meeting step of the given quality.
+pub fn outcome(quality: f32, romantically: bool) -> Outcome {
+    let love_per_step = if romantically {
+        2.0 * LOVE_PER_STEP
+    } else {
+        LOVE_PER_STEP
+    };
+
+    Outcome {
+        quality,
+        relation_change: (quality * MAX_RELATION_CHANGE).round() as i32,
+        love_change: (quality.max(0.0) * love_per_step) as i16,
+        fun_change: (quality * FUN_PER_STEP) as i16,
+    }
+}
+
+/// Let two wusels (given by their indices) communicate for one meeting step:
+/// Their relation changes, and if they are alive, they feel loved and have fun (or not).
+///
+/// Returns the outcome of the step.
+pub fn let_two_wusels_communicate(
+    world: &mut world::World,
+    active_index: usize,
+    passive_index: usize,
+    intention_good: bool,
+    romantically: bool,
+) -> Outcome {
+    let communication = wusels::abilities::Ability::COMMUNICATION;
+    let max_level = wusels::abilities::MAX_LEVEL as f32;
+
+    let ability = [active_index, passive_index]
+        .iter()
+        .map(|&index| world.wusels[index].get_ability(communication) as f32 / max_level)
+        .sum::<f32>()
+        / 2.0;
+
+    let mood = [active_index, passive_index]
+        .iter()
+        .flat_map(|&index| {
+            [wusels::needs::Need::FUN, wusels::needs::Need::LOVE]
+                .iter()
+                .map(move |&need| (index, need))
+        })
+        .map(|(index, need)| {
+            (world.wusels[index].get_need(need) as f32 / need.get_full() as f32).min(1.0)
+        })
+        .sum::<f32>()
+        / 4.0;
+
+    let active = world.wusel_handle_at(active_index);
+    let passive = world.wusel_handle_at(passive_index);
+
+    let familiarity = world
+        .wusel_get_relation(active, passive)
+        .map_or(0.0, |relation| {
+            let value = if romantically {
+                relation.get_romance()
+            } else {
+                relation.get_friendship()
+            };
+            (value as f32 / FAMILIAR_RELATION).clamp(-1.0, 1.0)
+        });
+
+    let chance: f32 = world.get_rng().gen_range(-1.0..=1.0);
+
+    let outcome = outcome(
+        quality(ability, mood, familiarity, chance, intention_good),
+        romantically,
+    );
+
+    log::debug!(
+        "Wusels {} and {} communicate: {:?}",
+        active,
+        passive,
+        outcome
+    );
+
+    world.wusel_change_relations(
+        active,
+        passive,
+        outcome.relation_change,
+        wusels::relations::RelationType::from_romantically(romantically),
+    );
+
+    for &index in [active_index, passive_index].iter() {
+        let wusel = &mut world.wusels[index];
+        if wusel.is_alive() {
+            wusel.set_need_relative(wusels::needs::Need::LOVE, outcome.love_change);
+            wusel.set_need_relative(wusels::needs::Need::FUN, outcome.fun_change);
+        }
+    }
+
+    outcome
+}
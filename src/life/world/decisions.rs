@@ -0,0 +1,321 @@
+//! # Decisions
+//!
+//! Let idle wusels decide on their own, what to do next.
+//!
+//! Every available option is scored by its utility: How much it would satisfy the wusel's needs,
+//! weighted by how urgent each need currently is, reduced by the way to go there.
+//! Options are using objects (as they [advertise](crate::life::world::advertisements)),
+//! crafting new objects (as their [blueprints](crate::life::world::items::Blueprint) describe),
+//! meeting other wusels (flirting, if they may, and romantically, if both consent) and resting.
+//! The wusel's personality (their abilities) changes how they value the options,
+//! their life stage limits them (eg. babies do not cook).
+//!
+//! If no option is worth it, the wusel may just wander around or meet someone by chance
+//! (flirting or romantically, if they are close enough).
+//! Ghosts have no needs to satisfy anymore, they only wander around and meet by chance.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life;
+use crate::life::world;
+use crate::life::world::areas;
+use crate::life::world::task_manager;
+use crate::life::wusels;
+use crate::life::wusels::tasks;
+
+use rand::Rng;
+
+/// Below this utility, an option is not worth to be done.
+const UTILITY_THRESHOLD: f32 = 0.01;
+
+/// Utility lost for each tile, the wusel needs to walk.
+const UTILITY_COST_PER_TILE: f32 = 0.0005;
+
+/// Part of a product's utility, crafting it is worth (it takes a while, before it can be used).
+const CRAFTING_PATIENCE: f32 = 0.5;
+
+/// Ticks of a meeting.
+const MEETING_DURATION: usize = 10;
+
+/// Part of the full LOVE, a meeting would satisfy.
+const MEETING_SATISFACTION: f32 = 0.05;
+
+/// Part of the full LOVE, a flirt (a romantic meeting) would satisfy.
+const FLIRT_SATISFACTION: f32 = 0.075;
+
+/// Part of the full LOVE, a romance would satisfy.
+const ROMANCE_SATISFACTION: f32 = 0.1;
+
+/// Ticks of a rest.
+const REST_DURATION: usize = life::HOUR as usize;
+
+/// Decide the next task for an idle wusel, given by its index.
+/// Returns None, if the wusel rather does nothing.
+pub fn decide(world: &mut world::World, wusel_index: usize) -> Option<tasks::TaskBuilder> {
+    let wusel_position = world
+        .wusels_index_on_position_index
+        .get(wusel_index)
+        .and_then(|&position_index| world.position_from_index(position_index))?;
+
+    if world.wusels[wusel_index].is_ghost() {
+        return decide_randomly(world, wusel_index);
+    }
+
+    let mut best: Option<(f32, tasks::TaskBuilder)> = None;
+
+    for (utility, task) in options(world, wusel_index, wusel_position) {
+        log::debug!("Option {:?}: {}", task.get_name(), utility);
+        if utility >= UTILITY_THRESHOLD && best.as_ref().is_none_or(|(best, _)| utility > *best) {
+            best = Some((utility, task));
+        }
+    }
+
+    match best {
+        Some((_, task)) => Some(task),
+        None => decide_randomly(world, wusel_index),
+    }
+}
+
+/// Get all options for the wusel with their utility.
+fn options(
+    world: &world::World,
+    wusel_index: usize,
+    wusel_position: areas::Position,
+) -> Vec<(f32, tasks::TaskBuilder)> {
+    let wusel = &world.wusels[wusel_index];
+    let wusel_id = wusel.get_id();
+
+    // Personality: Fitter wusels mind walking less, communicative wusels like meetings more.
+    let cost_per_tile = UTILITY_COST_PER_TILE
+        / (1.0 + wusel.get_ability(wusels::abilities::Ability::FITNESS) as f32 / 10.0);
+    let sociability =
+        1.0 + wusel.get_ability(wusels::abilities::Ability::COMMUNICATION) as f32 / 10.0;
+
+    let mut options: Vec<(f32, tasks::TaskBuilder)> = vec![];
+
+    // Use objects, as they advertise.
+    for (object_index, object) in world.objects.iter().enumerate() {
+        // Objects held by another wusel (also in their bags) cannot be used.
+        if world
+            .objects_index_get_holder(object_index)
+            .is_some_and(|holder_id| holder_id != wusel_id)
+        {
+            continue;
+        }
+
+        let object_position = match world.objects_index_get_position(object_index) {
+            Some(object_position) => object_position,
+            None => continue,
+        };
+
+        let object_handle = world.object_handle_at(object_index);
+        let distance_cost = cost_per_tile * wusel_position.distance_to(&object_position);
+
+        for advertisement in world
+            .advertisements
+            .for_object_type(object.get_object_type())
+        {
+            if !advertisement.may_be_used_by(wusel) {
+                continue;
+            }
+
+            // Nobody wants to eat spoiled food.
+            let consumes = world
+                .action_get(advertisement.get_action_id())
+                .is_some_and(|action| {
+                    action
+                        .get_step_effects()
+                        .contains(&world::actions::Effect::ConsumeBite)
+                });
+            if consumes && object.is_spoiled() {
+                continue;
+            }
+
+            let satisfaction: f32 = wusels::needs::Need::VALUES
+                .iter()
+                .map(|&need| satisfaction(wusel, need, advertisement.get_effect_on(need)))
+                .sum();
+
+            options.push((
+                satisfaction - distance_cost,
+                tasks::TaskBuilder::use_object(object_handle, advertisement.get_action_id())
+                    .set_duration(advertisement.get_duration()),
+            ));
+        }
+    }
+
+    // Craft something, if all components are there, eg. cook a meal.
+    for (blueprint_id, blueprint) in world.blueprint_get_all().iter().enumerate() {
+        if !wusel
+            .get_life_stage()
+            .may_do(&tasks::TaskTag::Craft(blueprint_id))
+        {
+            continue;
+        }
+
+        let crafting_position = match blueprint.get_workstation() {
+            None => wusel_position,
+            Some(_) => match world
+                .blueprint_find_workstation(
+                    blueprint_id,
+                    world.wusel_handle_at(wusel_index),
+                    wusel_position,
+                )
+                .and_then(|workstation| world.object_get_position(workstation))
+            {
+                Some(workstation_position) => workstation_position,
+                None => continue,
+            },
+        };
+
+        if world
+            .blueprint_find_components(
+                blueprint_id,
+                world.wusel_handle_at(wusel_index),
+                crafting_position,
+            )
+            .is_none()
+        {
+            continue;
+        }
+
+        let distance_cost = cost_per_tile * wusel_position.distance_to(&crafting_position);
+
+        let bites = blueprint.get_product_bites_by(wusel.get_ability(blueprint.get_ability()));
+
+        // The product's best use, as often as it can be used.
+        let satisfaction: f32 = world
+            .advertisements
+            .for_object_type(blueprint.get_product())
+            .iter()
+            .map(|advertisement| {
+                wusels::needs::Need::VALUES
+                    .iter()
+                    .map(|&need| {
+                        satisfaction(
+                            wusel,
+                            need,
+                            advertisement.get_effect_on(need) * bites as i64,
+                        )
+                    })
+                    .sum::<f32>()
+            })
+            .fold(0.0, f32::max);
+
+        options.push((
+            satisfaction * CRAFTING_PATIENCE - distance_cost,
+            tasks::TaskBuilder::craft(blueprint_id, blueprint.get_steps()),
+        ));
+    }
+
+    // Meet another wusel, the closest is the best.
+    let love = wusels::needs::Need::LOVE;
+    let meeting_satisfaction = satisfaction(
+        wusel,
+        love,
+        (love.get_full() as f32 * MEETING_SATISFACTION) as i64,
+    ) * sociability;
+    let flirt_satisfaction = satisfaction(
+        wusel,
+        love,
+        (love.get_full() as f32 * FLIRT_SATISFACTION) as i64,
+    ) * sociability;
+    let romance_satisfaction = satisfaction(
+        wusel,
+        love,
+        (love.get_full() as f32 * ROMANCE_SATISFACTION) as i64,
+    ) * sociability;
+
+    for (other_index, other) in world.wusels.iter().enumerate() {
+        if other_index == wusel_index || !other.is_alive() {
+            continue;
+        }
+
+        let other_position = match world
+            .wusels_index_on_position_index
+            .get(other_index)
+            .and_then(|&position_index| world.position_from_index(position_index))
+        {
+            Some(other_position) => other_position,
+            None => continue,
+        };
+
+        let distance_cost = cost_per_tile * wusel_position.distance_to(&other_position);
+        let other = world.wusel_handle_at(other_index);
+
+        options.push((
+            meeting_satisfaction - distance_cost,
+            tasks::TaskBuilder::meet_with(other, true, false).set_duration(MEETING_DURATION),
+        ));
+
+        if world.wusels_consent_to_romance(world.wusel_handle_at(wusel_index), other) {
+            options.push((
+                romance_satisfaction - distance_cost,
+                tasks::TaskBuilder::romance_with(other).set_duration(MEETING_DURATION),
+            ));
+        } else if world.wusels_may_flirt(world.wusel_handle_at(wusel_index), other) {
+            options.push((
+                flirt_satisfaction - distance_cost,
+                tasks::TaskBuilder::meet_with(other, true, true).set_duration(MEETING_DURATION),
+            ));
+        }
+    }
+
+    // Rest right here.
+    options.push((
+        satisfaction(
+            wusel,
+            wusels::needs::Need::SLEEP,
+            (REST_DURATION as i64) * task_manager::REST_SLEEP_PER_TICK as i64,
+        ),
+        tasks::TaskBuilder::rest(REST_DURATION),
+    ));
+
+    options
+}
+
+/// How much the wusel would be satisfied by changing the need.
+///
+/// The change is counted as part of the full need (as much as can be filled up),
+/// weighted by the urgency of the need: An almost empty need is far more urgent than a half full.
+fn satisfaction(wusel: &wusels::Wusel, need: wusels::needs::Need, change: i64) -> f32 {
+    let full = need.get_full() as i64;
+    let current = i64::min(wusel.get_need(need) as i64, full);
+
+    let change = change.clamp(-current, full - current);
+
+    let missing = 1.0 - current as f32 / full as f32;
+    let urgency = missing * missing;
+
+    urgency * change as f32 / full as f32
+}
+
+/// Nothing is worth it: Wander around or meet someone by chance, or just do nothing.
+fn decide_randomly(world: &mut world::World, wusel_index: usize) -> Option<tasks::TaskBuilder> {
+    let others: Vec<wusels::WuselHandle> = world
+        .wusels
+        .iter()
+        .enumerate()
+        .filter(|(other_index, other)| *other_index != wusel_index && other.is_alive())
+        .map(|(other_index, _)| world.wusel_handle_at(other_index))
+        .collect();
+
+    match world.get_rng().gen_range(0..4) {
+        0 if !others.is_empty() => {
+            let other = others[world.get_rng().gen_range(0..others.len())];
+            let wusel = world.wusel_handle_at(wusel_index);
+            let task = if world.wusels_consent_to_romance(wusel, other) {
+                // Close enough for more than a meeting.
+                tasks::TaskBuilder::romance_with(other)
+            } else if world.wusels_may_flirt(wusel, other) {
+                tasks::TaskBuilder::meet_with(other, true, true)
+            } else {
+                tasks::TaskBuilder::meet_with(other, true, false)
+            };
+            Some(task.set_duration(MEETING_DURATION))
+        }
+        1 => Some(tasks::TaskBuilder::move_to(world.position_random())),
+        _ => None, // do nothing randomly.
+    }
+}
@@ -0,0 +1,52 @@
+//! # Events
+//!
+//! Events are, what happened in the world and what a player may want to know,
+//! like a new wusel being born or dying.
+//!
+//! The world records them with the time they happened, until they are taken
+//! (see `World::events_take`), eg. to show them or to let the player give a newborn a name.
+//! They refer to the wusels by their handles, which keep the identifiers, even if the wusels are gone.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::wusels;
+
+/// Something, which happened in the world.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Event {
+    /// A new wusel was born, the other parent is optional.
+    Birth {
+        child: wusels::WuselHandle,
+        parent: wusels::WuselHandle,
+        other_parent: Option<wusels::WuselHandle>,
+    },
+
+    /// A wusel died and was buried.
+    Death {
+        wusel: wusels::WuselHandle,
+        cause: wusels::CauseOfDeath,
+    },
+
+    /// A buried wusel returned as ghost.
+    Haunting { ghost: wusels::WuselHandle },
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Birth {
+                child,
+                parent,
+                other_parent: Some(other_parent),
+            } => write!(fmt, "w{child} was born to w{parent} and w{other_parent}"),
+            Self::Birth {
+                child,
+                parent,
+                other_parent: None,
+            } => write!(fmt, "w{child} was born to w{parent}"),
+            Self::Death { wusel, cause } => write!(fmt, "w{wusel} died of {cause}"),
+            Self::Haunting { ghost } => write!(fmt, "w{ghost} returned as ghost"),
+        }
+    }
+}
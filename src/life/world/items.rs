@@ -1,50 +1,297 @@
 //! # Items in the World
 //!
-//! TODO (2023-06-13) compare against objects.rs
+//! Parts of the non-living things in the world:
+//! The components of [objects](crate::life::objects::Object) (consumables and storages),
+//! blueprints to craft new objects, and constructions (like walls and doors).
+//! Objects and constructions share one identifier space (see [EntityId](objects::EntityId)).
 //!
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
+use crate::life::objects;
 use crate::life::wusels;
 
+/// Identifier for a Blueprint (its index in the world's blueprints).
+pub type BlueprintId = usize;
+
 /// A Blueprint is a list of required abilities, consumables or positions
 ///
 /// to create a certain product after a certain time.
 /// Blueprint: [ components, Workstation ] + Time => Product.
-#[derive(Clone, PartialEq, Hash, Eq)]
-#[allow(dead_code)]
-struct Blueprint {
-    id: usize,
-    product: usize,
-    components: Vec<usize>, // needed components: such as tools (desk) or ingredients (pen, paper).
-    steps: usize,           // needed steps.
+///
+/// The components are consumed, the workstation is reserved while working on it.
+/// The better the crafting wusel's ability (compared to the difficulty), the better the product
+/// and the faster it is done. Below the difficulty, crafting may fail.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Blueprint {
+    name: String,
+    product: objects::ObjectType,
+    product_bites: u16, // consumable parts of a product made by an average crafter.
+    workstation: Option<objects::ObjectType>, // needed station: such as a stove or a desk.
+    components: Vec<objects::ObjectType>, // needed ingredients: such as tomatoes (pen, paper).
+    steps: usize,       // needed steps.
+    ability: wusels::abilities::Ability, // practised and needed ability.
+    difficulty: u32,    // ability level to surely succeed.
+}
+
+impl Blueprint {
+    /// Chance to fail lost for each level below the difficulty.
+    pub const FAILURE_PERCENT_PER_MISSING_LEVEL: u32 = 25;
+
+    /// Chance to do an extra step gained for each level above the difficulty.
+    pub const HASTE_PERCENT_PER_EXTRA_LEVEL: u32 = 10;
+
+    /// Highest chance to do an extra step.
+    pub const HASTE_PERCENT_MAX: u32 = 50;
+
+    /// Create a new blueprint for a product, which takes one step without components.
+    pub fn new(name: &str, product: objects::ObjectType, product_bites: u16) -> Self {
+        Self {
+            name: name.to_string(),
+            product,
+            product_bites,
+            workstation: None,
+            components: vec![],
+            steps: 1,
+            ability: wusels::abilities::Ability::COOKING,
+            difficulty: 0,
+        }
+    }
+
+    /// Set the workstation, where the product is crafted.
+    pub fn with_workstation(mut self, workstation: objects::ObjectType) -> Self {
+        self.workstation = Some(workstation);
+        self
+    }
+
+    /// Add a component, which is consumed for the product.
+    pub fn with_component(mut self, component: objects::ObjectType) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Set the steps, crafting the product takes.
+    pub fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = usize::max(1, steps);
+        self
+    }
+
+    /// Set the ability needed for crafting and its difficulty.
+    pub fn with_difficulty(mut self, ability: wusels::abilities::Ability, difficulty: u32) -> Self {
+        self.ability = ability;
+        self.difficulty = difficulty;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_product(&self) -> objects::ObjectType {
+        self.product
+    }
+
+    pub fn get_product_bites(&self) -> u16 {
+        self.product_bites
+    }
+
+    pub fn get_workstation(&self) -> Option<objects::ObjectType> {
+        self.workstation
+    }
+
+    pub fn get_components(&self) -> &[objects::ObjectType] {
+        &self.components
+    }
+
+    pub fn get_steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn get_ability(&self) -> wusels::abilities::Ability {
+        self.ability
+    }
+
+    pub fn get_difficulty(&self) -> u32 {
+        self.difficulty
+    }
+
+    /// Get the chance (in percent) to succeed, crafting with the ability level.
+    pub fn get_success_percent_by(&self, ability_level: u32) -> u32 {
+        let missing = self.difficulty.saturating_sub(ability_level);
+        100u32.saturating_sub(missing.saturating_mul(Self::FAILURE_PERCENT_PER_MISSING_LEVEL))
+    }
+
+    /// Get the chance (in percent) to do an extra step at once, crafting with the ability level.
+    pub fn get_haste_percent_by(&self, ability_level: u32) -> u32 {
+        let extra = ability_level.saturating_sub(self.difficulty);
+        u32::min(
+            extra.saturating_mul(Self::HASTE_PERCENT_PER_EXTRA_LEVEL),
+            Self::HASTE_PERCENT_MAX,
+        )
+    }
+
+    /// Get the consumable parts of a product, crafted with the given ability level.
+    /// Skilled crafters make up to twice as much, unskilled ones less (but at least one).
+    pub fn get_product_bites_by(&self, ability_level: u32) -> u16 {
+        let bites = self.product_bites as u32;
+        let bites = if ability_level >= self.difficulty {
+            bites + u32::min(bites, ability_level - self.difficulty)
+        } else {
+            bites * (ability_level + 1) / (self.difficulty + 1)
+        };
+        u32::max(1, bites) as u16
+    }
 }
 
-/// Something a Wusel can consume
+/// Something a Wusel can consume (a component of an object).
 ///
-/// Consumption / Usage will 'destroy' this object.
+/// Consumption / Usage will 'destroy' this object, after all bites are consumed.
 /// Consuming it might modify the needs and skills.
-#[derive(Clone, Debug)]
+///
+/// Sometimes, a consumable can spoil: It ages every day,
+/// a storage (like a fridge) may slow the aging down.
+/// Consuming a spoiled consumable harms the health.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Consumable {
-    name: String,
-
-    // Size representation: whole = 100% = size/size.
-    size: u32, // a size representation: consuming this [size]  times, the thing is gone. (fixed)
-    available: f32, // 1.0f whole, 0.0f gone. (temporary)
+    // Size representation: whole = bites/bites.
+    bites: u16,      // consuming this [bites] times, the thing is gone. (fixed)
+    bites_left: u16, // (temporary)
 
     // Sometimes, a consumable can spoil (> 0)
     spoils_after: u32, // spoils after 0: infinite, or N days. (fixed)
-    age: u32,          // the current age of the consumable (temporary)
+    age: u32,          // the current age of the consumable, in hundredths of days (temporary)
 
-    // While consuming it, one part (1/size) while change the needs as following.
+    // While consuming it, one part (bite) will change the needs as following.
     need_change: std::collections::HashMap<wusels::needs::Need, i16>,
 }
 
-/// Identifier for a Construction
-pub type ConstructionId = usize;
+impl Consumable {
+    /// Age gained each day, in hundredths of days.
+    pub const AGING_PER_DAY: u32 = 100;
+
+    /// Create a new (fresh) consumable with some bites, which spoils after some days (0: never).
+    pub fn new(bites: u16, spoils_after: u32) -> Self {
+        Self {
+            bites,
+            bites_left: bites,
+            spoils_after,
+            age: 0,
+            need_change: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Create a fresh and whole consumable like this.
+    pub fn fresh(&self) -> Self {
+        Self {
+            bites_left: self.bites,
+            age: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Add a change of a need, for every consumed part.
+    pub fn with_need_change(mut self, need: wusels::needs::Need, change: i16) -> Self {
+        self.need_change.insert(need, change);
+        self
+    }
+
+    pub fn get_bites(&self) -> u16 {
+        self.bites
+    }
+
+    pub fn get_bites_left(&self) -> u16 {
+        self.bites_left
+    }
+
+    pub fn set_bites_left(&mut self, bites_left: u16) {
+        self.bites_left = bites_left;
+    }
+
+    pub fn get_spoils_after(&self) -> u32 {
+        self.spoils_after
+    }
+
+    pub fn set_spoils_after(&mut self, spoils_after: u32) {
+        self.spoils_after = spoils_after;
+    }
+
+    /// Get the age in (started) days.
+    pub fn get_age(&self) -> u32 {
+        self.age / Self::AGING_PER_DAY
+    }
+
+    pub fn get_need_changes(&self) -> &std::collections::HashMap<wusels::needs::Need, i16> {
+        &self.need_change
+    }
+
+    /// Let the consumable age for a day, in percent of a normal day (a fridge ages slower).
+    /// Returns true, if it just got spoiled.
+    pub fn age_by(&mut self, percent: u32) -> bool {
+        let was_spoiled = self.is_spoiled();
+        self.age = self
+            .age
+            .saturating_add(percent.saturating_mul(Self::AGING_PER_DAY) / 100);
+        !was_spoiled && self.is_spoiled()
+    }
+
+    /// Check if the consumable is spoiled.
+    pub fn is_spoiled(&self) -> bool {
+        self.spoils_after > 0 && self.age >= self.spoils_after.saturating_mul(Self::AGING_PER_DAY)
+    }
+}
+
+/// Something, which keeps other objects (a component of an object), like a fridge or a bag.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Storage {
+    capacity: u16,      // objects, that can be stored. (fixed)
+    capacity_left: u16, // (temporary)
+    aging_percent: u32, // how fast stored objects age (a fridge slows it down).
+}
+
+impl Storage {
+    /// Create a new empty storage, where objects age like everywhere else.
+    pub fn new(capacity: u16) -> Self {
+        Self {
+            capacity,
+            capacity_left: capacity,
+            aging_percent: 100,
+        }
+    }
+
+    /// Create an empty storage like this.
+    pub fn empty(&self) -> Self {
+        Self {
+            capacity_left: self.capacity,
+            ..self.clone()
+        }
+    }
+
+    pub fn get_capacity(&self) -> u16 {
+        self.capacity
+    }
+
+    pub fn get_capacity_left(&self) -> u16 {
+        self.capacity_left
+    }
+
+    pub fn set_capacity_left(&mut self, capacity_left: u16) {
+        self.capacity_left = capacity_left;
+    }
+
+    pub fn get_aging_percent(&self) -> u32 {
+        self.aging_percent
+    }
+
+    pub fn set_aging_percent(&mut self, aging_percent: u32) {
+        self.aging_percent = aging_percent;
+    }
+}
+
+/// Identifier for a Construction (shared with the objects).
+pub type ConstructionId = objects::EntityId;
 
 /// Type and type attributes of a Construction.
-#[derive(Clone, Copy, PartialEq, Hash, Eq)]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ConstructionType {
     Wall(bool, usize), // is_horizontal (grows left->right, otherwise up->down), length
     Door(bool),        // is_open
@@ -53,6 +300,34 @@ pub enum ConstructionType {
     Floor,
 }
 
+impl ConstructionType {
+    /// Check if a wusel can walk through this construction.
+    /// Walls, windows and closed doors are in the way.
+    pub fn is_passable(&self) -> bool {
+        match self {
+            Self::Wall(_, _) | Self::Window => false,
+            Self::Door(is_open) => *is_open,
+            Self::Stairs(_) | Self::Floor => true,
+        }
+    }
+
+    /// Check if this construction separates rooms.
+    /// Walls, doors and windows enclose a room, open or closed.
+    pub fn is_room_boundary(&self) -> bool {
+        matches!(self, Self::Wall(_, _) | Self::Door(_) | Self::Window)
+    }
+
+    /// Check if light and view can go through this construction.
+    /// Windows let light through, even if they cannot be passed.
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            Self::Wall(_, _) => false,
+            Self::Door(is_open) => *is_open,
+            Self::Window | Self::Stairs(_) | Self::Floor => true,
+        }
+    }
+}
+
 pub const WALL_LR: bool = true; // is horizontal
 pub const WALL_UD: bool = false; // is not horizontal
                                  //
@@ -63,17 +338,28 @@ pub const DOOR_CLOSED: bool = false;
 ///
 /// They offer only just few options to interact with.
 /// Mostly they block ways and are there to build and present place for the world.
-#[derive(Clone, Copy, PartialEq, Hash, Eq)]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Construction {
     id: ConstructionId,
     construction_type: ConstructionType, // TODO better type.
+    position_index: usize,               // where it starts (a wall grows from there).
+
+    owner: Option<wusels::WuselId>, // who may lock and unlock it (None: everyone).
+    is_locked: bool,                // a locked door can only be opened by the owner.
 }
 
 impl Construction {
-    pub fn new(id: ConstructionId, construction_type: ConstructionType) -> Self {
+    pub fn new(
+        id: ConstructionId,
+        construction_type: ConstructionType,
+        position_index: usize,
+    ) -> Self {
         Self {
             id,
             construction_type,
+            position_index,
+            owner: None,
+            is_locked: false,
         }
     }
 
@@ -84,4 +370,36 @@ impl Construction {
     pub fn construction_type(&self) -> ConstructionType {
         self.construction_type
     }
+
+    /// Get the position index, where the construction starts.
+    pub fn get_position_index(&self) -> usize {
+        self.position_index
+    }
+
+    /// Change the state of the construction, eg. open or close a door.
+    pub fn set_construction_type(&mut self, construction_type: ConstructionType) {
+        self.construction_type = construction_type;
+    }
+
+    pub fn get_owner(&self) -> Option<wusels::WuselId> {
+        self.owner
+    }
+
+    pub fn set_owner(&mut self, owner: Option<wusels::WuselId>) {
+        self.owner = owner;
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    pub fn set_locked(&mut self, is_locked: bool) {
+        self.is_locked = is_locked;
+    }
+
+    /// Check if the wusel has the permission to lock and unlock this construction.
+    /// Without an owner, everyone has the permission.
+    pub fn has_permission(&self, wusel_id: wusels::WuselId) -> bool {
+        self.owner.is_none_or(|owner| owner == wusel_id)
+    }
 }
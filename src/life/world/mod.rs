@@ -5,16 +5,25 @@
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
+use crate::life;
 use crate::life::objects;
 use crate::life::wusels;
 use crate::life::wusels::tasks;
+use crate::util;
 
-use rand;
+use rand::{Rng, SeedableRng};
 
+pub mod actions;
+pub mod advertisements;
 pub mod areas;
+pub mod events;
 pub mod items;
+pub mod save;
+pub mod scenario;
 
 // engine.
+mod communication;
+mod decisions;
 mod task_manager;
 mod task_test;
 mod unit_tests;
@@ -35,7 +44,14 @@ mod unit_tests;
 // TODO (2023-06-13) world.items_set(id) // update item.
 // TODO (2023-06-13) world.items_get(id) // data.
 
+/// Random generator of a world.
+///
+/// Every random decision of the world is made with it,
+/// so the same seed and the same inputs lead to the same world.
+pub type WorldRng = rand_chacha::ChaCha8Rng;
+
 ///  The place of existence, time and relations.
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct World {
     width: u32,
     depth: u32,
@@ -43,10 +59,16 @@ pub struct World {
 
     area: areas::Area,
     position_upper_bound: usize,
-    positions: Vec<Vec<PlaceTaker>>,
+    #[serde(skip)]
+    positions: Vec<Vec<PlaceTaker>>, // recalculated on load.
+    topology_revision: usize, // increased, whenever passable positions may have changed.
+    occupancy: OccupancyPolicy,
 
     clock: usize, // time of the world.
 
+    seed: u64,     // seed of the random generator, to reproduce the world.
+    rng: WorldRng, // saved with its state, so a loaded world continues the same.
+
     sequential_wusel_id: wusels::WuselId,
 
     // all currently living wusel in map.
@@ -54,41 +76,49 @@ pub struct World {
     wusels_index_with_id: Vec<wusels::WuselId>,
     wusels_index_on_position_index: Vec<usize>,
 
-    sequential_object_id: objects::ObjectId,
+    sequential_entity_id: objects::EntityId, // shared by objects and constructions.
 
     // all current object instances in world.
     objects: Vec<objects::Object>,
-    objects_index_with_id: Vec<objects::ObjectId>,
-    objects_index_with_type: Vec<objects::ObjectType>,
-    objects_index_with_whereabouts: Vec<InWorld>,
 
     // all constructions
     constructions: Vec<items::Construction>,
-    constructions_index_on_position_index: Vec<usize>,
+
+    // all rooms, enclosed by constructions.
+    sequential_room_id: areas::RoomId,
+    rooms: Vec<areas::Room>,
+    #[serde(skip)]
+    rooms_on_position_index: Vec<Option<areas::RoomId>>, // recalculated on load.
+
+    #[serde(default)]
+    autonomous: bool, // idle wusels decide on their own, what to do next.
+
+    pregnancy_days: u8, // days from conception to birth.
+
+    life_stages: wusels::stages::LifeStages, // days, when wusels grow up (and grow old).
+
+    #[serde(default)]
+    events: Vec<(usize, events::Event)>, // what happened (and when), until it is taken.
 
     // actions in this world.
-    actions: Vec<String>,                      // actions to do.
-    actions_effects: Vec<tasks::ActionAffect>, // how various actions on various objects may influence
+    actions: Vec<actions::Action>, // verbs, the index is the action ID.
+    advertisements: advertisements::Advertisements, // how various actions on various objects may influence
+
+    // crafting in this world.
+    blueprints: Vec<items::Blueprint>, // recipes, the index is the blueprint ID.
+    workstations_reserved: std::collections::BTreeMap<objects::ObjectId, wusels::WuselId>, // who works where.
 
     // more world information ...
-    #[allow(dead_code)]
-    dead_wusels: Vec<wusels::Wusel>,
+    dead_wusels: Vec<wusels::Wusel>, // the graveyard, in order of their deaths.
+    #[serde(default)]
+    memorials: std::collections::BTreeMap<objects::ObjectId, wusels::WuselId>, // tombstones and urns of the dead.
 
     #[allow(dead_code)]
+    #[serde(with = "save::relations")]
     relations:
         std::collections::BTreeMap<(wusels::WuselId, wusels::WuselId), wusels::relations::Relation>, // vector of wusel relations
 }
 
-/// State (in a sum type) with Positional Data for the world.
-#[derive(Clone, Copy, PartialEq, Hash, Eq)]
-enum InWorld {
-    OnPositionIndex(usize),
-    #[allow(dead_code)]
-    InStorageId(objects::ObjectId),
-    HeldByWuselId(wusels::WuselId),
-    Nowhere,
-}
-
 /// A type wrapped identifier that represents something in the world.
 #[derive(Clone, Copy, PartialEq, Hash, Eq)]
 pub enum PlaceTaker {
@@ -97,12 +127,45 @@ pub enum PlaceTaker {
     Object(objects::ObjectId, objects::ObjectType),
 }
 
+/// Rules, which place takers can share a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OccupancyPolicy {
+    pub wusels_exclusive: bool, // a wusel cannot step on a position with another wusel.
+    pub blocking_objects_exclusive: bool, // blocking objects are placed only on empty positions.
+    pub only_stackables_share: bool, // objects only share a position, if all are stackable.
+    pub patience_to_pass: usize, // ticks a walker waits for another wusel, before walking around.
+    pub patience_to_give_up: usize, // ticks a walker waits at all, before they stop walking.
+}
+
+impl Default for OccupancyPolicy {
+    fn default() -> Self {
+        Self {
+            wusels_exclusive: true,
+            blocking_objects_exclusive: true,
+            only_stackables_share: true,
+            patience_to_pass: 1,
+            patience_to_give_up: 20,
+        }
+    }
+}
+
 // TODO split up engine like, updater, getter, etc.
 impl World {
-    /// Create a new world.
+    /// Create a new world with only one level.
     pub fn new(width: u32, depth: u32) -> Self {
-        let height = 1;
-        let position_upper_bound: usize = (width * depth * height) as usize;
+        Self::new_with_levels(width, depth, 1)
+    }
+
+    /// Create a new world with multiple levels (height), which can be connected by stairs.
+    /// The world's random generator starts with a random seed, see [World::set_seed].
+    ///
+    /// Panics, if the world would have more than [World::MAX_POSITIONS] positions,
+    /// see [World::position_count] to check the size before.
+    pub fn new_with_levels(width: u32, depth: u32, height: u32) -> Self {
+        let seed: u64 = rand::random();
+        let height = u32::max(1, height);
+        let position_upper_bound: usize = Self::position_count(width, depth, height)
+            .unwrap_or_else(|| panic!("World {}x{}x{} is too large.", width, depth, height));
         Self {
             width,
             depth,
@@ -111,31 +174,115 @@ impl World {
             area: areas::Area::new(areas::Position::ROOT, width, depth, height),
             position_upper_bound,
             positions: vec![vec![]; position_upper_bound],
+            topology_revision: 0,
+            occupancy: OccupancyPolicy::default(),
 
             clock: 0,
 
+            seed,
+            rng: WorldRng::seed_from_u64(seed),
+
             sequential_wusel_id: 0,
 
             wusels: vec![],
             wusels_index_with_id: vec![],
             wusels_index_on_position_index: vec![],
 
-            sequential_object_id: 0,
+            sequential_entity_id: 0,
 
             objects: vec![],
-            objects_index_with_id: vec![],
-            objects_index_with_type: vec![],
-            objects_index_with_whereabouts: vec![],
 
             constructions: vec![],
-            constructions_index_on_position_index: vec![],
+
+            sequential_room_id: 0,
+            rooms: vec![],
+            rooms_on_position_index: vec![None; position_upper_bound],
 
             dead_wusels: vec![],
+            memorials: std::collections::BTreeMap::new(),
             relations: std::collections::BTreeMap::new(),
 
-            actions: vec![],
-            actions_effects: vec![],
+            autonomous: false,
+
+            pregnancy_days: Self::PREGNANCY_DAYS,
+
+            life_stages: wusels::stages::LifeStages::default(),
+
+            events: vec![],
+
+            actions: Self::default_actions(),
+            advertisements: Self::default_advertisements(),
+
+            blueprints: Self::default_blueprints(),
+            workstations_reserved: std::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Save the complete world into a file.
+    /// The file format is described in [save](crate::life::world::save).
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(&self.to_document()?)?)
+    }
+
+    /// Load a complete world from a file, which was saved before (maybe with an older version).
+    /// The file format is described in [save](crate::life::world::save).
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        Self::from_document(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Get the complete world as save document (see [save](crate::life::world::save)).
+    pub fn to_document(&self) -> std::io::Result<serde_json::Value> {
+        Ok(save::wrap(serde_json::to_value(self)?))
+    }
+
+    /// Restore a complete world from a save document (maybe with an older version).
+    pub fn from_document(document: serde_json::Value) -> std::io::Result<Self> {
+        let mut world: Self = serde_json::from_value(save::unwrap_and_migrate(document)?)?;
+
+        let position_count = Self::position_count(world.width, world.depth, world.height)
+            .ok_or_else(|| save::invalid_data("World in save file is too large.".to_string()))?;
+
+        let is_consistent = world.position_upper_bound == position_count
+            && world.wusels.len() == world.wusels_index_with_id.len()
+            && world.wusels.len() == world.wusels_index_on_position_index.len();
+
+        if !is_consistent {
+            return Err(save::invalid_data(
+                "Inconsistent world in save file.".to_string(),
+            ));
+        }
+
+        // Recalculate the positional caches.
+        world.positions = vec![vec![]; world.position_upper_bound];
+        world.recalculate_positions_for_all_placetakers();
+
+        world.rooms_on_position_index = vec![None; world.position_upper_bound];
+        for room in world.rooms.iter() {
+            for position in room.get_positions() {
+                if world.has_position(position) {
+                    let position_index = world.position_to_index(position);
+                    world.rooms_on_position_index[position_index] = Some(room.id());
+                }
+            }
         }
+
+        Ok(world)
+    }
+
+    /// Get the seed, the world's random generator was started with.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restart the world's random generator with a new seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = WorldRng::seed_from_u64(seed);
+    }
+
+    /// Get the world's random generator, to make a random decision in this world.
+    pub fn get_rng(&mut self) -> &mut WorldRng {
+        &mut self.rng
     }
 
     /// Get the world's current time.
@@ -148,9 +295,10 @@ impl World {
         self.clock += 1;
 
         // A new day is over: Forward the day structure to the world.
-        let new_day: bool = self.clock % Self::TICKS_PER_DAY == 0;
+        let new_day: bool = self.clock.is_multiple_of(Self::TICKS_PER_DAY);
 
         let mut some_busy_wusel: Vec<wusels::WuselId> = vec![];
+        let mut some_idle_wusel: Vec<usize> = vec![];
         let mut new_babies: Vec<(
             wusels::WuselId,
             Option<wusels::WuselId>,
@@ -167,37 +315,59 @@ impl World {
             // This may lead to remove the done task.
             if !wusel.has_tasklist_empty() {
                 some_busy_wusel.push(i);
-            } else {
+            } else if wusel.can_act() {
                 // Wusel is currently not busy. => maybe apply an idle/auto task.
+                some_idle_wusel.push(i);
             }
 
-            // If pregnant: Maybe push out the child => Failure, Early or too late.
+            wusel.wusel_tick(new_day);
+
+            // If pregnant: Maybe push out the child => When due, or rarely a day early.
             if wusel.is_pregnant() {
-                let other_parent: Option<wusels::WuselId> = wusel.get_other_parent();
-                let pregnancy_days: Option<u8> = wusel.get_remaining_pregnancy_days();
-                let maybe_now: u8 = rand::random::<u8>() % 100;
-                let possibility: u8 = match pregnancy_days {
-                    Some(0) => 90,
-                    Some(1) => 75,
-                    _ => 10,
+                let possibility: u8 = match wusel.get_remaining_pregnancy_days() {
+                    Some(0) => Self::BIRTH_PERCENT_WHEN_DUE, // on every tick, once due.
+                    Some(1) if new_day => Self::BIRTH_PERCENT_EARLY, // once, a day before.
+                    _ => 0,
                 };
-                if (0u8..possibility).contains(&maybe_now) {
+                if possibility > 0 && self.rng.gen_range(0..100) < possibility {
                     log::debug!("Pop the baby!");
-                    let gender = wusels::WuselGender::random();
+                    let other_parent: Option<wusels::WuselId> = wusel.get_other_parent();
+                    let gender = wusels::WuselGender::random(&mut self.rng);
                     new_babies.push((wusel.get_id(), other_parent, gender));
                     // end pregnancy.
                     wusel.set_pregnancy(None, None);
                 }
             }
 
-            let alive = wusel.wusel_tick(new_day);
+            // A new day: Grow up, and the old may die of old age.
+            if new_day && wusel.is_alive() {
+                let lived_days = wusel.get_lived_days();
+                wusel.set_life_stage(self.life_stages.stage_for(lived_days));
+
+                if let Some(days_beyond) = lived_days.checked_sub(self.life_stages.old_age) {
+                    let percent = (days_beyond + 1)
+                        .saturating_mul(Self::OLD_AGE_DEATH_PERCENT_PER_DAY as u32);
+                    if self.rng.gen_range(0..100) < percent {
+                        wusel.die(wusels::CauseOfDeath::OldAge);
+                    }
+                }
+            }
 
-            // The wusel just died. Remove if from active wusels later.
-            if !alive {
+            // The wusel just died (or was declared dead). Bury them later.
+            if !wusel.can_act() {
                 dying_wusels.push(i);
             }
         }
 
+        // The dead do nothing anymore.
+        some_busy_wusel.retain(|i| !dying_wusels.contains(i));
+        some_idle_wusel.retain(|i| !dying_wusels.contains(i));
+
+        // A new day: All food ages.
+        if new_day {
+            self.objects_age();
+        }
+
         // Execute ongoing tasks, unmutable wusel context..
         for w in some_busy_wusel.iter() {
             if let Some(t) = self.wusels[*w].peek_ongoing_task() {
@@ -207,26 +377,452 @@ impl World {
             }
         }
 
+        // Let idle wusels decide, what to do next.
+        if self.autonomous {
+            for &wusel_index in some_idle_wusel.iter() {
+                if let Some(task) = decisions::decide(self, wusel_index) {
+                    self.wusels[wusel_index].assign_to_task(self.clock, task);
+                }
+            }
+        }
+
         for _ in self.relations.iter() {
             // Decay of relations over time.
         }
 
         // Command further name giving and attention from the player.
-        for baby in new_babies.iter() {
+        for (parent_id, other_parent_id, gender) in new_babies.into_iter() {
             log::debug!(
                 "New parents {}  and {} ({})",
-                baby.0,
-                baby.1.unwrap_or(usize::MAX),
-                baby.2.to_char(),
+                parent_id,
+                other_parent_id.unwrap_or(usize::MAX),
+                gender.to_char(),
             );
-            // put babies to the wusel set.
+            self.wusel_give_birth(parent_id, other_parent_id, gender);
+        }
+
+        // Bury the dead, the last first, so the other indices stay valid.
+        for &wusel_index in dying_wusels.iter().rev() {
+            self.wusel_bury(wusel_index);
+        }
+
+        // A new day: The buried may return as ghosts.
+        if new_day {
+            self.dead_wusels_haunt();
         }
     }
 
-    // const WORLD_INVENTORY: Where = InWorld::InStorageId((objects::ObjectType::Miscellaneous, "World-Storage", 0));
+    /// Take all events, which happened since they were taken the last time,
+    /// with the time they happened.
+    pub fn events_take(&mut self) -> Vec<(usize, events::Event)> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Get the count of positions of a world with the given size,
+    /// or None, if that are more than [World::MAX_POSITIONS].
+    pub fn position_count(width: u32, depth: u32, height: u32) -> Option<usize> {
+        (width as usize)
+            .checked_mul(depth as usize)
+            .and_then(|count| count.checked_mul(height as usize))
+            .filter(|&count| count <= Self::MAX_POSITIONS)
+    }
+
+    /// The most positions a world may have, so it still fits into memory.
+    pub const MAX_POSITIONS: usize = 1 << 22;
 
     pub const TICKS_PER_DAY: usize = 2880; // 24h by 0.5 minutes
 
+    /// Days, until food spoils (outside of a fridge).
+    pub const FOOD_SPOILS_AFTER_DAYS: u32 = 7;
+
+    /// Health lost by eating a bite of spoiled food.
+    pub const SPOILED_HEALTH_PER_BITE: i16 = -(life::DAY as i16);
+
+    /// Objects, a wusel can hold at once (bags with their contents count as one).
+    pub const WUSEL_CARRY_LIMIT: usize = 2;
+
+    /// Distance to the crafting place, within a crafter reaches stored components
+    /// (eg. a fridge next to the stove).
+    pub const CRAFTING_REACH: f32 = 3.0;
+
+    /// Default days from conception to birth.
+    pub const PREGNANCY_DAYS: u8 = 3;
+
+    /// Chance (in percent) to give birth on a tick, once the pregnancy is due.
+    pub const BIRTH_PERCENT_WHEN_DUE: u8 = 10;
+
+    /// Chance (in percent) to give birth a day before the pregnancy is due.
+    pub const BIRTH_PERCENT_EARLY: u8 = 5;
+
+    /// Romance, two wusels need to consent to a romance, which may lead to a child.
+    pub const ROMANCE_TO_CONSENT: i32 = 20;
+
+    /// Chance (in percent) to conceive a child after a consented romance.
+    pub const CONCEPTION_PERCENT: u8 = 30;
+
+    /// Kindred up to this distance (parents, kids, siblings, grandparents) do not consent to romance.
+    pub const KINDRED_TABOO_DISTANCE: u32 = 2;
+
+    /// Chance (in percent) to die of old age on a day beyond old age, growing with every further day.
+    pub const OLD_AGE_DEATH_PERCENT_PER_DAY: u8 = 5;
+
+    /// Fun and love, a wusel loses, when their closest one dies (others are grieved less).
+    pub const GRIEF: u32 = life::DAY;
+
+    /// Chance (in percent) for each buried wusel with a memorial, to return as ghost on a new day.
+    pub const GHOST_PERCENT: u8 = 5;
+
+    /// Memorial for a wusel, who died outdoors.
+    pub const MEMORIAL_TOMBSTONE: objects::ObjectSubtype = "Tombstone";
+
+    /// Memorial for a wusel, who died indoors.
+    pub const MEMORIAL_URN: objects::ObjectSubtype = "Urn";
+
+    /// Action ID to just view an object.
+    pub const ACTION_VIEW: tasks::ActionId = 0;
+
+    /// Action ID to take (and hold) an object.
+    pub const ACTION_TAKE: tasks::ActionId = 1;
+
+    /// Action ID to drop a held object.
+    pub const ACTION_DROP: tasks::ActionId = 2;
+
+    /// Action ID to consume (a bite of) an object.
+    pub const ACTION_CONSUME: tasks::ActionId = 3;
+
+    /// Actions, every world starts with, in the order of their IDs.
+    pub fn default_actions() -> Vec<actions::Action> {
+        vec![
+            actions::Action::new("View"),
+            actions::Action::new("Take")
+                .with_precondition(actions::Precondition::NotHeld)
+                .with_completion_effect(actions::Effect::Take),
+            actions::Action::new("Drop")
+                .with_precondition(actions::Precondition::HeldByActor)
+                .with_completion_effect(actions::Effect::Drop),
+            actions::Action::new("Consume")
+                .with_precondition(actions::Precondition::NotHeldByOthers)
+                .with_precondition(actions::Precondition::Consumable)
+                .with_step_effect(actions::Effect::ConsumeBite),
+        ]
+    }
+
+    /// Register a new action and get its ID.
+    /// An action with the same name is replaced and keeps its ID.
+    pub fn action_register(&mut self, action: actions::Action) -> tasks::ActionId {
+        match self.action_find(action.get_name()) {
+            Some(action_id) => {
+                self.actions[action_id] = action;
+                action_id
+            }
+            None => {
+                self.actions.push(action);
+                self.actions.len() - 1
+            }
+        }
+    }
+
+    /// Get an action by its ID.
+    pub fn action_get(&self, action_id: tasks::ActionId) -> Option<&actions::Action> {
+        self.actions.get(action_id)
+    }
+
+    /// Find the ID of an action by its name.
+    pub fn action_find(&self, name: &str) -> Option<tasks::ActionId> {
+        self.actions
+            .iter()
+            .position(|action| action.get_name() == name)
+    }
+
+    /// How much a bite of food satisfies.
+    pub const FOOD_PER_BITE: i16 = (life::DAY / 2) as i16;
+
+    /// Advertisements, every world starts with: Eating food satisfies hunger.
+    pub fn default_advertisements() -> advertisements::Advertisements {
+        let mut defaults = advertisements::Advertisements::new();
+        defaults.register(
+            advertisements::Advertisement::new(
+                advertisements::Advertiser::AnyFood,
+                Self::ACTION_CONSUME,
+            )
+            .with_effect(wusels::needs::Need::FOOD, Self::FOOD_PER_BITE),
+        );
+        defaults
+    }
+
+    /// Register what an action on objects offers.
+    /// An earlier advertisement of the same advertiser and action is replaced.
+    pub fn advertisement_register(&mut self, advertisement: advertisements::Advertisement) {
+        self.advertisements.register(advertisement);
+    }
+
+    /// Get all advertisements of this world.
+    pub fn advertisement_get_all(&self) -> &[advertisements::Advertisement] {
+        self.advertisements.get_all()
+    }
+
+    /// Get what an object offers, one advertisement per action.
+    pub fn object_get_advertisements(
+        &self,
+        object: objects::ObjectHandle,
+    ) -> Vec<advertisements::Advertisement> {
+        self.object_index(object)
+            .map(|object_index| {
+                self.advertisements
+                    .for_object_type(self.objects[object_index].get_object_type())
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Blueprints, every world starts with: Cooking meals on a stove.
+    pub fn default_blueprints() -> Vec<items::Blueprint> {
+        let stove = objects::ObjectType::Furniture("Stove");
+        vec![
+            items::Blueprint::new("Tomato Soup", objects::ObjectType::Food("Tomato Soup"), 4)
+                .with_workstation(stove)
+                .with_component(objects::ObjectType::Food("Tomato"))
+                .with_component(objects::ObjectType::Food("Tomato"))
+                .with_steps(20)
+                .with_difficulty(wusels::abilities::Ability::COOKING, 1),
+            items::Blueprint::new("Bibimbap", objects::ObjectType::Food("Bibimbap"), 6)
+                .with_workstation(stove)
+                .with_component(objects::ObjectType::Food("Rice"))
+                .with_component(objects::ObjectType::Food("Vegetables"))
+                .with_component(objects::ObjectType::Food("Egg"))
+                .with_steps(40)
+                .with_difficulty(wusels::abilities::Ability::COOKING, 3),
+        ]
+    }
+
+    /// Register a blueprint, to let wusels craft its product.
+    /// A blueprint with the same name is replaced, but keeps its ID.
+    ///
+    /// Returns the ID of the blueprint.
+    pub fn blueprint_register(&mut self, blueprint: items::Blueprint) -> items::BlueprintId {
+        match self.blueprint_find(blueprint.get_name()) {
+            Some(blueprint_id) => {
+                self.blueprints[blueprint_id] = blueprint;
+                blueprint_id
+            }
+            None => {
+                self.blueprints.push(blueprint);
+                self.blueprints.len() - 1
+            }
+        }
+    }
+
+    /// Get a blueprint by its ID.
+    pub fn blueprint_get(&self, blueprint_id: items::BlueprintId) -> Option<&items::Blueprint> {
+        self.blueprints.get(blueprint_id)
+    }
+
+    /// Get all blueprints of this world, the index is the blueprint ID.
+    pub fn blueprint_get_all(&self) -> &[items::Blueprint] {
+        &self.blueprints
+    }
+
+    /// Find the ID of a blueprint by its name.
+    pub fn blueprint_find(&self, name: &str) -> Option<items::BlueprintId> {
+        self.blueprints
+            .iter()
+            .position(|blueprint| blueprint.get_name() == name)
+    }
+
+    /// Find the components for a blueprint, the wusel can gather, crafting at the given position:
+    /// Objects held by the wusel (also in their bags) or kept in storages within the
+    /// [crafting reach](World::CRAFTING_REACH), not held by others.
+    /// Spoiled components are not used.
+    ///
+    /// Returns None, if a component is missing.
+    pub fn blueprint_find_components(
+        &self,
+        blueprint_id: items::BlueprintId,
+        wusel: wusels::WuselHandle,
+        crafting_position: areas::Position,
+    ) -> Option<Vec<objects::ObjectHandle>> {
+        let wusel_id = wusel.get_id();
+        let blueprint = self.blueprint_get(blueprint_id)?;
+        let mut found: Vec<objects::ObjectHandle> = vec![];
+
+        for &component in blueprint.get_components() {
+            let component = (0..self.objects.len())
+                .filter(|&object_index| self.objects[object_index].get_object_type() == component)
+                .filter(|&object_index| !self.objects[object_index].is_spoiled())
+                .filter(|&object_index| {
+                    let held_by = self.objects_index_get_holder(object_index);
+                    let stored_within_reach = matches!(
+                        self.objects[object_index].get_whereabouts(),
+                        objects::Whereabouts::InStorageId(_)
+                    ) && self
+                        .objects_index_get_position(object_index)
+                        .is_some_and(|position| {
+                            position.distance_to(&crafting_position) <= Self::CRAFTING_REACH
+                        });
+                    held_by == Some(wusel_id) || (held_by.is_none() && stored_within_reach)
+                })
+                .map(|object_index| self.object_handle_at(object_index))
+                .find(|object| !found.contains(object))?;
+            found.push(component);
+        }
+
+        Some(found)
+    }
+
+    /// Find the closest workstation for a blueprint, the wusel could work on.
+    ///
+    /// Returns None, if the blueprint needs no workstation or all are taken.
+    pub fn blueprint_find_workstation(
+        &self,
+        blueprint_id: items::BlueprintId,
+        wusel: wusels::WuselHandle,
+        position: areas::Position,
+    ) -> Option<objects::ObjectHandle> {
+        let workstation = self.blueprint_get(blueprint_id)?.get_workstation()?;
+
+        (0..self.objects.len())
+            .filter(|&object_index| self.objects[object_index].get_object_type() == workstation)
+            .filter(|&object_index| {
+                self.workstation_is_free_for(self.object_handle_at(object_index), wusel)
+            })
+            .filter_map(|object_index| {
+                self.objects_index_get_position(object_index)
+                    .map(|station| (object_index, position.distance_to(&station)))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(object_index, _)| self.object_handle_at(object_index))
+    }
+
+    /// Get the wusel, who currently works on the workstation.
+    pub fn workstation_get_worker(
+        &self,
+        workstation: objects::ObjectHandle,
+    ) -> Option<wusels::WuselHandle> {
+        let worker = self.wusel_handle(*self.workstations_reserved.get(&workstation.get_id())?)?;
+
+        // A reservation only lasts, while the worker is still crafting.
+        let is_crafting = self
+            .wusel_peek_ongoing_task(worker)
+            .is_some_and(|task| matches!(task.get_passive_part(), tasks::TaskTag::Craft(_)));
+
+        is_crafting.then_some(worker)
+    }
+
+    /// Check if the workstation is free or already reserved by the wusel.
+    pub fn workstation_is_free_for(
+        &self,
+        workstation: objects::ObjectHandle,
+        wusel: wusels::WuselHandle,
+    ) -> bool {
+        self.workstation_get_worker(workstation)
+            .is_none_or(|worker| worker == wusel)
+    }
+
+    /// Reserve the workstation for the wusel, if it is free.
+    ///
+    /// Returns false, if another wusel works on it.
+    pub fn workstation_reserve(
+        &mut self,
+        workstation: objects::ObjectHandle,
+        wusel: wusels::WuselHandle,
+    ) -> bool {
+        if self.object_index(workstation).is_none()
+            || self.wusel_index(wusel).is_none()
+            || !self.workstation_is_free_for(workstation, wusel)
+        {
+            return false;
+        }
+        self.workstations_reserved
+            .insert(workstation.get_id(), wusel.get_id());
+        true
+    }
+
+    /// Release the workstation, if the wusel reserved it.
+    pub fn workstation_release(
+        &mut self,
+        workstation: objects::ObjectHandle,
+        wusel: wusels::WuselHandle,
+    ) {
+        let wusel_id = wusel.get_id();
+        let workstation_id = workstation.get_id();
+        if self.workstations_reserved.get(&workstation_id) == Some(&wusel_id) {
+            self.workstations_reserved.remove(&workstation_id);
+        }
+    }
+
+    /// Find the objects, which satisfy the need within a distance around the position.
+    /// Returns the objects with the satisfying action and their distance, closest first.
+    pub fn object_find_satisfying(
+        &self,
+        need: wusels::needs::Need,
+        position: areas::Position,
+        max_distance: f32,
+    ) -> Vec<(objects::ObjectHandle, tasks::ActionId, f32)> {
+        let mut found: Vec<(objects::ObjectHandle, tasks::ActionId, f32)> = vec![];
+
+        for (object_index, object) in self.objects.iter().enumerate() {
+            let distance = match self.objects_index_get_position(object_index) {
+                Some(object_position) => object_position.distance_to(&position),
+                None => continue,
+            };
+
+            if distance > max_distance {
+                continue;
+            }
+
+            for advertisement in self
+                .advertisements
+                .for_object_type(object.get_object_type())
+            {
+                if advertisement.satisfies(need) {
+                    found.push((
+                        self.object_handle_at(object_index),
+                        advertisement.get_action_id(),
+                        distance,
+                    ));
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.2.total_cmp(&b.2));
+        found
+    }
+
+    /// Check, if idle wusels decide on their own, what to do next.
+    pub fn is_autonomous(&self) -> bool {
+        self.autonomous
+    }
+
+    /// Let idle wusels decide on their own (or not), what to do next.
+    pub fn set_autonomous(&mut self, autonomous: bool) {
+        self.autonomous = autonomous;
+    }
+
+    /// Get the days from conception to birth.
+    pub fn get_pregnancy_days(&self) -> u8 {
+        self.pregnancy_days
+    }
+
+    /// Set the days from conception to birth, for all following pregnancies.
+    pub fn set_pregnancy_days(&mut self, days: u8) {
+        self.pregnancy_days = days;
+    }
+
+    /// Get the days, when wusels grow up (and grow old).
+    pub fn get_life_stages(&self) -> wusels::stages::LifeStages {
+        self.life_stages
+    }
+
+    /// Set the days, when wusels grow up (and grow old): All wusels are in their new stages.
+    pub fn set_life_stages(&mut self, life_stages: wusels::stages::LifeStages) {
+        self.life_stages = life_stages;
+        for wusel in self.wusels.iter_mut() {
+            wusel.set_life_stage(life_stages.stage_for(wusel.get_lived_days()));
+        }
+    }
+
     /// Get width of the world.
     pub fn get_width(&self) -> u32 {
         self.width
@@ -252,19 +848,25 @@ impl World {
         self.area
     }
 
-    /// Get the `positions` index for the requesting position (width, height).
+    /// Get the `positions` index for the requesting position (width, depth, height).
     /// If the position is not in world, this index is not in [0, positions.len()).
     fn position_to_index(&self, position: areas::Position) -> usize {
-        (position.x + self.width * position.y) as usize
+        if !self.has_position(position) {
+            return self.position_upper_bound;
+        }
+        let (width, depth) = (self.width as usize, self.depth as usize);
+        position.x as usize + width * (position.y as usize + depth * position.z as usize)
     }
 
     /// Get the position tuple from the given index in this world.
     fn position_from_index(&self, position_index: usize) -> Option<areas::Position> {
         if position_index < self.position_upper_bound {
+            let level_size = (self.width * self.depth) as usize;
+            let on_level = position_index % level_size;
             Some(areas::Position {
-                x: position_index as u32 % self.width,
-                y: position_index as u32 / self.width,
-                z: 0,
+                x: (on_level % self.width as usize) as u32,
+                y: (on_level / self.width as usize) as u32,
+                z: (position_index / level_size) as u32,
             })
         } else {
             None
@@ -272,8 +874,8 @@ impl World {
     }
 
     /// Get a random position in this world.
-    pub fn position_random(&self) -> areas::Position {
-        self.area.position_random()
+    pub fn position_random(&mut self) -> areas::Position {
+        self.area.position_random(&mut self.rng)
     }
 
     /// Get the (valid) neighbours for a position.
@@ -281,103 +883,421 @@ impl World {
         self.area.get_all_neighbours_xy(position)
     }
 
-    /// Check if the position is inside the world bounds.
-    pub fn has_position(&self, position: areas::Position) -> bool {
-        self.area.contains_position(&position)
-    }
+    /// Get the positions on other levels, which are connected with this position by stairs.
+    ///
+    /// Stairs leading up connect their position with the position above,
+    /// stairs leading down connect their position with the position below.
+    pub fn position_get_stairs_links(&self, position: areas::Position) -> Vec<areas::Position> {
+        let above = areas::Position::new(position.x, position.y, position.z + 1);
+        let below = match position.z {
+            0 => None,
+            z => Some(areas::Position::new(position.x, position.y, z - 1)),
+        };
 
-    /// Get the distance between two positions represented by indices in this world.
-    #[allow(dead_code)]
-    fn positions_indices_distance(&self, a_index: usize, b_index: usize) -> f32 {
-        let a = self.position_from_index(a_index);
-        let b = self.position_from_index(b_index);
+        let has_stairs = |p: areas::Position, leading_up: bool| {
+            self.has_position(p)
+                && self.positions[self.position_to_index(p)]
+                    .iter()
+                    .any(|placetaker| {
+                        matches!(
+                            placetaker,
+                            PlaceTaker::Construction(items::ConstructionType::Stairs(up), _)
+                                if *up == leading_up
+                        )
+                    })
+        };
 
-        if a.is_none() || b.is_none() {
-            return f32::INFINITY;
+        let mut links = vec![];
+
+        // Up: Stairs here leading up, or stairs above leading down.
+        if self.has_position(above) && (has_stairs(position, true) || has_stairs(above, false)) {
+            links.push(above);
         }
 
-        let a = a.unwrap();
-        let b = b.unwrap();
+        // Down: Stairs here leading down, or stairs below leading up.
+        if let Some(below) = below {
+            if has_stairs(position, false) || has_stairs(below, true) {
+                links.push(below);
+            }
+        }
 
-        a.distance_to(&b)
+        links
     }
 
-    /// Get all positions with a list/vector of the place takers on this position.
-    /// Changing them will not influence the actual world state.
-    pub fn positions_for_all_placetakers(&self) -> Vec<Vec<PlaceTaker>> {
-        self.positions.clone()
+    /// Check if the position is inside the world bounds.
+    pub fn has_position(&self, position: areas::Position) -> bool {
+        self.area.contains_position(&position)
     }
 
-    /// Recalculate all the positions.
-    /// On each position (given by the index), make a list / vector of all PlaceTaker
-    /// which are on them.
-    pub fn recalculate_positions_for_all_placetakers(&mut self) {
-        // clear old positions.
-        for placetakers in self.positions.iter_mut() {
-            placetakers.clear();
+    /// Check if the position can be walked on.
+    ///
+    /// Walls and blocking objects cannot be passed.
+    /// A door decides by itself: Open doors can be passed, also if they are placed into a wall.
+    pub fn position_is_passable(&self, position: areas::Position) -> bool {
+        if !self.has_position(position) {
+            return false;
         }
 
-        // for constructions
-        for (construction_index, &construction) in self.constructions.iter().enumerate() {
-            let constructions_position_index =
-                self.constructions_index_on_position_index[construction_index];
-
-            let placetaker =
-                PlaceTaker::Construction(construction.construction_type(), construction.id());
+        let on_position = &self.positions[self.position_to_index(position)];
 
-            // add all positions.
-            self.positions[constructions_position_index].push(placetaker);
-
-            if let items::ConstructionType::Wall(horizontal, length) =
-                construction.construction_type()
+        // A door in the wall decides.
+        for placetaker in on_position.iter() {
+            if let PlaceTaker::Construction(items::ConstructionType::Door(is_open), _) = placetaker
             {
-                let mut more_position = constructions_position_index;
-                // first position is already put.
-                for _i in 1..length {
-                    more_position += if horizontal { 1 } else { self.width as usize };
-                    self.positions[more_position].push(placetaker);
-                }
+                return *is_open;
             }
         }
 
-        for (wusel_index, &wusel_position_index) in
-            self.wusels_index_on_position_index.iter().enumerate()
-        {
-            self.positions[wusel_position_index]
-                .push(PlaceTaker::Wusel(self.wusels_index_with_id[wusel_index]));
+        !on_position.iter().any(|placetaker| match placetaker {
+            PlaceTaker::Construction(construction_type, _) => !construction_type.is_passable(),
+            PlaceTaker::Object(object_id, _) => self
+                .object_id_to_index(*object_id)
+                .map(|object_index| self.objects[object_index].is_blocking())
+                .unwrap_or(false),
+            _ => false,
+        })
+    }
+
+    /// Check if light and view can go through the position.
+    ///
+    /// Walls and closed doors block the view, windows and everything else do not.
+    /// Like a door, a window placed into a wall decides by itself.
+    pub fn position_is_transparent(&self, position: areas::Position) -> bool {
+        if !self.has_position(position) {
+            return false;
         }
 
-        for (object_index, object_whereabouts) in
-            self.objects_index_with_whereabouts.iter().enumerate()
-        {
-            if let InWorld::OnPositionIndex(object_position_index) = *object_whereabouts {
-                let object_type = self.objects_index_with_type[object_index];
-                let object_id = self.objects_index_with_id[object_index];
-                self.positions[object_position_index]
-                    .push(PlaceTaker::Object(object_id, object_type));
+        let on_position = &self.positions[self.position_to_index(position)];
+
+        // A door or a window in the wall decides.
+        for placetaker in on_position.iter() {
+            if let PlaceTaker::Construction(
+                construction_type @ (items::ConstructionType::Door(_)
+                | items::ConstructionType::Window),
+                _,
+            ) = placetaker
+            {
+                return construction_type.is_transparent();
             }
         }
-    }
 
-    /// Update the positions.
-    ///
-    /// Remove the given place taker from the old position and put them onto the new position.
-    /// This can also be used for the first position or for removing from the world.
-    /// (With no valid old or new position.)
-    fn update_positions(
-        &mut self,
-        placetaker: PlaceTaker,
-        old_position_index: usize,
-        new_position_index: usize,
-    ) {
-        // not if both position indices are invalid / higher / "not given", it just does nothing.
-        // this also can remove a place taker from the map, or put it there on the first place.
+        on_position.iter().all(|placetaker| match placetaker {
+            PlaceTaker::Construction(construction_type, _) => construction_type.is_transparent(),
+            _ => true,
+        })
+    }
 
-        // remove from old position if given.
-        if old_position_index < self.position_upper_bound {
-            let opt_placetaker_index: Option<usize> = self.positions[old_position_index]
+    /// Check if the position is indoors, which means, it is covered by a floor.
+    pub fn position_is_indoors(&self, position: areas::Position) -> bool {
+        self.has_position(position)
+            && self.positions[self.position_to_index(position)]
                 .iter()
-                .position(|&p| p == placetaker);
+                .any(|placetaker| {
+                    matches!(
+                        placetaker,
+                        PlaceTaker::Construction(items::ConstructionType::Floor, _)
+                    )
+                })
+    }
+
+    /// Get the rules, which place takers can share a position.
+    pub fn get_occupancy_policy(&self) -> OccupancyPolicy {
+        self.occupancy
+    }
+
+    /// Change the rules, which place takers can share a position.
+    /// Already shared positions are kept as they are.
+    pub fn set_occupancy_policy(&mut self, policy: OccupancyPolicy) {
+        self.occupancy = policy;
+    }
+
+    /// Get the handles of all wusels standing on the position.
+    pub fn position_get_wusels(&self, position: areas::Position) -> Vec<wusels::WuselHandle> {
+        self.position_get_wusel_ids(position)
+            .into_iter()
+            .filter_map(|wusel_id| self.wusel_handle(wusel_id))
+            .collect()
+    }
+
+    /// Get the identifiers of all wusels standing on the position.
+    fn position_get_wusel_ids(&self, position: areas::Position) -> Vec<wusels::WuselId> {
+        if !self.has_position(position) {
+            return vec![];
+        }
+
+        self.positions[self.position_to_index(position)]
+            .iter()
+            .filter_map(|placetaker| match placetaker {
+                PlaceTaker::Wusel(wusel_id) => Some(*wusel_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Check if the wusel can step on the position.
+    ///
+    /// The position needs to be passable and, if wusels are exclusive, not taken by another wusel.
+    pub fn wusel_can_pass(&self, wusel: wusels::WuselHandle, position: areas::Position) -> bool {
+        let wusel_id = wusel.get_id();
+        self.position_is_passable(position) && self.position_is_free_for_wusel(wusel_id, position)
+    }
+
+    /// Check if the position is not taken by another wusel, if wusels are exclusive.
+    fn position_is_free_for_wusel(
+        &self,
+        wusel_id: wusels::WuselId,
+        position: areas::Position,
+    ) -> bool {
+        !self.occupancy.wusels_exclusive
+            || self
+                .position_get_wusel_ids(position)
+                .iter()
+                .all(|&other_id| other_id == wusel_id)
+    }
+
+    /// Check if the wusel could walk through the position,
+    /// either it is passable, or it is a closed door, the wusel may open on the way.
+    pub fn wusel_may_walk_through(
+        &self,
+        wusel: wusels::WuselHandle,
+        position: areas::Position,
+    ) -> bool {
+        self.position_is_passable(position)
+            || self
+                .door_at(position)
+                .is_some_and(|door_id| self.door_may_be_opened_by(door_id, wusel))
+    }
+
+    /// Check if the object can be placed on the position.
+    ///
+    /// A blocking object needs an empty position (no wusels, no other objects).
+    /// Otherwise objects only share a position, if they all are stackable.
+    pub fn object_can_be_placed(
+        &self,
+        object: objects::ObjectHandle,
+        position: areas::Position,
+    ) -> bool {
+        let object_id = object.get_id();
+        let object_index = match self.object_index(object) {
+            Some(object_index) => object_index,
+            None => return false,
+        };
+
+        if !self.position_is_passable(position) {
+            return false;
+        }
+
+        let object = &self.objects[object_index];
+        let on_position = &self.positions[self.position_to_index(position)];
+
+        on_position.iter().all(|placetaker| match placetaker {
+            PlaceTaker::Wusel(_) => {
+                !(self.occupancy.blocking_objects_exclusive && object.is_blocking())
+            }
+            PlaceTaker::Object(other_id, _) if *other_id == object_id => true,
+            PlaceTaker::Object(other_id, _) => {
+                let other_stackable = self
+                    .object_id_to_index(*other_id)
+                    .is_none_or(|other_index| self.objects[other_index].is_stackable());
+
+                !(self.occupancy.blocking_objects_exclusive && object.is_blocking())
+                    && (!self.occupancy.only_stackables_share
+                        || (object.is_stackable() && other_stackable))
+            }
+            PlaceTaker::Construction(_, _) => true,
+        })
+    }
+
+    /// Find a walkable path for the wusel from start to goal,
+    /// also walking around the other wusels, if they cannot be passed.
+    /// Closed doors, the wusel may open, are on the way.
+    ///
+    /// Returns the steps in walking order (with goal, without start),
+    /// or None if the goal cannot be reached.
+    pub fn wusel_find_path(
+        &self,
+        wusel: wusels::WuselHandle,
+        start: areas::Position,
+        goal: areas::Position,
+    ) -> Option<Vec<areas::Position>> {
+        let wusel_id = wusel.get_id();
+        self.area.find_path(
+            start,
+            goal,
+            &|position| self.wusel_may_walk_through(wusel, *position),
+            &|position| self.position_is_free_for_wusel(wusel_id, *position),
+            &|position| self.position_get_stairs_links(*position),
+        )
+    }
+
+    /// Find a walkable path for the wusel from start to goal, ignoring the other wusels.
+    /// Closed doors, the wusel may open, are on the way.
+    ///
+    /// Returns the steps in walking order (with goal, without start),
+    /// or None if the goal cannot be reached.
+    pub fn wusel_plan_path(
+        &self,
+        wusel: wusels::WuselHandle,
+        start: areas::Position,
+        goal: areas::Position,
+    ) -> Option<Vec<areas::Position>> {
+        self.area.find_path(
+            start,
+            goal,
+            &|position| self.wusel_may_walk_through(wusel, *position),
+            &|_| true,
+            &|position| self.position_get_stairs_links(*position),
+        )
+    }
+
+    /// Get the nearest position to the given one, where the wusel can step on.
+    pub fn wusel_find_nearest_free(
+        &self,
+        wusel: wusels::WuselHandle,
+        position: areas::Position,
+    ) -> Option<areas::Position> {
+        self.area.find_nearest(position, &|candidate| {
+            self.wusel_can_pass(wusel, *candidate)
+        })
+    }
+
+    /// Get the nearest position to the given one, where the object can be placed.
+    pub fn object_find_nearest_free(
+        &self,
+        object: objects::ObjectHandle,
+        position: areas::Position,
+    ) -> Option<areas::Position> {
+        self.area.find_nearest(position, &|candidate| {
+            self.object_can_be_placed(object, *candidate)
+        })
+    }
+
+    /// Get the current topology revision.
+    ///
+    /// It changes whenever constructions or objects are placed, moved or removed,
+    /// so a pre-calculated path only needs to be checked again, if the revision changed.
+    pub fn get_topology_revision(&self) -> usize {
+        self.topology_revision
+    }
+
+    /// Find a walkable path from start to goal, avoiding everything, which is not passable.
+    ///
+    /// Returns the steps in walking order (with goal, without start),
+    /// or None if the goal cannot be reached.
+    pub fn position_find_path(
+        &self,
+        start: areas::Position,
+        goal: areas::Position,
+    ) -> Option<Vec<areas::Position>> {
+        self.area.find_path(
+            start,
+            goal,
+            &|position| self.position_is_passable(*position),
+            &|_| true,
+            &|position| self.position_get_stairs_links(*position),
+        )
+    }
+
+    /// Get the distance between two positions represented by indices in this world.
+    #[allow(dead_code)]
+    fn positions_indices_distance(&self, a_index: usize, b_index: usize) -> f32 {
+        let a = self.position_from_index(a_index);
+        let b = self.position_from_index(b_index);
+
+        if a.is_none() || b.is_none() {
+            return f32::INFINITY;
+        }
+
+        let a = a.unwrap();
+        let b = b.unwrap();
+
+        a.distance_to(&b)
+    }
+
+    /// Get all positions with a list/vector of the place takers on this position.
+    /// Changing them will not influence the actual world state.
+    pub fn positions_for_all_placetakers(&self) -> Vec<Vec<PlaceTaker>> {
+        self.positions.clone()
+    }
+
+    /// Get all positions of one level (z) with a list/vector of the place takers on this position.
+    /// The positions are ordered like on the ground level (x + width * y).
+    /// Changing them will not influence the actual world state.
+    pub fn positions_for_level(&self, level: u32) -> Vec<Vec<PlaceTaker>> {
+        if level >= self.height {
+            return vec![];
+        }
+        let level_size = (self.width * self.depth) as usize;
+        let level_start = level as usize * level_size;
+        self.positions[level_start..level_start + level_size].to_vec()
+    }
+
+    /// Recalculate all the positions.
+    /// On each position (given by the index), make a list / vector of all PlaceTaker
+    /// which are on them.
+    pub fn recalculate_positions_for_all_placetakers(&mut self) {
+        // clear old positions.
+        for placetakers in self.positions.iter_mut() {
+            placetakers.clear();
+        }
+
+        // for constructions
+        for &construction in self.constructions.iter() {
+            let placetaker =
+                PlaceTaker::Construction(construction.construction_type(), construction.id());
+
+            // add all positions (none, if the start is out of world).
+            for position_index in self.construction_get_position_indices(
+                construction.construction_type(),
+                construction.get_position_index(),
+            ) {
+                self.positions[position_index].push(placetaker);
+            }
+        }
+
+        for (wusel_index, &wusel_position_index) in
+            self.wusels_index_on_position_index.iter().enumerate()
+        {
+            if wusel_position_index < self.position_upper_bound {
+                self.positions[wusel_position_index]
+                    .push(PlaceTaker::Wusel(self.wusels_index_with_id[wusel_index]));
+            }
+        }
+
+        for object in self.objects.iter() {
+            if let objects::Whereabouts::OnPositionIndex(object_position_index) =
+                object.get_whereabouts()
+            {
+                if object_position_index >= self.position_upper_bound {
+                    continue;
+                }
+                self.positions[object_position_index].push(PlaceTaker::Object(
+                    object.get_object_id(),
+                    object.get_object_type(),
+                ));
+            }
+        }
+    }
+
+    /// Update the positions.
+    ///
+    /// Remove the given place taker from the old position and put them onto the new position.
+    /// This can also be used for the first position or for removing from the world.
+    /// (With no valid old or new position.)
+    fn update_positions(
+        &mut self,
+        placetaker: PlaceTaker,
+        old_position_index: usize,
+        new_position_index: usize,
+    ) {
+        // not if both position indices are invalid / higher / "not given", it just does nothing.
+        // this also can remove a place taker from the map, or put it there on the first place.
+
+        // remove from old position if given.
+        if old_position_index < self.position_upper_bound {
+            let opt_placetaker_index: Option<usize> = self.positions[old_position_index]
+                .iter()
+                .position(|&p| p == placetaker);
 
             if let Some(placetaker_index) = opt_placetaker_index {
                 self.positions[old_position_index].remove(placetaker_index);
@@ -396,30 +1316,61 @@ impl World {
         construction_type: items::ConstructionType,
         position: areas::Position,
     ) {
-        let construction = items::Construction::new(0usize, construction_type);
+        self.topology_revision += 1;
 
         let position_index = self.position_to_index(position);
 
+        let construction =
+            items::Construction::new(self.entity_id_new(), construction_type, position_index);
+
         let placetaker = PlaceTaker::Construction(construction_type, construction.id());
 
         self.constructions.push(construction);
 
-        // start position.
-        self.constructions_index_on_position_index
-            .push(position_index);
-
         // all positions it may take.
-        self.update_positions(placetaker, 0, position_index);
-        if let items::ConstructionType::Wall(horizontal, length) = construction_type {
-            let mut more_position = position_index;
-            // first position is already put.
-            for _i in 1..length {
-                more_position += if horizontal { 1 } else { self.width as usize };
-                self.update_positions(placetaker, 0, more_position);
-            }
+        for more_position_index in
+            self.construction_get_position_indices(construction_type, position_index)
+        {
+            self.update_positions(placetaker, self.position_upper_bound, more_position_index);
+        }
+
+        if construction_type.is_room_boundary() {
+            self.rooms_recalculate();
         }
     }
 
+    /// Get all position indices a construction takes, if it starts on the given position index.
+    /// A wall grows along its direction, but stays on its level.
+    fn construction_get_position_indices(
+        &self,
+        construction_type: items::ConstructionType,
+        position_index: usize,
+    ) -> Vec<usize> {
+        let position = match self.position_from_index(position_index) {
+            Some(position) => position,
+            None => return vec![],
+        };
+
+        let length = match construction_type {
+            items::ConstructionType::Wall(_, length) => length as u32,
+            _ => 1,
+        };
+
+        (0..length)
+            .map(|i| match construction_type {
+                items::ConstructionType::Wall(true, _) => {
+                    areas::Position::new(position.x + i, position.y, position.z)
+                }
+                items::ConstructionType::Wall(false, _) => {
+                    areas::Position::new(position.x, position.y + i, position.z)
+                }
+                _ => position,
+            })
+            .take_while(|&more_position| self.has_position(more_position))
+            .map(|more_position| self.position_to_index(more_position))
+            .collect()
+    }
+
     /// Get all construction inidces of a door.
     fn get_all_doors_indices(&self) -> Vec<usize> {
         let mut doors = vec![];
@@ -431,10 +1382,334 @@ impl World {
         doors
     }
 
+    /// Get the construction index of a door, given by its identifier.
+    fn door_id_to_index(&self, door_id: items::ConstructionId) -> Option<usize> {
+        self.get_all_doors_indices()
+            .into_iter()
+            .find(|&index| self.constructions[index].id() == door_id)
+    }
+
+    /// Get the identifiers of all doors.
+    pub fn door_get_all(&self) -> Vec<items::ConstructionId> {
+        self.get_all_doors_indices()
+            .into_iter()
+            .map(|index| self.constructions[index].id())
+            .collect()
+    }
+
+    /// Get the door on the position, if there is one.
+    pub fn door_at(&self, position: areas::Position) -> Option<items::ConstructionId> {
+        if !self.has_position(position) {
+            return None;
+        }
+        self.positions[self.position_to_index(position)]
+            .iter()
+            .find_map(|placetaker| match placetaker {
+                PlaceTaker::Construction(items::ConstructionType::Door(_), door_id) => {
+                    Some(*door_id)
+                }
+                _ => None,
+            })
+    }
+
+    /// Get the position of a door.
+    pub fn door_get_position(&self, door_id: items::ConstructionId) -> Option<areas::Position> {
+        self.door_id_to_index(door_id).and_then(|index| {
+            self.position_from_index(self.constructions[index].get_position_index())
+        })
+    }
+
+    /// Check if the door is open.
+    pub fn door_is_open(&self, door_id: items::ConstructionId) -> Option<bool> {
+        self.door_id_to_index(door_id).map(|index| {
+            self.constructions[index].construction_type() == items::ConstructionType::Door(true)
+        })
+    }
+
+    /// Check if the door is locked.
+    pub fn door_is_locked(&self, door_id: items::ConstructionId) -> Option<bool> {
+        self.door_id_to_index(door_id)
+            .map(|index| self.constructions[index].is_locked())
+    }
+
+    /// Get the owner of the door, who may lock and unlock it (if they are still in the world).
+    pub fn door_get_owner(&self, door_id: items::ConstructionId) -> Option<wusels::WuselHandle> {
+        self.door_id_to_index(door_id)
+            .and_then(|index| self.constructions[index].get_owner())
+            .and_then(|owner_id| self.wusel_handle(owner_id))
+    }
+
+    /// Set the owner of the door, who may lock and unlock it.
+    /// Without an owner, every wusel may lock and unlock it.
+    pub fn door_set_owner(
+        &mut self,
+        door_id: items::ConstructionId,
+        owner: Option<wusels::WuselHandle>,
+    ) {
+        if let Some(index) = self.door_id_to_index(door_id) {
+            self.constructions[index].set_owner(owner.map(|owner| owner.get_id()));
+        }
+    }
+
+    /// Check if the wusel is allowed to open the door.
+    /// Unlocked doors can be opened by everyone, locked doors only with the permission.
+    pub fn door_may_be_opened_by(
+        &self,
+        door_id: items::ConstructionId,
+        wusel: wusels::WuselHandle,
+    ) -> bool {
+        self.door_id_to_index(door_id).is_some_and(|index| {
+            let door = &self.constructions[index];
+            !door.is_locked() || door.has_permission(wusel.get_id())
+        })
+    }
+
+    /// Let the wusel open, close, lock or unlock the door.
+    ///
+    /// Opening a locked door also unlocks it, locking an open door also closes it.
+    /// A door cannot be closed, while someone or something is in the doorway.
+    /// Returns true, if the wusel was allowed and able to do so.
+    pub fn door_use(
+        &mut self,
+        door_id: items::ConstructionId,
+        wusel: wusels::WuselHandle,
+        door_action: tasks::DoorAction,
+    ) -> bool {
+        let index = match (self.door_id_to_index(door_id), self.wusel_index(wusel)) {
+            (Some(index), Some(_)) => index,
+            _ => return false,
+        };
+
+        let has_permission = self.constructions[index].has_permission(wusel.get_id());
+        let is_locked = self.constructions[index].is_locked();
+        let is_open = self.door_is_open(door_id).unwrap_or(false);
+        let position_index = self.constructions[index].get_position_index();
+        let is_doorway_free = self.positions[position_index]
+            .iter()
+            .all(|placetaker| matches!(placetaker, PlaceTaker::Construction(_, _)));
+
+        match door_action {
+            tasks::DoorAction::Open if is_locked && !has_permission => false,
+            tasks::DoorAction::Open => {
+                self.constructions[index].set_locked(false);
+                self.door_set_open(index, true);
+                true
+            }
+            tasks::DoorAction::Close | tasks::DoorAction::Lock if is_open && !is_doorway_free => {
+                false
+            }
+            tasks::DoorAction::Close => {
+                self.door_set_open(index, false);
+                true
+            }
+            tasks::DoorAction::Lock | tasks::DoorAction::Unlock if !has_permission => false,
+            tasks::DoorAction::Lock => {
+                self.door_set_open(index, false);
+                self.constructions[index].set_locked(true);
+                true
+            }
+            tasks::DoorAction::Unlock => {
+                self.constructions[index].set_locked(false);
+                true
+            }
+        }
+    }
+
+    /// Open or close the door given by its construction index.
+    /// This updates the door's place taker, and may change the walkable ways.
+    fn door_set_open(&mut self, index: usize, is_open: bool) {
+        let door = self.constructions[index];
+        let old_placetaker = PlaceTaker::Construction(door.construction_type(), door.id());
+        let new_type = items::ConstructionType::Door(is_open);
+
+        if door.construction_type() == new_type {
+            return; // nothing changes.
+        }
+
+        self.constructions[index].set_construction_type(new_type);
+
+        let position_index = door.get_position_index();
+        if position_index < self.position_upper_bound {
+            for placetaker in self.positions[position_index].iter_mut() {
+                if *placetaker == old_placetaker {
+                    *placetaker = PlaceTaker::Construction(new_type, door.id());
+                }
+            }
+        }
+
+        self.topology_revision += 1;
+    }
+
+    /// Check if a construction on the position separates rooms.
+    fn position_is_room_boundary(&self, position: areas::Position) -> bool {
+        self.has_position(position)
+            && self.positions[self.position_to_index(position)]
+                .iter()
+                .any(|placetaker| match placetaker {
+                    PlaceTaker::Construction(construction_type, _) => {
+                        construction_type.is_room_boundary()
+                    }
+                    _ => false,
+                })
+    }
+
+    /// Detect all rooms again, after the bounding constructions changed.
+    ///
+    /// A new room keeps the identifier (and name) of the old room it shares the most positions
+    /// with, so splitting or growing a room keeps its identity.
+    fn rooms_recalculate(&mut self) {
+        let regions = self
+            .area
+            .find_enclosed_regions(&|position| self.position_is_room_boundary(*position));
+
+        // count shared positions of each new region with the old rooms: (count, region, room).
+        let mut overlaps: Vec<(usize, usize, areas::RoomId)> = vec![];
+        for (region_index, region) in regions.iter().enumerate() {
+            let mut counter: std::collections::BTreeMap<areas::RoomId, usize> =
+                std::collections::BTreeMap::new();
+            for &position in region.iter() {
+                if let Some(old_room_id) = self.room_at(position) {
+                    *counter.entry(old_room_id).or_default() += 1;
+                }
+            }
+            overlaps.extend(
+                counter
+                    .into_iter()
+                    .map(|(old_room_id, count)| (count, region_index, old_room_id)),
+            );
+        }
+
+        // biggest overlaps keep the old rooms first.
+        overlaps.sort_by_key(|&(count, region_index, old_room_id)| {
+            (std::cmp::Reverse(count), region_index, old_room_id)
+        });
+
+        let mut old_rooms = std::mem::take(&mut self.rooms);
+        let mut regions_with_room: Vec<Option<areas::Room>> = vec![None; regions.len()];
+
+        for (_, region_index, old_room_id) in overlaps {
+            if regions_with_room[region_index].is_some() {
+                continue;
+            }
+            if let Some(old_index) = old_rooms.iter().position(|room| room.id() == old_room_id) {
+                regions_with_room[region_index] = Some(old_rooms.remove(old_index));
+            }
+        }
+
+        let mut rooms_on_position_index = vec![None; self.position_upper_bound];
+
+        for (region, opt_room) in regions.into_iter().zip(regions_with_room) {
+            let room = match opt_room {
+                Some(mut room) => {
+                    room.set_positions(region);
+                    room
+                }
+                None => {
+                    let room_id = self.sequential_room_id;
+                    self.sequential_room_id += 1;
+                    areas::Room::new(room_id, format!("Room {}", room_id), region)
+                }
+            };
+
+            for &position in room.get_positions().iter() {
+                rooms_on_position_index[self.position_to_index(position)] = Some(room.id());
+            }
+            self.rooms.push(room);
+        }
+
+        self.rooms_on_position_index = rooms_on_position_index;
+    }
+
+    /// Get the room, the position is in.
+    /// Outdoor positions and the bounding walls themselves are in no room.
+    pub fn room_at(&self, position: areas::Position) -> Option<areas::RoomId> {
+        match self.has_position(position) {
+            true => self.rooms_on_position_index[self.position_to_index(position)],
+            false => None,
+        }
+    }
+
+    /// Get the identifiers of all rooms.
+    pub fn room_get_all(&self) -> Vec<areas::RoomId> {
+        self.rooms.iter().map(|room| room.id()).collect()
+    }
+
+    /// Get the name of a room.
+    pub fn room_get_name(&self, room_id: areas::RoomId) -> Option<String> {
+        self.room_get(room_id).map(|room| room.get_name())
+    }
+
+    /// Rename a room, eg. to "Kitchen".
+    pub fn room_set_name(&mut self, room_id: areas::RoomId, new_name: String) {
+        if let Some(room) = self.rooms.iter_mut().find(|room| room.id() == room_id) {
+            room.set_name(new_name);
+        }
+    }
+
+    /// Get all positions inside a room.
+    pub fn room_get_positions(&self, room_id: areas::RoomId) -> Vec<areas::Position> {
+        self.room_get(room_id)
+            .map(|room| room.get_positions())
+            .unwrap_or_default()
+    }
+
+    /// Get all wusels, who are currently inside a room.
+    pub fn room_get_wusels(&self, room_id: areas::RoomId) -> Vec<wusels::WuselHandle> {
+        self.wusels_index_on_position_index
+            .iter()
+            .enumerate()
+            .filter(|(_, &position_index)| {
+                position_index < self.position_upper_bound
+                    && self.rooms_on_position_index[position_index] == Some(room_id)
+            })
+            .map(|(wusel_index, _)| self.wusel_handle_at(wusel_index))
+            .collect()
+    }
+
+    /// Get all objects, which are placed inside a room.
+    pub fn room_get_objects(&self, room_id: areas::RoomId) -> Vec<objects::ObjectHandle> {
+        (0..self.objects.len())
+            .filter(
+                |&object_index| match self.objects[object_index].get_whereabouts() {
+                    objects::Whereabouts::OnPositionIndex(position_index) => {
+                        position_index < self.position_upper_bound
+                            && self.rooms_on_position_index[position_index] == Some(room_id)
+                    }
+                    _ => false,
+                },
+            )
+            .map(|object_index| self.object_handle_at(object_index))
+            .collect()
+    }
+
+    /// Get the room by its identifier.
+    fn room_get(&self, room_id: areas::RoomId) -> Option<&areas::Room> {
+        self.rooms.iter().find(|room| room.id() == room_id)
+    }
+
+    /// Get the handles of all objects in this world.
+    pub fn object_get_all(&self) -> Vec<objects::ObjectHandle> {
+        (0..self.objects.len())
+            .map(|object_index| self.object_handle_at(object_index))
+            .collect()
+    }
+
+    /// Get the name of an object, given by a handle.
+    pub fn object_get_name(&self, object: objects::ObjectHandle) -> Option<String> {
+        self.object_index(object)
+            .map(|object_index| self.objects[object_index].get_name())
+    }
+
+    /// Get the type of an object, given by a handle.
+    pub fn object_get_type(&self, object: objects::ObjectHandle) -> Option<objects::ObjectType> {
+        self.object_index(object)
+            .map(|object_index| self.objects[object_index].get_object_type())
+    }
+
     /// Create a new object to exist in this world.
     ///
     /// Placed in a world inventory/storage first, can be placed in world.
-    /// Returns the new object's index for the world's objects.
+    /// Returns the new object's handle.
     pub fn object_new(
         &mut self,
         object_type: objects::ObjectType,
@@ -443,30 +1718,35 @@ impl World {
         passable: bool,
         consumable_parts: u16,
         storage_capacity: u16,
-    ) -> objects::ObjectId {
+    ) -> objects::ObjectHandle {
+        let object_id: objects::ObjectId = self.entity_id_new();
+
         // Add the new object into the world active objects.
-        self.objects.push(objects::Object::new(
+        let mut object = objects::Object::new(
+            object_id,
             name,
             object_type,
-            passable,
-            false, // stackable
+            !passable, // blocking
+            false,     // stackable
             transportable,
             consumable_parts,
             storage_capacity,
-        ));
-
-        let object_id: objects::ObjectId = self.sequential_object_id;
+        );
+
+        // Food spoils.
+        if let objects::ObjectType::Food(_) = object_type {
+            object.set_consumable_component(Some(items::Consumable::new(
+                consumable_parts,
+                Self::FOOD_SPOILS_AFTER_DAYS,
+            )));
+        }
 
-        self.objects_index_with_whereabouts.push(InWorld::Nowhere);
-        self.objects_index_with_id.push(object_id);
-        self.objects_index_with_type.push(object_type);
+        self.objects.push(object);
 
         log::info!("New object created: {:?}", self.objects.last_mut());
 
-        self.sequential_object_id += 1;
-
-        // Return new ID and appended index.
-        object_id
+        // Return new handle.
+        self.object_handle_at(self.objects.len() - 1)
     }
 
     /// Create a new food (an object) to exist in this world.
@@ -475,8 +1755,8 @@ impl World {
     /// => Food is transportable, no storage.
     ///
     /// Placed in a world inventory/storage first, can be placed in world.
-    /// Returns the new object's index for the world's objects.
-    pub fn food_new(&mut self, name: objects::ObjectSubtype, bites: u16) -> objects::ObjectId {
+    /// Returns the new object's handle.
+    pub fn food_new(&mut self, name: objects::ObjectSubtype, bites: u16) -> objects::ObjectHandle {
         self.object_new(
             objects::ObjectType::Food(name),
             name.to_string(),
@@ -487,180 +1767,772 @@ impl World {
         )
     }
 
+    /// Let the consumable object spoil after some days (0: never).
+    pub fn object_set_spoils_after(&mut self, object: objects::ObjectHandle, days: u32) {
+        if let Some(consumable) = self
+            .object_index(object)
+            .and_then(|object_index| self.objects[object_index].get_consumable_component_mut())
+        {
+            consumable.set_spoils_after(days);
+        }
+    }
+
+    /// Get the age of the object in days, if it ages.
+    pub fn object_get_age(&self, object: objects::ObjectHandle) -> Option<u32> {
+        self.object_index(object)
+            .and_then(|object_index| self.objects[object_index].get_consumable_component())
+            .map(|consumable| consumable.get_age())
+    }
+
+    /// Check if the object is spoiled.
+    pub fn object_is_spoiled(&self, object: objects::ObjectHandle) -> bool {
+        self.object_index(object)
+            .is_some_and(|object_index| self.objects[object_index].is_spoiled())
+    }
+
+    /// Set how fast the objects age, which are stored in the storage, in percent (a fridge slows it down).
+    pub fn object_set_aging_percent(&mut self, storage: objects::ObjectHandle, percent: u32) {
+        if let Some(storage) = self
+            .object_index(storage)
+            .and_then(|storage_index| self.objects[storage_index].get_storage_component_mut())
+        {
+            storage.set_aging_percent(percent);
+        }
+    }
+
+    /// Get how fast the object ages in percent, slowed down by all its (nested) storages.
+    fn objects_index_get_aging_percent(&self, object_index: usize) -> u32 {
+        match self
+            .objects
+            .get(object_index)
+            .map(objects::Object::get_whereabouts)
+        {
+            Some(objects::Whereabouts::InStorageId(storage_id)) => self
+                .object_id_to_index(storage_id)
+                .map(|storage_index| {
+                    self.objects[storage_index]
+                        .get_aging_percent()
+                        .saturating_mul(self.objects_index_get_aging_percent(storage_index))
+                        / 100
+                })
+                .unwrap_or(100),
+            _ => 100,
+        }
+    }
+
+    /// Let all consumables age for a day.
+    fn objects_age(&mut self) {
+        for object_index in 0..self.objects.len() {
+            let percent = self.objects_index_get_aging_percent(object_index);
+            let object = &mut self.objects[object_index];
+            if let Some(consumable) = object.get_consumable_component_mut() {
+                if consumable.age_by(percent) {
+                    log::info!("{} is spoiled.", object.get_name());
+                }
+            }
+        }
+    }
+
     /// Duplicate a world object: Use all attributes, but change the ID.
     ///
     /// This will create a new object, currently in world's storage.
-    pub fn object_duplicate(&mut self, base_index: usize) -> Option<objects::ObjectId> {
+    pub fn object_duplicate(
+        &mut self,
+        base: objects::ObjectHandle,
+    ) -> Option<objects::ObjectHandle> {
         // Duplicate non existing?.
-        if base_index >= self.objects.len() {
-            return None;
-        }
+        let base_index = self.object_index(base)?;
 
-        let fresh_object = objects::Object::clone_as_new(&(self.objects[base_index]));
-        let fresh_object_id = fresh_object.get_object_id();
-        let fresh_object_type = fresh_object.get_object_type();
+        let fresh_object_id = self.entity_id_new();
+        let fresh_object =
+            objects::Object::clone_as_new(&(self.objects[base_index]), fresh_object_id);
 
         self.objects.push(fresh_object);
-        self.objects_index_with_whereabouts.push(InWorld::Nowhere);
-        self.objects_index_with_id.push(fresh_object_id);
-        self.objects_index_with_type.push(fresh_object_type);
 
-        self.sequential_object_id += 1;
+        Some(self.object_handle_at(self.objects.len() - 1))
+    }
 
-        Some(fresh_object_id)
+    /// Get a new identifier for an object or a construction, unique within this world.
+    fn entity_id_new(&mut self) -> objects::EntityId {
+        let entity_id = self.sequential_entity_id;
+        self.sequential_entity_id += 1;
+        entity_id
     }
 
     fn get_objects_index_by_id(&self, object_id: objects::ObjectId) -> Option<usize> {
-        self.objects_index_with_id
+        self.objects
             .iter()
-            .position(|id| *id == object_id)
+            .position(|object| object.get_object_id() == object_id)
     }
 
-    fn get_object_whereabouts_by_id(&self, object_id: objects::ObjectId) -> Option<&InWorld> {
-        if let Some(object_index) = self.get_objects_index_by_id(object_id) {
-            self.objects_index_with_whereabouts.get(object_index)
-        } else {
-            None
+    /// Return the object index for the handle, or None if the handle is stale (the object is gone).
+    fn object_index(&self, object: objects::ObjectHandle) -> Option<usize> {
+        match self.objects.get(object.get_slot()) {
+            Some(found) if found.get_object_id() == object.get_id() => Some(object.get_slot()),
+            _ => self.get_objects_index_by_id(object.get_id()),
         }
     }
 
-    fn get_object_type_by_id(&self, object_id: objects::ObjectId) -> Option<objects::ObjectType> {
-        if let Some(object_index) = self.get_objects_index_by_id(object_id) {
-            self.objects_index_with_type.get(object_index).copied()
-        } else {
-            None
-        }
+    /// Get a handle for the object at the object index.
+    fn object_handle_at(&self, object_index: usize) -> objects::ObjectHandle {
+        objects::ObjectHandle::new(object_index, self.objects[object_index].get_object_id())
+    }
+
+    /// Get a handle for the object given by its identifier, if it is in the world.
+    pub fn object_handle(&self, object_id: objects::ObjectId) -> Option<objects::ObjectHandle> {
+        self.get_objects_index_by_id(object_id)
+            .map(|object_index| self.object_handle_at(object_index))
+    }
+
+    fn get_object_whereabouts_by_id(
+        &self,
+        object_id: objects::ObjectId,
+    ) -> Option<objects::Whereabouts> {
+        self.get_objects_index_by_id(object_id)
+            .map(|object_index| self.objects[object_index].get_whereabouts())
     }
 
     /// Find the optional index of an object, given by an ID.
     fn object_id_to_index(&self, object_id: objects::ObjectId) -> Option<usize> {
-        self.objects
-            .iter()
-            .position(|o| o.get_object_id() == object_id)
+        self.get_objects_index_by_id(object_id)
     }
 
     /// Get the optional position of an object, given by an index.
     /// If the position is held by a storage, get the position of the storage.
     fn objects_index_get_position(&self, object_index: usize) -> Option<areas::Position> {
-        match self.objects_index_with_whereabouts.get(object_index) {
-            Some(InWorld::OnPositionIndex(position_index)) => {
-                self.position_from_index(*position_index)
+        match self
+            .objects
+            .get(object_index)
+            .map(objects::Object::get_whereabouts)
+        {
+            Some(objects::Whereabouts::OnPositionIndex(position_index)) => {
+                self.position_from_index(position_index)
             }
-            Some(InWorld::HeldByWuselId(wusel_id)) => {
+            Some(objects::Whereabouts::HeldByWuselId(wusel_id)) => {
                 // get nested position of holder.
-                self.get_wusels_index_by_id(*wusel_id)
+                self.get_wusels_index_by_id(wusel_id)
                     .map(|holder_index| self.wusels_index_on_position_index[holder_index])
                     .map(|wusel_position_index| self.position_from_index(wusel_position_index))
                     .map(|opt_opt_position| opt_opt_position.unwrap())
             }
-            Some(InWorld::InStorageId(storage_object_id)) => {
+            Some(objects::Whereabouts::InStorageId(storage_object_id)) => {
                 // get nested position (of storage).
-                self.object_get_position(*storage_object_id)
+                self.object_id_to_index(storage_object_id)
+                    .and_then(|storage_index| self.objects_index_get_position(storage_index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the optional position of an object, given by its handle.
+    /// If the position is held by a storage, get the position of the storage.
+    pub fn object_get_position(&self, object: objects::ObjectHandle) -> Option<areas::Position> {
+        if let Some(object_index) = self.object_index(object) {
+            self.objects_index_get_position(object_index)
+        } else {
+            None
+        }
+    }
+
+    /// Get the positions of all objects::Whereabouts::OnPositionIndex objects.
+    #[allow(dead_code)]
+    pub fn positions_for_objects(&self) -> Vec<areas::Position> {
+        // unique positions.
+        self.objects
+            .iter()
+            .filter_map(|object| {
+                if let objects::Whereabouts::OnPositionIndex(position_index) =
+                    object.get_whereabouts()
+                {
+                    self.position_from_index(position_index)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Place an object on a new position (in world).
+    /// If the object was held or stored before, it is now not anymore.
+    ///
+    /// Returns false, if the position cannot take the object (see [OccupancyPolicy]).
+    pub fn object_set_position(
+        &mut self,
+        object: objects::ObjectHandle,
+        position: areas::Position,
+    ) -> bool {
+        let position_index = self.position_to_index(position);
+        match self.object_index(object) {
+            Some(object_index) => self.object_move(
+                object_index,
+                objects::Whereabouts::OnPositionIndex(position_index),
+            ),
+            None => false,
+        }
+    }
+
+    /// Store an object in a storage object (like a fridge, a shelf or a bag).
+    /// If the object was placed, held or stored before, it is now not anymore.
+    ///
+    /// Returns false, if the object cannot be stored there (see [World::object_can_be_stored]).
+    pub fn object_store(
+        &mut self,
+        object: objects::ObjectHandle,
+        storage: objects::ObjectHandle,
+    ) -> bool {
+        match self.object_index(object) {
+            Some(object_index) => self.object_move(
+                object_index,
+                objects::Whereabouts::InStorageId(storage.get_id()),
+            ),
+            None => false,
+        }
+    }
+
+    /// Check if the object can be stored in the storage.
+    ///
+    /// Only portable objects can be stored, and only if the storage has capacity left.
+    /// Storages can be nested (a bag in a fridge), but never in themselves.
+    pub fn object_can_be_stored(
+        &self,
+        object: objects::ObjectHandle,
+        storage: objects::ObjectHandle,
+    ) -> bool {
+        match (self.object_index(object), self.object_index(storage)) {
+            (Some(object_index), Some(storage_index)) => {
+                self.objects_index_can_be_stored(object_index, storage_index)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if the object can be stored in the storage, both given by their index.
+    fn objects_index_can_be_stored(&self, object_index: usize, storage_index: usize) -> bool {
+        if !self.objects[object_index].is_portable()
+            || self.objects[storage_index].get_storage_capacity_left() < 1
+        {
+            return false;
+        }
+
+        // The storage must not be (nested) within the object.
+        let mut container_index = storage_index;
+        loop {
+            if container_index == object_index {
+                return false;
             }
+            container_index = match self.objects[container_index].get_whereabouts() {
+                objects::Whereabouts::InStorageId(container_id) => {
+                    match self.object_id_to_index(container_id) {
+                        Some(container_index) => container_index,
+                        None => return true,
+                    }
+                }
+                _ => return true,
+            };
+        }
+    }
+
+    /// Get the objects, which are directly stored in the storage object.
+    pub fn object_list_contents(
+        &self,
+        storage: objects::ObjectHandle,
+    ) -> Vec<objects::ObjectHandle> {
+        self.objects_index_with_whereabouts(objects::Whereabouts::InStorageId(storage.get_id()))
+            .into_iter()
+            .map(|object_index| self.object_handle_at(object_index))
+            .collect()
+    }
+
+    /// Get the indices of all objects with the whereabouts.
+    fn objects_index_with_whereabouts(&self, whereabouts: objects::Whereabouts) -> Vec<usize> {
+        (0..self.objects.len())
+            .filter(|&object_index| self.objects[object_index].get_whereabouts() == whereabouts)
+            .collect()
+    }
+
+    /// Get the storage object, the object is directly stored in.
+    pub fn object_get_storage(
+        &self,
+        object: objects::ObjectHandle,
+    ) -> Option<objects::ObjectHandle> {
+        match self.get_object_whereabouts_by_id(object.get_id()) {
+            Some(objects::Whereabouts::InStorageId(storage_id)) => self.object_handle(storage_id),
             _ => None,
         }
     }
 
-    /// Get the optional position of an object, given by an ID.
-    /// If the position is held by a storage, get the position of the storage.
-    pub fn object_get_position(&self, object_id: objects::ObjectId) -> Option<areas::Position> {
-        if let Some(object_index) = self.object_id_to_index(object_id) {
-            self.objects_index_get_position(object_index)
-        } else {
-            None
+    /// Get the wusel, who holds the object, also if it is stored in a held bag.
+    fn objects_index_get_holder(&self, object_index: usize) -> Option<wusels::WuselId> {
+        match self
+            .objects
+            .get(object_index)
+            .map(objects::Object::get_whereabouts)
+        {
+            Some(objects::Whereabouts::HeldByWuselId(wusel_id)) => Some(wusel_id),
+            Some(objects::Whereabouts::InStorageId(storage_id)) => self
+                .object_id_to_index(storage_id)
+                .and_then(|storage_index| self.objects_index_get_holder(storage_index)),
+            _ => None,
         }
     }
 
-    /// Get the positions of all InWorld::OnPositionIndex objects.
-    #[allow(dead_code)]
-    pub fn positions_for_objects(&self) -> Vec<areas::Position> {
-        // unique positions.
-        self.objects_index_with_whereabouts
-            .iter()
-            .filter(|whereabout| matches!(whereabout, InWorld::OnPositionIndex(_)))
-            .map(|on_position_index| {
-                if let InWorld::OnPositionIndex(position_index) = on_position_index {
-                    self.position_from_index(*position_index)
-                } else {
-                    None
-                }
-            })
-            .flatten()
-            .collect()
+    /// Get the wusel, who holds the object, also if it is stored in a held bag.
+    pub fn object_get_holder(&self, object: objects::ObjectHandle) -> Option<wusels::WuselHandle> {
+        self.object_index(object)
+            .and_then(|object_index| self.objects_index_get_holder(object_index))
+            .and_then(|wusel_id| self.wusel_handle(wusel_id))
     }
 
-    /// Place an object on a new position (in world).
-    /// If the object was held or stored before, it is now not anymore.
-    pub fn object_set_position(&mut self, object_id: objects::ObjectId, position: areas::Position) {
-        if let Some(object_index) = self.object_id_to_index(object_id) {
-            let object_type = *self.objects_index_with_type.get(object_index).unwrap();
+    /// Move an object to its new whereabouts: On a position, in a storage, held by a wusel or nowhere.
+    /// The old whereabouts are left, a storage gets its capacity back.
+    ///
+    /// Returns false, if the object cannot be moved there (and it stays, where it was).
+    fn object_move(&mut self, object_index: usize, whereto: objects::Whereabouts) -> bool {
+        // Invalid index. => Abort.
+        if object_index >= self.objects.len() {
+            return false;
+        }
 
-            let placetaker = PlaceTaker::Object(object_id, object_type);
+        let object_id = self.objects[object_index].get_object_id();
+        let wherefrom = self.objects[object_index].get_whereabouts();
 
-            let old_position_index = match self.objects_index_with_whereabouts[object_index] {
-                InWorld::OnPositionIndex(old_position_index) => old_position_index,
-                _ => self.position_upper_bound, // none (out of world).
-            };
-            let new_position_index = self.position_to_index(position);
+        if wherefrom == whereto {
+            return true;
+        }
+
+        let possible = match whereto {
+            objects::Whereabouts::OnPositionIndex(position_index) => self
+                .position_from_index(position_index)
+                .is_some_and(|position| {
+                    self.object_can_be_placed(self.object_handle_at(object_index), position)
+                }),
+            objects::Whereabouts::InStorageId(storage_id) => self
+                .object_id_to_index(storage_id)
+                .is_some_and(|storage_index| {
+                    self.objects_index_can_be_stored(object_index, storage_index)
+                }),
+            objects::Whereabouts::HeldByWuselId(wusel_id) => {
+                self.get_wusels_index_by_id(wusel_id).is_some()
+                    && self.objects[object_index].is_portable()
+                    && self.objects_index_with_whereabouts(whereto).len() < Self::WUSEL_CARRY_LIMIT
+            }
+            objects::Whereabouts::Nowhere => true,
+        };
+
+        if !possible {
+            log::warn!(
+                "Object {} cannot be moved from {:?} to {:?}.",
+                object_id,
+                wherefrom,
+                whereto
+            );
+            return false;
+        }
 
-            self.object_set_whereabouts(object_index, InWorld::OnPositionIndex(new_position_index));
+        let placetaker =
+            PlaceTaker::Object(object_id, self.objects[object_index].get_object_type());
 
-            self.update_positions(placetaker, old_position_index, new_position_index);
+        // Leave the old whereabouts.
+        match wherefrom {
+            objects::Whereabouts::OnPositionIndex(position_index) => {
+                self.update_positions(placetaker, position_index, self.position_upper_bound);
+                self.topology_revision += 1;
+            }
+            objects::Whereabouts::InStorageId(storage_id) => {
+                if let Some(storage_index) = self.object_id_to_index(storage_id) {
+                    let storage = &mut self.objects[storage_index];
+                    storage.set_storage_capacity_left(storage.get_storage_capacity_left() + 1);
+                }
+            }
+            objects::Whereabouts::HeldByWuselId(_) | objects::Whereabouts::Nowhere => {}
         }
-    }
 
-    /// Place an object on a new position, or store it within an inventory, or let it held by a wusel.
-    /// The object is given by an (vector) index of all currently active objects.
-    /// If the object is removed from a world position, this will remove the object from the old position.
-    fn object_set_whereabouts(&mut self, object_index: usize, whereto: InWorld) {
-        // Invalid index. => Abort.
-        if object_index >= self.objects.len() {
-            return;
+        // Take the new whereabouts.
+        match whereto {
+            objects::Whereabouts::OnPositionIndex(position_index) => {
+                self.update_positions(placetaker, self.position_upper_bound, position_index);
+                self.topology_revision += 1;
+            }
+            objects::Whereabouts::InStorageId(storage_id) => {
+                if let Some(storage_index) = self.object_id_to_index(storage_id) {
+                    let storage = &mut self.objects[storage_index];
+                    storage.set_storage_capacity_left(storage.get_storage_capacity_left() - 1);
+                }
+            }
+            objects::Whereabouts::HeldByWuselId(_) | objects::Whereabouts::Nowhere => {}
         }
 
-        // just update.
-        self.objects_index_with_whereabouts[object_index] = whereto;
+        self.objects[object_index].set_whereabouts(whereto);
+        true
     }
 
     /// Destroy an object given by a certain all-active-object's index.
+    /// Stored objects are put where the storage was.
     fn object_destroy(&mut self, object_index: usize) {
         if object_index >= self.objects.len() {
             return;
         }
 
+        let object_id = self.objects[object_index].get_object_id();
+        let whereabouts = self.objects[object_index].get_whereabouts();
+
+        self.object_move(object_index, objects::Whereabouts::Nowhere);
+        self.workstations_reserved.remove(&object_id);
+        self.memorials.remove(&object_id);
+
+        let contents: Vec<objects::ObjectId> = self
+            .objects_index_with_whereabouts(objects::Whereabouts::InStorageId(object_id))
+            .into_iter()
+            .map(|content_index| self.objects[content_index].get_object_id())
+            .collect();
+
+        for content_id in contents {
+            if let Some(content_index) = self.object_id_to_index(content_id) {
+                if !self.object_move(content_index, whereabouts) {
+                    self.object_move(content_index, objects::Whereabouts::Nowhere);
+                }
+            }
+        }
+
         self.objects.remove(object_index);
-        self.objects_index_with_whereabouts.remove(object_index);
-        self.objects_index_with_id.remove(object_index);
+
+        self.topology_revision += 1;
     }
 
     /// Add a wusel to the world.
     ///
-    ///ID is the current wusel count.
-    // TODO (2020-11-20) what is about dead wusels and decreasing length?
+    /// Returns the new wusel's handle.
     pub fn wusel_new(
         &mut self,
         name: String,
         gender: wusels::WuselGender,
         position: areas::Position,
-    ) {
+    ) -> wusels::WuselHandle {
         let new_wusel_id = self.sequential_wusel_id; // almost id (for a long time unique)
-        let new_wusel = wusels::Wusel::new(new_wusel_id, name, gender); // new wusel at (position)
+        let mut new_wusel = wusels::Wusel::new(new_wusel_id, name, gender); // new wusel at (position)
+
+        // New wusels join the world grown up.
+        new_wusel.set_lived_days(self.life_stages.adult);
+        new_wusel.set_life_stage(self.life_stages.stage_for(self.life_stages.adult));
+
+        self.wusel_add(new_wusel, position)
+    }
 
-        // Add wusel to positions, start at (position).
+    /// Add a new wusel (with the next sequential identifier) to the world.
+    fn wusel_add(
+        &mut self,
+        new_wusel: wusels::Wusel,
+        position: areas::Position,
+    ) -> wusels::WuselHandle {
+        let new_wusel_id = new_wusel.get_id();
+
+        // Add wusel to positions, start at (position), or next to it, if it is taken.
+        let position = self
+            .area
+            .find_nearest(position, &|candidate| {
+                self.position_is_passable(*candidate)
+                    && self.position_is_free_for_wusel(new_wusel_id, *candidate)
+            })
+            .unwrap_or(position);
         let position_index = self.position_to_index(position);
 
-        // XXX put new wusel on position.
         self.wusels.push(new_wusel);
         self.wusels_index_with_id.push(new_wusel_id); // fast access id.
         self.wusels_index_on_position_index.push(position_index); // access position.
 
-        // self.wusels_positions.push(position_index); // index.
-        self.sequential_wusel_id += 1;
+        self.update_positions(
+            PlaceTaker::Wusel(new_wusel_id),
+            self.position_upper_bound,
+            position_index,
+        );
+
+        // The identifier stays unique, also a returning ghost keeps theirs.
+        self.sequential_wusel_id = usize::max(self.sequential_wusel_id, new_wusel_id + 1);
+
+        self.wusel_handle_at(self.wusels.len() - 1)
+    }
+
+    /// Let a wusel give birth to a child with an optional other parent.
+    ///
+    /// The newborn gets a generated name (to be changed by the player) and is placed next to the parent.
+    /// They are kindred to their parents and the parents' kin, and their birth is recorded as event.
+    fn wusel_give_birth(
+        &mut self,
+        parent_id: wusels::WuselId,
+        other_parent_id: Option<wusels::WuselId>,
+        gender: wusels::WuselGender,
+    ) -> Option<wusels::WuselHandle> {
+        let position = self.wusel_get_position(self.wusel_handle(parent_id)?)?;
+
+        let name_length = self.rng.gen_range(2..15);
+        let name = util::more_strings::name_gen(name_length, &mut self.rng);
+
+        let child_id = self.sequential_wusel_id;
+        let child = self.wusel_add(wusels::Wusel::new_born(child_id, name, gender), position);
+
+        // Kindred: The parents are closest, their relatives one step further.
+        let parent_ids: Vec<wusels::WuselId> =
+            std::iter::once(parent_id).chain(other_parent_id).collect();
+        let mut kindred: std::collections::BTreeMap<wusels::WuselId, u32> =
+            parent_ids.iter().map(|&parent_id| (parent_id, 1)).collect();
+
+        for (&(wusel0_id, wusel1_id), relation) in self.relations.iter() {
+            let distance = match relation.get_kindred_distance() {
+                Some(distance) => distance.saturating_add(1),
+                None => continue,
+            };
+            for (kin_id, relative_id) in [(wusel0_id, wusel1_id), (wusel1_id, wusel0_id)] {
+                if parent_ids.contains(&kin_id) {
+                    let known = kindred.entry(relative_id).or_insert(distance);
+                    *known = u32::min(*known, distance);
+                }
+            }
+        }
+        kindred.remove(&child_id);
+
+        for (relative_id, distance) in kindred {
+            let key = (
+                usize::min(relative_id, child_id),
+                usize::max(relative_id, child_id),
+            );
+            self.relations
+                .entry(key)
+                .or_default()
+                .set_kindred_distance(distance);
+        }
+
+        self.events.push((
+            self.clock,
+            events::Event::Birth {
+                child,
+                parent: wusels::WuselHandle::from_id(parent_id),
+                other_parent: other_parent_id.map(wusels::WuselHandle::from_id),
+            },
+        ));
+
+        Some(child)
+    }
+
+    /// Bury the dead wusel given by their index: They leave the world for the graveyard.
+    ///
+    /// What they held is dropped where they died, and a memorial is placed there:
+    /// An urn indoors, otherwise a tombstone.
+    /// Others stop doing something with them, and who was close to them grieves.
+    fn wusel_bury(&mut self, wusel_index: usize) {
+        if !self.check_valid_wusel_index(wusel_index) {
+            return;
+        }
+
+        let wusel_id = self.wusels_index_with_id[wusel_index];
+        let position_index = self.wusels_index_on_position_index[wusel_index];
+        let position = self.position_from_index(position_index);
+
+        // Drop everything, they held.
+        for object_index in
+            self.objects_index_with_whereabouts(objects::Whereabouts::HeldByWuselId(wusel_id))
+        {
+            let dropped = position
+                .and_then(|position| {
+                    self.object_find_nearest_free(self.object_handle_at(object_index), position)
+                })
+                .map_or(objects::Whereabouts::Nowhere, |position| {
+                    objects::Whereabouts::OnPositionIndex(self.position_to_index(position))
+                });
+            self.object_move(object_index, dropped);
+        }
+        self.workstations_reserved
+            .retain(|_, worker_id| *worker_id != wusel_id);
+
+        // Leave the world.
+        self.update_positions(
+            PlaceTaker::Wusel(wusel_id),
+            position_index,
+            self.position_upper_bound,
+        );
+        self.wusels_index_with_id.remove(wusel_index);
+        self.wusels_index_on_position_index.remove(wusel_index);
+        let mut dead = self.wusels.remove(wusel_index);
+
+        dead.abort_tasks_with(&|_| true);
+        dead.set_pregnancy(None, None);
+
+        // Nobody meets the dead anymore.
+        for other in self.wusels.iter_mut() {
+            other.abort_tasks_with(&|task| {
+                task.get_passive_part().get_other_wusel() == Some(wusel_id)
+            });
+        }
+
+        // Kindred, lovers and friends grieve, the closer they were, the more.
+        let mourners: Vec<(wusels::WuselId, f32)> = self
+            .relations
+            .iter()
+            .filter_map(|(&(wusel0_id, wusel1_id), relation)| {
+                let other_id = match (wusel0_id == wusel_id, wusel1_id == wusel_id) {
+                    (true, false) => wusel1_id,
+                    (false, true) => wusel0_id,
+                    _ => return None,
+                };
+                let closeness = [
+                    relation
+                        .get_kindred_distance()
+                        .map_or(0.0, |distance| 1.0 / distance.max(1) as f32),
+                    if relation.get_romance() >= Self::ROMANCE_TO_CONSENT {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                    if relation.get_friendship() > 0 {
+                        0.5
+                    } else {
+                        0.0
+                    },
+                ]
+                .iter()
+                .copied()
+                .fold(0.0, f32::max);
+                (closeness > 0.0).then_some((other_id, closeness))
+            })
+            .collect();
+
+        for (mourner_id, closeness) in mourners {
+            if let Some(mourner_index) = self.get_wusels_index_by_id(mourner_id) {
+                let mourner = &mut self.wusels[mourner_index];
+                if mourner.is_alive() {
+                    let grief = (Self::GRIEF as f32 * closeness) as u32;
+                    for need in [wusels::needs::Need::FUN, wusels::needs::Need::LOVE] {
+                        mourner.set_need(need, mourner.get_need(need).saturating_sub(grief));
+                    }
+                }
+            }
+        }
+
+        // Remember them with a memorial, where they died.
+        let name = dead.get_name();
+        let memorial = if position.is_some_and(|position| self.position_is_indoors(position)) {
+            self.object_new(
+                objects::ObjectType::Miscellaneous(Self::MEMORIAL_URN),
+                format!("Urn of {name}"),
+                true,
+                true,
+                0,
+                0,
+            )
+        } else {
+            self.object_new(
+                objects::ObjectType::Furniture(Self::MEMORIAL_TOMBSTONE),
+                format!("Tombstone of {name}"),
+                false,
+                true,
+                0,
+                0,
+            )
+        };
+        if let Some(memorial_position) =
+            position.and_then(|position| self.object_find_nearest_free(memorial, position))
+        {
+            self.object_set_position(memorial, memorial_position);
+        }
+        self.memorials.insert(memorial.get_id(), wusel_id);
+
+        self.events.push((
+            self.clock,
+            events::Event::Death {
+                wusel: wusels::WuselHandle::from_id(wusel_id),
+                cause: dead
+                    .get_cause_of_death()
+                    .unwrap_or(wusels::CauseOfDeath::Unknown),
+            },
+        ));
+
+        self.dead_wusels.push(dead);
+    }
+
+    /// Let the buried wusels maybe return as ghosts, by chance (see [World::GHOST_PERCENT]).
+    fn dead_wusels_haunt(&mut self) {
+        let buried: Vec<wusels::WuselId> = self.memorials.values().copied().collect();
+
+        for dead_id in buried {
+            if self.rng.gen_range(0..100) < Self::GHOST_PERCENT {
+                self.dead_wusel_return_as_ghost(wusels::WuselHandle::from_id(dead_id));
+            }
+        }
+    }
+
+    /// Let a buried wusel return as ghost, where their memorial is.
+    /// They keep their identifier, relations and needs,
+    /// so handles from before their death are valid again (see [handles](crate::life::handles)).
+    ///
+    /// Returns the ghost's handle, or None if they are not buried or their memorial is gone.
+    pub fn dead_wusel_return_as_ghost(
+        &mut self,
+        dead: wusels::WuselHandle,
+    ) -> Option<wusels::WuselHandle> {
+        let dead_id = dead.get_id();
+        let dead_index = self
+            .dead_wusels
+            .iter()
+            .position(|dead| dead.get_id() == dead_id)?;
+
+        let position = self
+            .memorials
+            .iter()
+            .filter(|(_, &memorial_wusel_id)| memorial_wusel_id == dead_id)
+            .find_map(|(&memorial_id, _)| {
+                self.object_id_to_index(memorial_id)
+                    .and_then(|memorial_index| self.objects_index_get_position(memorial_index))
+            })?;
+
+        let mut ghost = self.dead_wusels.remove(dead_index);
+        ghost.set_life_state(wusels::Life::GHOST);
+
+        self.events
+            .push((self.clock, events::Event::Haunting { ghost: dead }));
+
+        Some(self.wusel_add(ghost, position))
+    }
+
+    /// Get the handles of all buried wusels, in order of their deaths.
+    /// They are stale for the living world, unless the wusel returns as ghost.
+    pub fn dead_wusel_get_all(&self) -> Vec<wusels::WuselHandle> {
+        self.dead_wusels
+            .iter()
+            .map(|dead| wusels::WuselHandle::from_id(dead.get_id()))
+            .collect()
+    }
+
+    /// Get the name of a buried wusel.
+    pub fn dead_wusel_get_name(&self, dead: wusels::WuselHandle) -> Option<String> {
+        self.dead_wusels
+            .iter()
+            .find(|buried| buried.get_id() == dead.get_id())
+            .map(|buried| buried.get_name())
+    }
+
+    /// Get why a buried wusel died.
+    pub fn dead_wusel_get_cause_of_death(
+        &self,
+        dead: wusels::WuselHandle,
+    ) -> Option<wusels::CauseOfDeath> {
+        self.dead_wusels
+            .iter()
+            .find(|buried| buried.get_id() == dead.get_id())
+            .and_then(|buried| buried.get_cause_of_death())
+    }
+
+    /// Get the wusel, the memorial (a tombstone or an urn) remembers.
+    pub fn memorial_get_wusel(
+        &self,
+        memorial: objects::ObjectHandle,
+    ) -> Option<wusels::WuselHandle> {
+        self.memorials
+            .get(&memorial.get_id())
+            .map(|&wusel_id| wusels::WuselHandle::from_id(wusel_id))
     }
 
     /// Create a new random wusel.
     pub fn wusel_new_random(&mut self, wusel_name: String) {
-        let wusel_gender = wusels::WuselGender::random();
+        let wusel_gender = wusels::WuselGender::random(&mut self.rng);
         let wusel_position = self.position_random();
         self.wusel_new(wusel_name, wusel_gender, wusel_position);
     }
@@ -682,42 +2554,113 @@ impl World {
             .position(|id| *id == wusel_id)
     }
 
-    /// Return an index for positions that is held by the wusel given by their identifier.
-    fn get_wusel_position_index_by_id(&self, wusel_id: wusels::WuselId) -> Option<&usize> {
-        if let Some(wusel_index) = self.get_wusels_index_by_id(wusel_id) {
-            self.wusels_index_on_position_index.get(wusel_index)
-        } else {
-            None
+    /// Return the wusel index for the handle, or None if the handle is stale (the wusel is gone).
+    fn wusel_index(&self, wusel: wusels::WuselHandle) -> Option<usize> {
+        match self.wusels_index_with_id.get(wusel.get_slot()) {
+            Some(&wusel_id) if wusel_id == wusel.get_id() => Some(wusel.get_slot()),
+            _ => self.get_wusels_index_by_id(wusel.get_id()),
         }
     }
 
-    /// Get an optional Position for the wusel given by their identifier.
-    pub fn wusel_get_position(&self, wusel_id: wusels::WuselId) -> Option<areas::Position> {
-        self.get_wusel_position_index_by_id(wusel_id)
-            .map(|&position_index| self.position_from_index(position_index))
-            .map(|opt_position| opt_position.unwrap())
+    /// Get a handle for the wusel at the wusel index.
+    fn wusel_handle_at(&self, wusel_index: usize) -> wusels::WuselHandle {
+        wusels::WuselHandle::new(wusel_index, self.wusels_index_with_id[wusel_index])
     }
 
-    /// Set the position of the indexed wusel to the nearest valid position
+    /// Get a handle for the wusel given by their identifier, if they are in the world.
+    pub fn wusel_handle(&self, wusel_id: wusels::WuselId) -> Option<wusels::WuselHandle> {
+        self.get_wusels_index_by_id(wusel_id)
+            .map(|wusel_index| self.wusel_handle_at(wusel_index))
+    }
+
+    /// Get an optional Position for the wusel given by their handle.
+    pub fn wusel_get_position(&self, wusel: wusels::WuselHandle) -> Option<areas::Position> {
+        self.wusel_index(wusel).and_then(|wusel_index| {
+            self.position_from_index(self.wusels_index_on_position_index[wusel_index])
+        })
+    }
+
+    /// Get the objects, the wusel holds in their hands (see [World::WUSEL_CARRY_LIMIT]).
+    pub fn wusel_list_inventory(&self, wusel: wusels::WuselHandle) -> Vec<objects::ObjectHandle> {
+        self.objects_index_with_whereabouts(objects::Whereabouts::HeldByWuselId(wusel.get_id()))
+            .into_iter()
+            .map(|object_index| self.object_handle_at(object_index))
+            .collect()
+    }
+
+    /// Let the wusel take an object into their hands, from a position or out of a storage.
+    ///
+    /// Returns false, if the object cannot be taken (not portable, or the wusel's hands are full).
+    pub fn wusel_take_object(
+        &mut self,
+        wusel: wusels::WuselHandle,
+        object: objects::ObjectHandle,
+    ) -> bool {
+        match (self.wusel_index(wusel), self.object_index(object)) {
+            (Some(_), Some(object_index)) => self.object_move(
+                object_index,
+                objects::Whereabouts::HeldByWuselId(wusel.get_id()),
+            ),
+            _ => false,
+        }
+    }
+
+    /// Let the wusel put down a held object, where they stand.
+    ///
+    /// Returns false, if the wusel does not hold the object, or it cannot be placed there.
+    pub fn wusel_drop_object(
+        &mut self,
+        wusel: wusels::WuselHandle,
+        object: objects::ObjectHandle,
+    ) -> bool {
+        let held = self.get_object_whereabouts_by_id(object.get_id())
+            == Some(objects::Whereabouts::HeldByWuselId(wusel.get_id()));
+
+        match self.wusel_get_position(wusel) {
+            Some(position) if held => self.object_set_position(object, position),
+            _ => false,
+        }
+    }
+
+    /// Set the position of the wusel to the nearest valid position
     /// If the position may land out of the grid, put it to the nearest border.
-    pub fn wusel_set_position(&mut self, wusel_id: wusels::WuselId, position: areas::Position) {
-        if let Some(&wusel_index) = self.get_wusel_position_index_by_id(wusel_id) {
+    pub fn wusel_set_position(&mut self, wusel: wusels::WuselHandle, position: areas::Position) {
+        if let Some(wusel_index) = self.wusel_index(wusel) {
             self.wusel_set_position_by_index(wusel_index, position);
         }
     }
 
     /// Set the position of the indexed wusel to the nearest valid position
     /// If the position may land out of the grid, put it to the nearest border.
-    fn wusel_set_position_by_index(&mut self, wusel_index: usize, position: areas::Position) {
+    ///
+    /// Returns false, if the position is already taken by another wusel (see [OccupancyPolicy]).
+    fn wusel_set_position_by_index(
+        &mut self,
+        wusel_index: usize,
+        position: areas::Position,
+    ) -> bool {
         if self.check_valid_wusel_index(wusel_index) {
-            let placetaker = PlaceTaker::Wusel(self.wusels_index_with_id[wusel_index]);
+            let wusel_id = self.wusels_index_with_id[wusel_index];
+
+            if self.occupancy.wusels_exclusive
+                && self
+                    .position_get_wusel_ids(position)
+                    .iter()
+                    .any(|&other_id| other_id != wusel_id)
+            {
+                return false; // taken.
+            }
+
+            let placetaker = PlaceTaker::Wusel(wusel_id);
             let old_position_index = self.wusels_index_on_position_index[wusel_index];
             let new_position_index = self.position_to_index(position);
 
             self.wusels_index_on_position_index[wusel_index] = new_position_index;
 
             self.update_positions(placetaker, old_position_index, new_position_index);
+            return true;
         }
+        false
     }
 
     /// Get the positions of all active wusels.
@@ -726,58 +2669,82 @@ impl World {
         // unique positions.
         self.wusels_index_on_position_index
             .iter()
-            .map(|&position_index| self.position_from_index(position_index))
-            .flatten()
+            .filter_map(|&position_index| self.position_from_index(position_index))
             .collect()
     }
 
-    /// Get the indices of all wusels, which are alive.
-    pub fn wusel_get_all_alive(&self) -> Vec<usize> {
-        // TODO (2021-12-11) why as indices, this could be leaked or obsolete on later steps.
-
-        let mut alive: Vec<usize> = vec![];
-        for i in 0..self.wusels.len() {
-            if self.wusels[i].is_alive() {
-                alive.push(i);
-            }
-        }
-        alive
+    /// Get the handles of all wusels in this world.
+    pub fn wusel_get_all(&self) -> Vec<wusels::WuselHandle> {
+        (0..self.wusels.len())
+            .map(|wusel_index| self.wusel_handle_at(wusel_index))
+            .collect()
     }
 
-    /// Get the indices of all wusels, which are currently having no tasks to do.
-    pub fn wusel_get_all_unbusy(&self) -> Vec<usize> {
-        // TODO (2021-12-11) why as indices, this could be leaked or obsolete on later steps.
+    /// Get the handles of all wusels, which are alive.
+    pub fn wusel_get_all_alive(&self) -> Vec<wusels::WuselHandle> {
+        (0..self.wusels.len())
+            .filter(|&wusel_index| self.wusels[wusel_index].is_alive())
+            .map(|wusel_index| self.wusel_handle_at(wusel_index))
+            .collect()
+    }
 
-        let mut unbusy: Vec<usize> = vec![];
-        for i in 0..self.wusels.len() {
-            if self.wusels[i].has_tasklist_empty() {
-                unbusy.push(i);
-            }
-        }
-        unbusy
+    /// Get the handles of all wusels, which are currently having no tasks to do.
+    pub fn wusel_get_all_unbusy(&self) -> Vec<wusels::WuselHandle> {
+        (0..self.wusels.len())
+            .filter(|&wusel_index| self.wusels[wusel_index].has_tasklist_empty())
+            .map(|wusel_index| self.wusel_handle_at(wusel_index))
+            .collect()
     }
 
     /// Check if the wusel of the world is alive.
     ///
     /// This wraps [wusel::Wusel::is_alive](wusel::Wusel::is_alive) for a world wusel.
-    pub fn wusel_is_alive(&self, wusel_id: wusels::WuselId) -> Option<bool> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_is_alive(&self, wusel: wusels::WuselHandle) -> Option<bool> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].is_alive())
     }
 
+    /// Check if the wusel of the world is a ghost.
+    ///
+    /// This wraps [wusel::Wusel::is_ghost](wusel::Wusel::is_ghost) for a world wusel.
+    pub fn wusel_is_ghost(&self, wusel: wusels::WuselHandle) -> Option<bool> {
+        self.wusel_index(wusel)
+            .map(|index| self.wusels[index].is_ghost())
+    }
+
     /// Get the age of the wusel in days.
     ///
     /// This wraps [wusel::Wusel::get_lived_days](wusel::Wusel::get_lived_days) for a world wusel.
-    pub fn wusel_get_lived_days(&self, wusel_id: wusels::WuselId) -> Option<u32> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_lived_days(&self, wusel: wusels::WuselHandle) -> Option<u32> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_lived_days())
     }
 
+    /// Set the days, the wusel lived: They are in the life stage of that age.
+    pub fn wusel_set_lived_days(&mut self, wusel: wusels::WuselHandle, lived_days: u32) {
+        if let Some(index) = self.wusel_index(wusel) {
+            self.wusels[index].set_lived_days(lived_days);
+            self.wusels[index].set_life_stage(self.life_stages.stage_for(lived_days));
+        }
+    }
+
+    /// Get the life stage of the wusel (see [stages](crate::life::wusels::stages)).
+    ///
+    /// This wraps [wusel::Wusel::get_life_stage](wusel::Wusel::get_life_stage) for a world wusel.
+    pub fn wusel_get_life_stage(
+        &self,
+        wusel: wusels::WuselHandle,
+    ) -> Option<wusels::stages::LifeStage> {
+        self.wusel_index(wusel)
+            .map(|index| self.wusels[index].get_life_stage())
+    }
+
     /// Set the life stage of the wusel. This also indirectly may override the age in days.
+    /// A wusel set dead is buried with the next tick.
     ///
     /// This wraps [wusel::Wusel::set_life_state](wusel::Wusel::set_life_state) for a world wusel.
-    pub fn wusel_set_life_state(&mut self, wusel_id: wusels::WuselId, life_state: wusels::Life) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_set_life_state(&mut self, wusel: wusels::WuselHandle, life_state: wusels::Life) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_life_state(life_state);
         }
     }
@@ -785,16 +2752,16 @@ impl World {
     /// Get the name of the wusel.
     ///
     /// This wraps [wusel::Wusel::get_name](wusel::Wusel::get_name) for a world wusel.
-    pub fn wusel_get_name(&self, wusel_id: wusels::WuselId) -> Option<String> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_name(&self, wusel: wusels::WuselHandle) -> Option<String> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_name())
     }
 
     /// Set the name of a Wusel.
     ///
     /// This wraps [wusel::Wusel::set_name](wusel::Wusel::set_name) for a world wusel.
-    pub fn wusel_set_name(&mut self, wusel_id: wusels::WuselId, new_name: String) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_set_name(&mut self, wusel: wusels::WuselHandle, new_name: String) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_name(new_name);
         }
     }
@@ -802,25 +2769,49 @@ impl World {
     /// Get the gender of the wusel.
     ///
     /// This wraps [wusel::Wusel::get_gender](wusel::Wusel::get_gender) for a world wusel.
-    pub fn wusel_get_gender(&self, wusel_id: wusels::WuselId) -> Option<wusels::WuselGender> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_gender(&self, wusel: wusels::WuselHandle) -> Option<wusels::WuselGender> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_gender())
     }
 
     /// Set the gender of a Wusel.
     ///
     /// This wraps [wusel::Wusel::set_gender](wusel::Wusel::set_gender) for a world wusel.
-    pub fn wusel_set_gender(&mut self, wusel_id: wusels::WuselId, new_gender: wusels::WuselGender) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_set_gender(
+        &mut self,
+        wusel: wusels::WuselHandle,
+        new_gender: wusels::WuselGender,
+    ) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_gender(new_gender);
         }
     }
 
+    /// Get the reproductive traits of the wusel.
+    pub fn wusel_get_reproduction(
+        &self,
+        wusel: wusels::WuselHandle,
+    ) -> Option<wusels::Reproduction> {
+        self.wusel_index(wusel)
+            .map(|index| self.wusels[index].get_reproduction())
+    }
+
+    /// Set the reproductive traits of the wusel, the gender stays as it is.
+    pub fn wusel_set_reproduction(
+        &mut self,
+        wusel: wusels::WuselHandle,
+        reproduction: wusels::Reproduction,
+    ) {
+        if let Some(index) = self.wusel_index(wusel) {
+            self.wusels[index].set_reproduction(reproduction);
+        }
+    }
+
     /// Get the requested need's level of the wusel.
     ///
     /// This wraps [wusel::Wusel::get_need](wusel::Wusel::get_need) for a world wusel.
-    pub fn wusel_get_need(&mut self, wusel_id: wusels::WuselId, need: wusels::needs::Need) -> u32 {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_need(&mut self, wusel: wusels::WuselHandle, need: wusels::needs::Need) -> u32 {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_need(need))
             .unwrap_or(0u32)
     }
@@ -830,11 +2821,11 @@ impl World {
     /// This wraps [wusel::Wusel::set_need](wusel::Wusel::set_need) for a world wusel.
     pub fn wusel_set_need(
         &mut self,
-        wusel_id: wusels::WuselId,
+        wusel: wusels::WuselHandle,
         need: &wusels::needs::Need,
         new_value: u32,
     ) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_need(*need, new_value);
         }
     }
@@ -845,11 +2836,11 @@ impl World {
     /// for a world wusel.
     pub fn wusel_set_need_relative(
         &mut self,
-        wusel_id: wusels::WuselId,
+        wusel: wusels::WuselHandle,
         need: &wusels::needs::Need,
         relative: i16,
     ) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_need_relative(*need, relative);
         }
     }
@@ -859,36 +2850,48 @@ impl World {
     /// This wraps [wusel::Wusel::get_ability](wusel::Wusel::get_ability) for a world wusel.
     pub fn wusel_get_ability(
         &self,
-        wusel_id: wusels::WuselId,
+        wusel: wusels::WuselHandle,
         ability: wusels::abilities::Ability,
     ) -> Option<u32> {
-        self.get_wusels_index_by_id(wusel_id)
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_ability(ability))
     }
 
+    /// Get the experience, the wusel gained with the ability.
+    ///
+    /// This wraps [wusel::Wusel::get_ability_experience](wusel::Wusel::get_ability_experience) for a world wusel.
+    pub fn wusel_get_ability_experience(
+        &self,
+        wusel: wusels::WuselHandle,
+        ability: wusels::abilities::Ability,
+    ) -> Option<u32> {
+        self.wusel_index(wusel)
+            .map(|index| self.wusels[index].get_ability_experience(ability))
+    }
+
     /// Set the requesting ability's value of the wusel.
     ///
     /// This wraps [wusel::Wusel::set_ability](wusel::Wusel::set_ability) for a world wusel.
     pub fn wusel_set_ability(
         &mut self,
-        wusel_id: wusels::WuselId,
+        wusel: wusels::WuselHandle,
         ability: wusels::abilities::Ability,
         new_value: u32,
     ) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].set_ability(ability, new_value);
         }
     }
 
-    /// Increase the requesting ability's value of the wusel.
+    /// Let the wusel practise the ability once (see [abilities](crate::life::wusels::abilities)).
     ///
     /// This wraps [wusel::Wusel::improve](wusel::Wusel::improve) for a world wusel.
     pub fn wusel_improve(
         &mut self,
-        wusel_id: wusels::WuselId,
+        wusel: wusels::WuselHandle,
         ability: wusels::abilities::Ability,
     ) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].improve(ability);
         }
     }
@@ -897,8 +2900,8 @@ impl World {
     ///
     /// This wraps [wusel::Wusel::has_tasklist_empty](wusel::Wusel::has_tasklist_empty) for a world
     /// wusel.
-    pub fn wusel_has_tasklist_empty(&self, wusel_id: wusels::WuselId) -> Option<bool> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_has_tasklist_empty(&self, wusel: wusels::WuselHandle) -> Option<bool> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].has_tasklist_empty())
     }
 
@@ -906,8 +2909,8 @@ impl World {
     ///
     /// This wraps [wusel::Wusel::get_tasklist_len](wusel::Wusel::get_tasklist_len) for a world
     /// wusel.
-    pub fn wusel_get_tasklist_len(&self, wusel_id: wusels::WuselId) -> Option<usize> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_tasklist_len(&self, wusel: wusels::WuselHandle) -> Option<usize> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_tasklist_len())
     }
 
@@ -915,23 +2918,21 @@ impl World {
     ///
     /// This wraps [wusel::Wusel::get_tasklist_names](wusel::Wusel::get_tasklist_names)
     /// for a world wusel.
-    pub fn wusel_get_tasklist_names(&mut self, wusel_id: wusels::WuselId) -> Vec<String> {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_get_tasklist_names(&mut self, wusel: wusels::WuselHandle) -> Vec<String> {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].get_tasklist_names()
         } else {
             vec![]
         }
     }
 
-    /// Give an available wusel (by index) a new task.
+    /// Give an available wusel a new task.
     ///
     /// This wraps [wusel::Wusel::assign_to_task](wusel::Wusel::assign_to_task) for a world wusel.
-    pub fn wusel_assign_to_task(&mut self, wusel_index: usize, taskb: tasks::TaskBuilder) {
-        // TODO (2021-12-11) why given with wusel index?
-
-        if let Some(wusel) = self.wusels.get_mut(wusel_index) {
-            // Task apply wusel[index] as actor.
-            wusel.assign_to_task(self.clock, taskb);
+    pub fn wusel_assign_to_task(&mut self, wusel: wusels::WuselHandle, taskb: tasks::TaskBuilder) {
+        if let Some(index) = self.wusel_index(wusel) {
+            // Task apply wusel as actor.
+            self.wusels[index].assign_to_task(self.clock, taskb);
             log::debug!("task successfully assigned")
         }
     }
@@ -939,8 +2940,8 @@ impl World {
     /// Abort the wusel's task.
     ///
     /// This wraps [wusel::Wusel::abort_task](wusel::Wusel::abort_task) for a world wusel.
-    pub fn wusel_abort_task(&mut self, wusel_id: wusels::WuselId, task_index: usize) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_abort_task(&mut self, wusel: wusels::WuselHandle, task_index: usize) {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].abort_task(task_index);
         }
     }
@@ -952,8 +2953,8 @@ impl World {
     ///
     /// This wraps [wusel::Wusel::peek_ongoing_task](wusel::Wusel::peek_ongoing_task)
     /// for a world wusel.
-    pub fn wusel_peek_ongoing_task(&self, wusel_id: wusels::WuselId) -> Option<&tasks::Task> {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
+    pub fn wusel_peek_ongoing_task(&self, wusel: wusels::WuselHandle) -> Option<&tasks::Task> {
+        if let Some(index) = self.wusel_index(wusel) {
             self.wusels[index].peek_ongoing_task()
         } else {
             None
@@ -963,8 +2964,8 @@ impl World {
     /// Check if the wusel is pregnant.
     ///
     /// This wraps [wusel::Wusel::is_pregnant](wusel::Wusel::is_pregnant) for a world wusel.
-    pub fn wusel_is_pregnant(&self, wusel_id: wusels::WuselId) -> Option<bool> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_is_pregnant(&self, wusel: wusels::WuselHandle) -> Option<bool> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].is_pregnant())
     }
 
@@ -973,12 +2974,15 @@ impl World {
     /// This wraps [wusel::Wusel::set_pregnancy](wusel::Wusel::set_pregnancy) for a world wusel.
     pub fn wusel_set_pregnancy(
         &mut self,
-        wusel_id: wusels::WuselId,
-        other_parent: Option<wusels::WuselId>,
+        wusel: wusels::WuselHandle,
+        other_parent: Option<wusels::WuselHandle>,
         remaining_days: Option<u8>,
     ) {
-        if let Some(index) = self.get_wusels_index_by_id(wusel_id) {
-            self.wusels[index].set_pregnancy(other_parent, remaining_days);
+        if let Some(index) = self.wusel_index(wusel) {
+            self.wusels[index].set_pregnancy(
+                other_parent.map(|other_parent| other_parent.get_id()),
+                remaining_days,
+            );
         }
     }
 
@@ -986,32 +2990,34 @@ impl World {
     ///
     /// This wraps [wusel::Wusel::get_other_parent](wusel::Wusel::get_other_parent)
     /// for a world wusel.
-    pub fn wusel_get_other_parent(&self, wusel_id: wusels::WuselId) -> Option<wusels::WuselId> {
-        self.get_wusels_index_by_id(wusel_id)
-            .map(|index| self.wusels[index].get_other_parent())
-            .unwrap_or(None)
+    pub fn wusel_get_other_parent(
+        &self,
+        wusel: wusels::WuselHandle,
+    ) -> Option<wusels::WuselHandle> {
+        self.wusel_index(wusel)
+            .and_then(|index| self.wusels[index].get_other_parent())
+            .and_then(|other_parent_id| self.wusel_handle(other_parent_id))
     }
 
     /// Get the remaining days of the wusel's pregnancy.
     ///
     /// This wraps [wusel::Wusel::get_remaining_pregnancy_days](wusel::Wusel::get_remaining_pregnancy_days) for a world wusel.
-    pub fn wusel_get_remaining_pregnancy_days(&self, wusel_id: wusels::WuselId) -> Option<u8> {
-        self.get_wusels_index_by_id(wusel_id)
+    pub fn wusel_get_remaining_pregnancy_days(&self, wusel: wusels::WuselHandle) -> Option<u8> {
+        self.wusel_index(wusel)
             .map(|index| self.wusels[index].get_remaining_pregnancy_days())
             .unwrap_or(None)
     }
 
-    /// Show all relations for the wusel, given by index.
+    /// Show all relations for the wusel.
     /// Prints directly to std::out.
-    pub fn wusel_show_relations(&self, wusel_index: usize) {
-        // TODO (2021-12-11) refactor function declaration (not with index; this should not be on top out of the class.
-        // TODO (2021-12-11) do not wusel_index on pub functions.
-        // TODO (2021-12-11) refactor. function
-
-        if wusel_index >= self.wusels.len() {
-            println!("There is no wusel to show.");
-            return;
-        }
+    pub fn wusel_show_relations(&self, wusel: wusels::WuselHandle) {
+        let wusel_index = match self.wusel_index(wusel) {
+            Some(wusel_index) => wusel_index,
+            None => {
+                println!("There is no wusel to show.");
+                return;
+            }
+        };
 
         let wusel_id = self.wusels[wusel_index].get_id();
 
@@ -1032,7 +3038,10 @@ impl World {
                 continue;
             } // not in relation
 
-            let other_name = self.wusels[other_id].get_name();
+            let other_name = match self.get_wusels_index_by_id(other_id) {
+                Some(other_index) => self.wusels[other_index].get_name(),
+                None => format!("#{}", other_id), // not in this world anymore.
+            };
 
             // Print Relation.
             print!("[{:?}: {relation}]", other_name);
@@ -1046,33 +3055,88 @@ impl World {
         println!();
     }
 
-    /// Update the relation of two wusels, given by their ID.
+    /// Check, if two wusels may flirt (meet romantically), to become romantically closer:
+    /// Both are alive, old enough and not closely kindred.
+    pub fn wusels_may_flirt(
+        &self,
+        wusel0: wusels::WuselHandle,
+        wusel1: wusels::WuselHandle,
+    ) -> bool {
+        wusel0 != wusel1
+            && self.wusel_is_alive(wusel0) == Some(true)
+            && self.wusel_is_alive(wusel1) == Some(true)
+            && [wusel0, wusel1].iter().all(|&wusel| {
+                self.wusel_get_life_stage(wusel)
+                    .is_some_and(|stage| stage.may_romance())
+            })
+            && self
+                .wusel_get_relation(wusel0, wusel1)
+                .and_then(|relation| relation.get_kindred_distance())
+                .is_none_or(|distance| distance > Self::KINDRED_TABOO_DISTANCE)
+    }
+
+    /// Check, if two wusels consent to a romance, which may lead to a child:
+    /// They may flirt (see [World::wusels_may_flirt]) and are romantically close enough.
+    pub fn wusels_consent_to_romance(
+        &self,
+        wusel0: wusels::WuselHandle,
+        wusel1: wusels::WuselHandle,
+    ) -> bool {
+        self.wusels_may_flirt(wusel0, wusel1)
+            && self
+                .wusel_get_relation(wusel0, wusel1)
+                .is_some_and(|relation| relation.get_romance() >= Self::ROMANCE_TO_CONSENT)
+    }
+
+    /// Get the relation of two wusels, if they have one.
+    pub fn wusel_get_relation(
+        &self,
+        wusel0: wusels::WuselHandle,
+        wusel1: wusels::WuselHandle,
+    ) -> Option<wusels::relations::Relation> {
+        let (wusel0_id, wusel1_id) = (wusel0.get_id(), wusel1.get_id());
+        let key = if wusel0_id <= wusel1_id {
+            (wusel0_id, wusel1_id)
+        } else {
+            (wusel1_id, wusel0_id)
+        };
+        self.relations.get(&key).cloned()
+    }
+
+    /// Update the relation of two wusels, both need to be in the world.
     pub fn wusel_update_relations(
         &mut self,
-        wusel0_id: wusels::WuselId,
-        wusel1_id: wusels::WuselId,
+        wusel0: wusels::WuselHandle,
+        wusel1: wusels::WuselHandle,
         nice: bool,
         relationtype: wusels::relations::RelationType,
     ) {
-        // TODO (2021-12-11) refactor.
-
-        // Decide for a relation key: (Greater ID, Smaller ID).
+        let change = if nice { 1 } else { -1 };
+        self.wusel_change_relations(wusel0, wusel1, change, relationtype);
+    }
 
+    /// Change the relation of two wusels by the given value, both need to be in the world.
+    pub fn wusel_change_relations(
+        &mut self,
+        wusel0: wusels::WuselHandle,
+        wusel1: wusels::WuselHandle,
+        change: i32,
+        relationtype: wusels::relations::RelationType,
+    ) {
+        if self.wusel_index(wusel0).is_none() || self.wusel_index(wusel1).is_none() {
+            return;
+        }
+        let (wusel0_id, wusel1_id) = (wusel0.get_id(), wusel1.get_id());
         let key = if wusel0_id <= wusel1_id {
             (wusel0_id, wusel1_id)
         } else {
             (wusel1_id, wusel0_id)
         };
 
-        let change = if nice { 1 } else { -1 };
-
-        // Get the relation if available.
-        // update a key, guarding against the key possibly not being set.
-        let rel = self
-            .relations
+        // Update the relation, guarding against the key possibly not being set.
+        self.relations
             .entry(key)
-            .or_insert_with(wusels::relations::Relation::new);
-
-        rel.update(relationtype, change);
+            .or_default()
+            .update(relationtype, change);
     }
 }
@@ -0,0 +1,631 @@
+//! # Save and Load
+//!
+//! Persist a complete [World](crate::life::world::World) to a file and restore it.
+//!
+//! ## Format
+//!
+//! A save file is a JSON document with a small envelope:
+//!
+//! ```json
+//! {
+//!   "format": "wusel-world",
+//!   "version": 1,
+//!   "world": { ... }
+//! }
+//! ```
+//!
+//! - `format` is always `"wusel-world"`.
+//! - `version` is the version of the `world` part, see [VERSION].
+//! - `world` holds the complete world:
+//!   - its size, clock and random generator (seed and state),
+//!   - the wusels (with needs, abilities, skills, reproductive traits, life stages and tasks),
+//!   - the buried wusels (with their causes of death) and their memorials,
+//!   - the days of a pregnancy and of the life stages,
+//!   - the relations between the wusels,
+//!   - the objects (with their whereabouts, consumable and storage components)
+//!     and the constructions, sharing one id space,
+//!   - the rooms,
+//!   - the [actions](crate::life::world::actions) and the objects'
+//!     [advertisements](crate::life::world::advertisements),
+//!   - the [blueprints](crate::life::world::items::Blueprint) for crafting
+//!     and the reserved workstations,
+//!   - the [events](crate::life::world::events), which were not taken yet.
+//!
+//! Positional caches (who is on which position) are not saved, they are recalculated on load.
+//!
+//! ## Recordings
+//!
+//! A [Recording] keeps the save document of the world, when the recording started,
+//! and the ticks, which passed since:
+//!
+//! ```json
+//! {
+//!   "format": "wusel-recording",
+//!   "ticks": 300,
+//!   "start": { "format": "wusel-world", ... }
+//! }
+//! ```
+//!
+//! Since the random generator is saved with the world, the recorded ticks are replayed exactly.
+//!
+//! ## Versions and Migrations
+//!
+//! Whenever the `world` part changes incompatibly, [VERSION] is increased and a migration is
+//! appended to [MIGRATIONS], which updates a document of the previous version.
+//! Older documents are migrated step by step, newer documents are rejected.
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::objects;
+use crate::life::world::World;
+use crate::life::wusels;
+
+/// Name of the format, to recognise a save file.
+pub const FORMAT: &str = "wusel-world";
+
+/// Current version of the saved world.
+pub const VERSION: u64 = 10;
+
+/// A migration updates a saved world by one version.
+pub type Migration = fn(&mut serde_json::Value) -> std::io::Result<()>;
+
+/// Migrations for old versions: The migration at index `i` updates version `i + 1` to `i + 2`.
+pub const MIGRATIONS: [Migration; (VERSION - 1) as usize] = [
+    migrate_v1_add_rng,
+    migrate_v2_add_advertisements,
+    migrate_v3_typed_actions,
+    migrate_v4_add_blueprints,
+    migrate_v5_unify_entities,
+    migrate_v6_add_reproduction,
+    migrate_v7_add_life_stages,
+    migrate_v8_add_skills,
+    migrate_v9_fold_construction_positions,
+];
+
+/// Version 2 added the world's random generator: Old worlds continue with seed 0.
+fn migrate_v1_add_rng(world: &mut serde_json::Value) -> std::io::Result<()> {
+    use rand::SeedableRng;
+
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    world.insert("seed".to_string(), serde_json::json!(0));
+    world.insert(
+        "rng".to_string(),
+        serde_json::to_value(crate::life::world::WorldRng::seed_from_u64(0))?,
+    );
+    Ok(())
+}
+
+/// Version 3 replaced the actions' effects by the advertisements: Old worlds get the defaults.
+/// Each saved effect of a (default) action on an object is kept as advertisement of the object's exact type,
+/// effects of unknown actions or on unknown objects are dropped with a warning.
+fn migrate_v2_add_advertisements(world: &mut serde_json::Value) -> std::io::Result<()> {
+    use crate::life::world::advertisements;
+
+    type SavedActionAffect = (
+        serde_json::Value, // affected object
+        usize,             // object subtype (never used)
+        String,            // action name
+        Vec<(wusels::needs::Need, i16)>,
+    );
+
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    let saved_effects: Vec<SavedActionAffect> = match world.remove("actions_effects") {
+        Some(saved_effects) => serde_json::from_value(saved_effects)
+            .map_err(|_| invalid_data("Actions' effects are malformed.".to_string()))?,
+        None => vec![],
+    };
+
+    // Until version 6, the objects' ids and types were kept apart.
+    let object_type_of = |object_id: &serde_json::Value| -> Option<objects::ObjectType> {
+        let index = world
+            .get("objects_index_with_id")?
+            .as_array()?
+            .iter()
+            .position(|id| id == object_id)?;
+        let object_type = world
+            .get("objects_index_with_type")?
+            .as_array()?
+            .get(index)?;
+        serde_json::from_value(object_type.clone()).ok()
+    };
+
+    let actions = World::default_actions();
+
+    let mut advertisements = World::default_advertisements();
+    for (object_id, _, action_name, needs) in saved_effects {
+        let action_id = actions
+            .iter()
+            .position(|action| action.get_name() == action_name);
+
+        match (object_type_of(&object_id), action_id) {
+            (Some(object_type), Some(action_id)) => {
+                let advertisement = needs.into_iter().fold(
+                    advertisements::Advertisement::new(
+                        advertisements::Advertiser::Exactly(object_type),
+                        action_id,
+                    ),
+                    |advertisement, (need, change)| advertisement.with_effect(need, change),
+                );
+                advertisements.register(advertisement);
+            }
+            _ => log::warn!(
+                "Dropped the saved effects of '{}' on object {}: Unknown action or object.",
+                action_name,
+                object_id
+            ),
+        }
+    }
+
+    world.insert(
+        "advertisements".to_string(),
+        serde_json::to_value(advertisements)?,
+    );
+    Ok(())
+}
+
+/// Version 4 replaced the action names by typed actions: Old worlds only knew the defaults.
+fn migrate_v3_typed_actions(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    world.insert(
+        "actions".to_string(),
+        serde_json::to_value(crate::life::world::World::default_actions())?,
+    );
+    Ok(())
+}
+
+/// Version 5 added crafting: Old worlds get the default blueprints and no workstation is reserved.
+fn migrate_v4_add_blueprints(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    world.insert(
+        "blueprints".to_string(),
+        serde_json::to_value(crate::life::world::World::default_blueprints())?,
+    );
+    world.insert("workstations_reserved".to_string(), serde_json::json!({}));
+    Ok(())
+}
+
+/// Version 6 unified objects and constructions to entities with one id space:
+/// Each object keeps its id, type and whereabouts itself, consumable and storage parts are components,
+/// and constructions get new ids after the objects (doors in planned tasks are updated).
+fn migrate_v5_unify_entities(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    let take_array = |world: &mut serde_json::Map<String, serde_json::Value>, key: &str| {
+        world
+            .remove(key)
+            .and_then(|value| value.as_array().cloned())
+            .unwrap_or_default()
+    };
+
+    let ids = take_array(world, "objects_index_with_id");
+    let types = take_array(world, "objects_index_with_type");
+    let whereabouts = take_array(world, "objects_index_with_whereabouts");
+
+    let mut objects = take_array(world, "objects");
+    if ids.len() != objects.len()
+        || types.len() != objects.len()
+        || whereabouts.len() != objects.len()
+    {
+        return Err(invalid_data("Objects are inconsistent.".to_string()));
+    }
+
+    for (index, object) in objects.iter_mut().enumerate() {
+        let object = object
+            .as_object_mut()
+            .ok_or_else(|| invalid_data("Object is not an object.".to_string()))?;
+
+        object.insert("id".to_string(), ids[index].clone());
+        object.insert("object_type".to_string(), types[index].clone());
+        object.insert("whereabouts".to_string(), whereabouts[index].clone());
+
+        let bites = object
+            .remove("consumable_bites")
+            .unwrap_or(serde_json::json!(0));
+        let bites_left = object
+            .remove("consumable_bites_left")
+            .unwrap_or(serde_json::json!(0));
+        let state = object
+            .remove("consumable_state")
+            .unwrap_or(serde_json::Value::Null);
+        let consumable = if bites == serde_json::json!(0) && state.is_null() {
+            serde_json::Value::Null
+        } else {
+            serde_json::json!({
+                "bites": bites,
+                "bites_left": bites_left,
+                "spoils_after": state.get("spoils_after").cloned().unwrap_or(serde_json::json!(0)),
+                "age": state.get("age").cloned().unwrap_or(serde_json::json!(0)),
+                "need_change": state.get("need_change").cloned().unwrap_or(serde_json::json!({})),
+            })
+        };
+        object.insert("consumable".to_string(), consumable);
+
+        let capacity = object
+            .remove("storage_capacity")
+            .unwrap_or(serde_json::json!(0));
+        let capacity_left = object
+            .remove("storage_capacity_left")
+            .unwrap_or(serde_json::json!(0));
+        let aging_percent = object
+            .remove("aging_percent")
+            .unwrap_or(serde_json::json!(100));
+        let storage = if capacity == serde_json::json!(0) {
+            serde_json::Value::Null
+        } else {
+            serde_json::json!({
+                "capacity": capacity,
+                "capacity_left": capacity_left,
+                "aging_percent": aging_percent,
+            })
+        };
+        object.insert("storage".to_string(), storage);
+    }
+    world.insert("objects".to_string(), serde_json::Value::Array(objects));
+
+    // Constructions continue, where the objects stopped.
+    let mut sequential_entity_id = world
+        .remove("sequential_object_id")
+        .and_then(|id| id.as_u64())
+        .unwrap_or(0);
+
+    let mut new_construction_ids = std::collections::HashMap::new();
+    if let Some(constructions) = world
+        .get_mut("constructions")
+        .and_then(|constructions| constructions.as_array_mut())
+    {
+        for construction in constructions.iter_mut() {
+            let construction = construction
+                .as_object_mut()
+                .ok_or_else(|| invalid_data("Construction is not an object.".to_string()))?;
+
+            if let Some(old_id) = construction.get("id").and_then(|id| id.as_u64()) {
+                new_construction_ids.insert(old_id, sequential_entity_id);
+            }
+            construction.insert("id".to_string(), serde_json::json!(sequential_entity_id));
+            sequential_entity_id += 1;
+        }
+    }
+    world.insert(
+        "sequential_entity_id".to_string(),
+        serde_json::json!(sequential_entity_id),
+    );
+
+    for wusels_key in ["wusels", "dead_wusels"] {
+        let tasks = world
+            .get_mut(wusels_key)
+            .and_then(|wusels| wusels.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter_map(|wusel| wusel.get_mut("tasklist"))
+            .filter_map(|tasklist| tasklist.as_array_mut())
+            .flatten();
+
+        for task in tasks {
+            if let Some(door_id) = task.pointer_mut("/passive_part/UseDoor/0") {
+                if let Some(&new_id) = door_id
+                    .as_u64()
+                    .and_then(|id| new_construction_ids.get(&id))
+                {
+                    *door_id = serde_json::json!(new_id);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Version 7 separated the reproductive traits from the gender:
+/// Old wusels get the traits typical for their gender, pregnancies take the default days.
+fn migrate_v6_add_reproduction(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    for wusels_key in ["wusels", "dead_wusels"] {
+        let old_wusels = world
+            .get_mut(wusels_key)
+            .and_then(|wusels| wusels.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter_map(|wusel| wusel.as_object_mut());
+
+        for wusel in old_wusels {
+            let gender: wusels::WuselGender = wusel
+                .get("gender")
+                .and_then(|gender| serde_json::from_value(gender.clone()).ok())
+                .unwrap_or(wusels::WuselGender::Undefined);
+            wusel.insert(
+                "reproduction".to_string(),
+                serde_json::to_value(wusels::Reproduction::typical_for(gender))?,
+            );
+        }
+    }
+
+    world.insert(
+        "pregnancy_days".to_string(),
+        serde_json::json!(crate::life::world::World::PREGNANCY_DAYS),
+    );
+    Ok(())
+}
+
+/// Version 8 added life stages: Before, every wusel was grown up,
+/// so they lived at least until they are adults, the stages begin on their default days.
+fn migrate_v7_add_life_stages(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    let life_stages = wusels::stages::LifeStages::default();
+
+    for wusels_key in ["wusels", "dead_wusels"] {
+        let old_wusels = world
+            .get_mut(wusels_key)
+            .and_then(|wusels| wusels.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter_map(|wusel| wusel.as_object_mut());
+
+        for wusel in old_wusels {
+            let lived_days = wusel
+                .get("lived_days")
+                .and_then(|days| days.as_u64())
+                .map_or(0, |days| days.min(u32::MAX as u64) as u32)
+                .max(life_stages.adult);
+            wusel.insert("lived_days".to_string(), serde_json::json!(lived_days));
+            wusel.insert(
+                "life_stage".to_string(),
+                serde_json::to_value(life_stages.stage_for(lived_days))?,
+            );
+        }
+    }
+
+    world.insert(
+        "life_stages".to_string(),
+        serde_json::to_value(life_stages)?,
+    );
+    Ok(())
+}
+
+/// Version 9 learnt abilities by experience: Old ability values are the levels just reached.
+fn migrate_v8_add_skills(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    for wusels_key in ["wusels", "dead_wusels"] {
+        let old_abilities = world
+            .get_mut(wusels_key)
+            .and_then(|wusels| wusels.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter_map(|wusel| wusel.get_mut("abilities"))
+            .filter_map(|abilities| abilities.as_object_mut());
+
+        for abilities in old_abilities {
+            for (_, value) in abilities.iter_mut() {
+                let level = value
+                    .as_u64()
+                    .map_or(0, |level| level.min(u32::MAX as u64) as u32);
+                *value = serde_json::to_value(wusels::abilities::Skill::with_level(level))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Version 10 keeps the construction's position within the construction,
+/// instead of a parallel list of position indices.
+fn migrate_v9_fold_construction_positions(world: &mut serde_json::Value) -> std::io::Result<()> {
+    let world = world
+        .as_object_mut()
+        .ok_or_else(|| invalid_data("World is not an object.".to_string()))?;
+
+    let position_indices = world
+        .remove("constructions_index_on_position_index")
+        .and_then(|value| value.as_array().cloned())
+        .unwrap_or_default();
+
+    let constructions = world
+        .get_mut("constructions")
+        .and_then(|constructions| constructions.as_array_mut())
+        .map_or(&mut [][..], |constructions| constructions.as_mut_slice());
+
+    if constructions.len() != position_indices.len() {
+        return Err(invalid_data("Constructions are inconsistent.".to_string()));
+    }
+
+    for (construction, position_index) in constructions.iter_mut().zip(position_indices) {
+        construction
+            .as_object_mut()
+            .ok_or_else(|| invalid_data("Construction is not an object.".to_string()))?
+            .insert("position_index".to_string(), position_index);
+    }
+    Ok(())
+}
+
+/// Name of the format, to recognise a recording.
+pub const RECORDING_FORMAT: &str = "wusel-recording";
+
+/// A recorded simulation: The world, when the recording started (as save document),
+/// and how many ticks passed since.
+///
+/// The world's randomness (seed and state) is saved with it,
+/// so ticking the start again for the recorded ticks replays exactly the same simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    start: serde_json::Value,
+    ticks: usize,
+}
+
+impl Recording {
+    /// Start recording the world as it is now.
+    pub fn start(world: &World) -> std::io::Result<Self> {
+        Ok(Self {
+            start: world.to_document()?,
+            ticks: 0,
+        })
+    }
+
+    /// Count a tick, which passed since the start.
+    pub fn record_tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    /// Get the ticks, which passed since the start.
+    pub fn get_ticks(&self) -> usize {
+        self.ticks
+    }
+
+    /// Get the world, as it was, when the recording started.
+    pub fn get_start(&self) -> std::io::Result<World> {
+        World::from_document(self.start.clone())
+    }
+
+    /// Save the recording into a file.
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let document = serde_json::json!({
+            "format": RECORDING_FORMAT,
+            "ticks": self.ticks,
+            "start": self.start,
+        });
+        std::fs::write(path, serde_json::to_string_pretty(&document)?)
+    }
+
+    /// Load a recording from a file.
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        let mut document: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        if document.get("format").and_then(|format| format.as_str()) != Some(RECORDING_FORMAT) {
+            return Err(invalid_data(format!(
+                "Not a '{}' document.",
+                RECORDING_FORMAT
+            )));
+        }
+
+        let ticks = document
+            .get("ticks")
+            .and_then(|ticks| ticks.as_u64())
+            .ok_or_else(|| invalid_data("Missing ticks.".to_string()))?;
+
+        let start = document
+            .get_mut("start")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| invalid_data("Missing start.".to_string()))?;
+
+        Ok(Self {
+            start,
+            ticks: ticks as usize,
+        })
+    }
+}
+
+/// Wrap the saved world into the envelope with format and version.
+pub fn wrap(world: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "format": FORMAT,
+        "version": VERSION,
+        "world": world,
+    })
+}
+
+/// Unwrap the saved world from the envelope, and migrate it to the current version.
+pub fn unwrap_and_migrate(mut document: serde_json::Value) -> std::io::Result<serde_json::Value> {
+    if document.get("format").and_then(|format| format.as_str()) != Some(FORMAT) {
+        return Err(invalid_data(format!("Not a '{}' document.", FORMAT)));
+    }
+
+    let version = document
+        .get("version")
+        .and_then(|version| version.as_u64())
+        .ok_or_else(|| invalid_data("Missing version.".to_string()))?;
+
+    if version == 0 || version > VERSION {
+        return Err(invalid_data(format!(
+            "Unsupported version {} (supported up to {}).",
+            version, VERSION
+        )));
+    }
+
+    let mut world = document
+        .get_mut("world")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| invalid_data("Missing world.".to_string()))?;
+
+    for migration in MIGRATIONS[(version - 1) as usize..].iter() {
+        migration(&mut world)?;
+    }
+
+    Ok(world)
+}
+
+/// Create an error for invalid save data.
+pub fn invalid_data(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// Static strings, which were loaded before (see [deserialize_static_str]).
+static LOADED_STATIC_STRS: std::sync::Mutex<std::collections::BTreeSet<&'static str>> =
+    std::sync::Mutex::new(std::collections::BTreeSet::new());
+
+/// Deserialize a string, that needs to live as long as the game.
+///
+/// Some names (like object subtypes) are static strings,
+/// loaded ones are leaked once, to live as long as the built-in ones.
+/// Every distinct string is only leaked on its first load, later loads reuse it.
+pub fn deserialize_static_str<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let string: String = serde::Deserialize::deserialize(deserializer)?;
+    let mut loaded = LOADED_STATIC_STRS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(&interned) = loaded.get(string.as_str()) {
+        return Ok(interned);
+    }
+    let interned: &'static str = Box::leak(string.into_boxed_str());
+    loaded.insert(interned);
+    Ok(interned)
+}
+
+/// Save the relations as a list, since JSON only supports string keys.
+pub mod relations {
+    use super::*;
+
+    type Relations =
+        std::collections::BTreeMap<(wusels::WuselId, wusels::WuselId), wusels::relations::Relation>;
+
+    pub fn serialize<S>(relations: &Relations, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(relations.iter())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Relations, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let saved: Vec<(
+            (wusels::WuselId, wusels::WuselId),
+            wusels::relations::Relation,
+        )> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(saved.into_iter().collect())
+    }
+}
@@ -0,0 +1,655 @@
+//! # Scenario
+//!
+//! Declarative description of a starting world, which can be authored as a file
+//! and loaded without recompiling.
+//!
+//! ## Format
+//!
+//! A scenario is a JSON document. All lists may be left out, a missing size is set by the caller.
+//! Positions are given as `{ "x": 1, "y": 2, "z": 0 }`, a missing position is random.
+//! Wusels and objects are referenced by their index in the scenario's lists.
+//! Objects with a `storage` capacity can keep other objects, which are `stored_in` them.
+//! Food spoils after some days (`spoils_after`, 0: never), storages may slow aging (`aging_percent`).
+//! With a `seed`, all random choices (also of the following life) are reproducible.
+//! Pregnancies last `pregnancy_days` (see `World::PREGNANCY_DAYS`, if not given).
+//! Wusels grow up and grow old on the days of the `life_stages` (see `LifeStages::default`, if not given),
+//! they start as adults, unless their `lived_days` are given.
+//! Wusels have the reproductive traits typical for their gender, unless `reproduction` is given.
+//! Wusels of a scenario decide on their own what to do, unless `autonomous` is false.
+//!
+//! ```json
+//! {
+//!   "name": "Tiny House",
+//!   "width": 40, "depth": 20, "height": 1,
+//!   "seed": 42,
+//!   "autonomous": true,
+//!   "pregnancy_days": 5,
+//!   "life_stages": { "child": 3, "teen": 10, "adult": 16, "elder": 60, "old_age": 80 },
+//!   "random_wusels": 2,
+//!   "wusels": [
+//!     { "name": "Mo", "gender": "Female", "position": { "x": 3, "y": 3, "z": 0 },
+//!       "reproduction": { "can_carry": true, "can_impregnate": false }, "lived_days": 20,
+//!       "needs": { "FOOD": 200 }, "abilities": { "COOKING": 5 },
+//!       "tasks": [ { "type": "move_to", "position": { "x": 12, "y": 3, "z": 0 } } ] }
+//!   ],
+//!   "objects": [
+//!     { "object_type": { "Food": "Bread" }, "bites": 3, "position": { "x": 5, "y": 5, "z": 0 } },
+//!     { "object_type": { "Furniture": "Fridge" }, "storage": 10, "aging_percent": 25,
+//!       "transportable": false, "passable": false, "position": { "x": 3, "y": 5, "z": 0 } },
+//!     { "object_type": { "Food": "Kimchi" }, "bites": 8, "spoils_after": 30, "stored_in": 1 }
+//!   ],
+//!   "constructions": [
+//!     { "type": "wall", "direction": "horizontal", "length": 10, "position": { "x": 1, "y": 1, "z": 0 } },
+//!     { "type": "door", "open": false, "locked": true, "owner": 0, "position": { "x": 4, "y": 1, "z": 0 } },
+//!     { "type": "window", "position": { "x": 6, "y": 1, "z": 0 } },
+//!     { "type": "stairs", "up": true, "position": { "x": 8, "y": 3, "z": 0 } },
+//!     { "type": "floor", "width": 8, "depth": 4, "position": { "x": 2, "y": 2, "z": 0 } }
+//!   ],
+//!   "rooms": [ { "name": "Kitchen", "position": { "x": 3, "y": 3, "z": 0 } } ]
+//! }
+//! ```
+//!
+//! Tasks are one of:
+//! - `{ "type": "move_to", "position": ... }`
+//! - `{ "type": "meet_with", "wusel": 1, "friendly": true, "romantically": false }`
+//! - `{ "type": "romance", "wusel": 1 }` (may lead to a pregnancy, if both consent)
+//! - `{ "type": "use_object", "object": 0, "action": 3 }`
+//! - `{ "type": "store_object", "object": 0, "storage": 1 }`
+//! - `{ "type": "craft", "blueprint": 0 }` (see `World::default_blueprints`)
+//! - `{ "type": "use_door", "position": ..., "action": "Open" | "Close" | "Lock" | "Unlock" }`
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::objects;
+use crate::life::world;
+use crate::life::world::areas;
+use crate::life::world::items;
+use crate::life::world::save;
+use crate::life::wusels;
+use crate::life::wusels::tasks;
+use crate::util;
+
+use rand::Rng;
+
+/// A scenario: The description of a starting world.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default)]
+    pub name: String,
+
+    pub width: Option<u32>,
+    pub depth: Option<u32>,
+    pub height: Option<u32>,
+
+    pub seed: Option<u64>, // random seed if not given.
+
+    #[serde(default = "default_autonomous")]
+    pub autonomous: bool, // idle wusels decide on their own, what to do next.
+
+    pub pregnancy_days: Option<u8>, // the world's default, if not given.
+
+    pub life_stages: Option<wusels::stages::LifeStages>, // the world's default, if not given.
+
+    #[serde(default)]
+    pub random_wusels: usize, // wusels with random names, genders and positions.
+
+    #[serde(default)]
+    pub wusels: Vec<ScenarioWusel>,
+
+    #[serde(default)]
+    pub objects: Vec<ScenarioObject>,
+
+    #[serde(default)]
+    pub constructions: Vec<ScenarioConstruction>,
+
+    #[serde(default)]
+    pub rooms: Vec<ScenarioRoom>,
+}
+
+/// Scenarios describe living worlds: Idle wusels decide on their own by default.
+fn default_autonomous() -> bool {
+    true
+}
+
+/// A wusel in the scenario.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioWusel {
+    pub name: String,
+    pub gender: Option<wusels::WuselGender>, // random if not given.
+    pub reproduction: Option<wusels::Reproduction>, // typical for the gender, if not given.
+    pub lived_days: Option<u32>,             // grown up (an adult), if not given.
+    pub position: Option<areas::Position>,
+
+    #[serde(default)]
+    pub needs: std::collections::HashMap<wusels::needs::Need, u32>,
+
+    #[serde(default)]
+    pub abilities: std::collections::HashMap<wusels::abilities::Ability, u32>,
+
+    #[serde(default)]
+    pub tasks: Vec<ScenarioTask>, // in the order, they are done.
+}
+
+/// An object in the scenario.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioObject {
+    pub object_type: objects::ObjectType,
+    pub name: Option<String>, // the subtype, if not given.
+
+    #[serde(default)]
+    pub bites: u16,
+    #[serde(default)]
+    pub storage: u16,
+    #[serde(default = "default_true")]
+    pub transportable: bool,
+    #[serde(default = "default_true")]
+    pub passable: bool,
+
+    pub spoils_after: Option<u32>, // days, the world's default for food if not given.
+    pub aging_percent: Option<u32>, // how fast stored objects age, 100% if not given.
+
+    pub position: Option<areas::Position>,
+    pub stored_in: Option<usize>, // index of a scenario object, instead of a position.
+}
+
+/// Growing direction of a wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WallDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// A construction in the scenario.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioConstruction {
+    Wall {
+        position: areas::Position,
+        direction: WallDirection,
+        length: usize,
+    },
+    Door {
+        position: areas::Position,
+        #[serde(default)]
+        open: bool,
+        #[serde(default)]
+        locked: bool,
+        owner: Option<usize>, // index of a scenario wusel.
+    },
+    Window {
+        position: areas::Position,
+    },
+    Stairs {
+        position: areas::Position,
+        up: bool,
+    },
+    Floor {
+        position: areas::Position,
+        #[serde(default = "default_one")]
+        width: u32,
+        #[serde(default = "default_one")]
+        depth: u32,
+    },
+}
+
+/// A name for the room, which contains the position.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioRoom {
+    pub name: String,
+    pub position: areas::Position,
+}
+
+/// An initial task of a scenario wusel.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioTask {
+    MoveTo {
+        position: areas::Position,
+    },
+    MeetWith {
+        wusel: usize, // index of a scenario wusel.
+        #[serde(default = "default_true")]
+        friendly: bool,
+        #[serde(default)]
+        romantically: bool,
+    },
+    Romance {
+        wusel: usize, // index of a scenario wusel.
+    },
+    UseObject {
+        object: usize, // index of a scenario object.
+        action: tasks::ActionId,
+    },
+    StoreObject {
+        object: usize,  // index of a scenario object.
+        storage: usize, // index of a scenario object.
+    },
+    UseDoor {
+        position: areas::Position,
+        action: tasks::DoorAction,
+    },
+    Craft {
+        blueprint: items::BlueprintId, // index of the world's default blueprints.
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_one() -> u32 {
+    1
+}
+
+impl Scenario {
+    /// Read a scenario from a file.
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read a scenario from a JSON text.
+    pub fn parse(text: &str) -> std::io::Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Override the world's size, where a new size is given.
+    pub fn set_size(&mut self, width: Option<u32>, depth: Option<u32>, height: Option<u32>) {
+        self.width = width.or(self.width);
+        self.depth = depth.or(self.depth);
+        self.height = height.or(self.height);
+    }
+
+    /// Set the world's size, if the scenario does not define it.
+    pub fn set_default_size(&mut self, width: u32, depth: u32) {
+        self.width.get_or_insert(width);
+        self.depth.get_or_insert(depth);
+    }
+
+    /// Create the described world.
+    ///
+    /// Everything is checked before the world is built,
+    /// so an invalid scenario creates no world, but an error describing the first problem.
+    pub fn build(&self) -> std::io::Result<world::World> {
+        self.validate()?;
+
+        let (width, depth, height) = self.get_dimensions();
+        let mut world = world::World::new_with_levels(width, depth, height);
+        if let Some(seed) = self.seed {
+            world.set_seed(seed);
+        }
+        world.set_autonomous(self.autonomous);
+        if let Some(days) = self.pregnancy_days {
+            world.set_pregnancy_days(days);
+        }
+        if let Some(life_stages) = self.life_stages {
+            world.set_life_stages(life_stages);
+        }
+
+        // constructions first, so wusels and objects find free positions.
+        for construction in self.constructions.iter() {
+            match *construction {
+                ScenarioConstruction::Wall {
+                    position,
+                    direction,
+                    length,
+                } => world.construction_new(
+                    items::ConstructionType::Wall(direction == WallDirection::Horizontal, length),
+                    position,
+                ),
+                ScenarioConstruction::Door { position, open, .. } => {
+                    world.construction_new(items::ConstructionType::Door(open), position)
+                }
+                ScenarioConstruction::Window { position } => {
+                    world.construction_new(items::ConstructionType::Window, position)
+                }
+                ScenarioConstruction::Stairs { position, up } => {
+                    world.construction_new(items::ConstructionType::Stairs(up), position)
+                }
+                ScenarioConstruction::Floor {
+                    position,
+                    width,
+                    depth,
+                } => {
+                    // The floor's extent was validated to be within the world.
+                    for x in position.x..position.x + width {
+                        for y in position.y..position.y + depth {
+                            world.construction_new(
+                                items::ConstructionType::Floor,
+                                areas::Position::new(x, y, position.z),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        for room in self.rooms.iter() {
+            if let Some(room_id) = world.room_at(room.position) {
+                world.room_set_name(room_id, room.name.clone());
+            }
+        }
+
+        let mut wusel_handles = vec![];
+        for wusel in self.wusels.iter() {
+            let gender = wusel
+                .gender
+                .unwrap_or_else(|| wusels::WuselGender::random(world.get_rng()));
+            let position = wusel.position.unwrap_or_else(|| world.position_random());
+            let wusel_handle = world.wusel_new(wusel.name.clone(), gender, position);
+
+            if let Some(reproduction) = wusel.reproduction {
+                world.wusel_set_reproduction(wusel_handle, reproduction);
+            }
+            if let Some(lived_days) = wusel.lived_days {
+                world.wusel_set_lived_days(wusel_handle, lived_days);
+            }
+            for (need, &value) in wusel.needs.iter() {
+                world.wusel_set_need(wusel_handle, need, value);
+            }
+            for (&ability, &value) in wusel.abilities.iter() {
+                world.wusel_set_ability(wusel_handle, ability, value);
+            }
+
+            wusel_handles.push(wusel_handle);
+        }
+
+        for _ in 0..self.random_wusels {
+            let name_length = world.get_rng().gen_range(2..15);
+            let name = util::more_strings::name_gen(name_length, world.get_rng());
+            world.wusel_new_random(name);
+        }
+
+        let mut object_handles = vec![];
+        for object in self.objects.iter() {
+            let name = object
+                .name
+                .clone()
+                .unwrap_or_else(|| object.object_type.get_subtype().to_string());
+            let object_handle = world.object_new(
+                object.object_type,
+                name,
+                object.transportable,
+                object.passable,
+                object.bites,
+                object.storage,
+            );
+            if let Some(days) = object.spoils_after {
+                world.object_set_spoils_after(object_handle, days);
+            }
+            if let Some(percent) = object.aging_percent {
+                world.object_set_aging_percent(object_handle, percent);
+            }
+            object_handles.push(object_handle);
+        }
+
+        // place the objects, now that all storages exist.
+        for (object, &object_handle) in self.objects.iter().zip(object_handles.iter()) {
+            if let Some(storage) = object.stored_in {
+                if !world.object_store(object_handle, object_handles[storage]) {
+                    log::warn!(
+                        "Object[{}] could not be stored in Object[{}].",
+                        object_handle,
+                        object_handles[storage]
+                    );
+                }
+                continue;
+            }
+
+            let position = object.position.unwrap_or_else(|| world.position_random());
+            if !world.object_set_position(object_handle, position) {
+                log::warn!(
+                    "Object[{}] could not be placed on {:?}.",
+                    object_handle,
+                    position
+                );
+            }
+        }
+
+        // doors with owners and locks, now that the wusels exist.
+        for construction in self.constructions.iter() {
+            if let ScenarioConstruction::Door {
+                position,
+                locked,
+                owner,
+                ..
+            } = *construction
+            {
+                let door_id = world.door_at(position).unwrap(); // placed above.
+                let owner = owner.map(|owner| wusel_handles[owner]);
+                world.door_set_owner(door_id, owner);
+                match owner {
+                    _ if !locked => {}
+                    Some(owner) => {
+                        world.door_use(door_id, owner, tasks::DoorAction::Lock);
+                    }
+                    None => {
+                        // locked for everyone, nobody needs to turn the key.
+                        let index = world.door_id_to_index(door_id).unwrap(); // placed above.
+                        world.door_set_open(index, false);
+                        world.constructions[index].set_locked(true);
+                    }
+                }
+            }
+        }
+
+        for (wusel, &wusel_handle) in self.wusels.iter().zip(wusel_handles.iter()) {
+            for task in wusel.tasks.iter() {
+                let task_builder = match *task {
+                    ScenarioTask::MoveTo { position } => tasks::TaskBuilder::move_to(position),
+                    ScenarioTask::MeetWith {
+                        wusel,
+                        friendly,
+                        romantically,
+                    } => {
+                        tasks::TaskBuilder::meet_with(wusel_handles[wusel], friendly, romantically)
+                    }
+                    ScenarioTask::Romance { wusel } => {
+                        tasks::TaskBuilder::romance_with(wusel_handles[wusel])
+                    }
+                    ScenarioTask::UseObject { object, action } => {
+                        tasks::TaskBuilder::use_object(object_handles[object], action)
+                    }
+                    ScenarioTask::StoreObject { object, storage } => {
+                        tasks::TaskBuilder::store_object(
+                            object_handles[object],
+                            object_handles[storage],
+                        )
+                    }
+                    ScenarioTask::UseDoor { position, action } => {
+                        let door_id = world.door_at(position).unwrap(); // validated.
+                        tasks::TaskBuilder::use_door(door_id, action)
+                    }
+                    ScenarioTask::Craft { blueprint } => {
+                        let steps = world.blueprint_get(blueprint).unwrap().get_steps(); // validated.
+                        tasks::TaskBuilder::craft(blueprint, steps)
+                    }
+                };
+                world.wusel_assign_to_task(wusel_handle, task_builder);
+            }
+        }
+
+        Ok(world)
+    }
+
+    /// Get the world's size (width, depth, height).
+    fn get_dimensions(&self) -> (u32, u32, u32) {
+        (
+            self.width.unwrap_or(0),
+            self.depth.unwrap_or(0),
+            self.height.unwrap_or(1),
+        )
+    }
+
+    /// Check the scenario for mistakes, like positions out of the world or unknown references.
+    pub fn validate(&self) -> std::io::Result<()> {
+        let (width, depth, height) = self.get_dimensions();
+        if width == 0 || depth == 0 || height == 0 {
+            return Err(save::invalid_data(format!(
+                "Scenario '{}' has no valid size ({}x{}x{}).",
+                self.name, width, depth, height
+            )));
+        }
+
+        if world::World::position_count(width, depth, height).is_none() {
+            return Err(save::invalid_data(format!(
+                "Scenario '{}' is too large ({}x{}x{}), a world may have at most {} positions.",
+                self.name,
+                width,
+                depth,
+                height,
+                world::World::MAX_POSITIONS
+            )));
+        }
+
+        if let Some(stages) = self.life_stages {
+            let days = [
+                stages.child,
+                stages.teen,
+                stages.adult,
+                stages.elder,
+                stages.old_age,
+            ];
+            if days.windows(2).any(|pair| pair[0] > pair[1]) {
+                return Err(save::invalid_data(format!(
+                    "Scenario '{}' has life stages out of order: {:?}.",
+                    self.name, stages
+                )));
+            }
+        }
+
+        let area = areas::Area::new(areas::Position::ROOT, width, depth, height);
+        let check_position = |what: String, position: &areas::Position| {
+            if area.contains_position(position) {
+                Ok(())
+            } else {
+                Err(save::invalid_data(format!(
+                    "{} is out of the world: {:?}.",
+                    what, position
+                )))
+            }
+        };
+        let check_object = |what: String, object: usize| {
+            if object < self.objects.len() {
+                Ok(())
+            } else {
+                Err(save::invalid_data(format!(
+                    "{} references the unknown object {}.",
+                    what, object
+                )))
+            }
+        };
+        let check_wusel = |what: String, wusel: usize| {
+            if wusel < self.wusels.len() {
+                Ok(())
+            } else {
+                Err(save::invalid_data(format!(
+                    "{} references the unknown wusel {}.",
+                    what, wusel
+                )))
+            }
+        };
+
+        let mut doors = vec![];
+
+        for (index, construction) in self.constructions.iter().enumerate() {
+            let what = format!("Construction {}", index);
+            match construction {
+                ScenarioConstruction::Wall { position, .. }
+                | ScenarioConstruction::Window { position }
+                | ScenarioConstruction::Stairs { position, .. } => check_position(what, position)?,
+                ScenarioConstruction::Floor {
+                    position,
+                    width,
+                    depth,
+                } => {
+                    check_position(what.clone(), position)?;
+                    let last = position
+                        .x
+                        .checked_add(width.saturating_sub(1))
+                        .zip(position.y.checked_add(depth.saturating_sub(1)))
+                        .map(|(x, y)| areas::Position::new(x, y, position.z))
+                        .ok_or_else(|| {
+                            save::invalid_data(format!(
+                                "{} is too large: {}x{} from {:?}.",
+                                what, width, depth, position
+                            ))
+                        })?;
+                    check_position(what, &last)?;
+                }
+                ScenarioConstruction::Door {
+                    position, owner, ..
+                } => {
+                    check_position(what.clone(), position)?;
+                    if let Some(owner) = owner {
+                        check_wusel(what, *owner)?;
+                    }
+                    doors.push(*position);
+                }
+            }
+        }
+
+        for (index, room) in self.rooms.iter().enumerate() {
+            check_position(format!("Room {}", index), &room.position)?;
+        }
+
+        for (index, wusel) in self.wusels.iter().enumerate() {
+            let what = format!("Wusel {} ({})", index, wusel.name);
+            if let Some(position) = wusel.position.as_ref() {
+                check_position(what.clone(), position)?;
+            }
+            for task in wusel.tasks.iter() {
+                match task {
+                    ScenarioTask::MoveTo { position } => check_position(what.clone(), position)?,
+                    ScenarioTask::MeetWith { wusel, .. } | ScenarioTask::Romance { wusel } => {
+                        check_wusel(what.clone(), *wusel)?
+                    }
+                    ScenarioTask::UseObject { object, .. } => check_object(what.clone(), *object)?,
+                    ScenarioTask::StoreObject { object, storage } => {
+                        check_object(what.clone(), *object)?;
+                        check_object(what.clone(), *storage)?;
+                    }
+                    ScenarioTask::UseDoor { position, .. } if !doors.contains(position) => {
+                        return Err(save::invalid_data(format!(
+                            "{} references no door on {:?}.",
+                            what, position
+                        )));
+                    }
+                    ScenarioTask::UseDoor { .. } => {}
+                    ScenarioTask::Craft { blueprint }
+                        if *blueprint >= world::World::default_blueprints().len() =>
+                    {
+                        return Err(save::invalid_data(format!(
+                            "{} references the unknown blueprint {}.",
+                            what, blueprint
+                        )));
+                    }
+                    ScenarioTask::Craft { .. } => {}
+                }
+            }
+        }
+
+        for (index, object) in self.objects.iter().enumerate() {
+            let what = format!("Object {}", index);
+            if let Some(position) = object.position.as_ref() {
+                check_position(what.clone(), position)?;
+            }
+            if let Some(storage) = object.stored_in {
+                check_object(what.clone(), storage)?;
+                if self.objects[storage].storage == 0 {
+                    return Err(save::invalid_data(format!(
+                        "{} is stored in object {}, which has no storage.",
+                        what, storage
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,17 +5,23 @@
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
+use crate::life::objects;
 use crate::life::world;
 use crate::life::world::areas;
 use crate::life::wusels;
 use crate::life::wusels::tasks;
 
+use rand::Rng;
+
 const MEET_RESULT_ERROR: i8 = -1; //  meeting error.
 const MEET_RESULT_OK: i8 = 0; //  When they met, like the C-ish "OK".
 const MEET_RESULT_FOLLOWED: i8 = 1; //  When the actor walked, they might not have met yet.
 const MEET_RESULT_KNOCKED: i8 = 2; //  When the actual knocking was just applied, they know both of the meeting, but that may come next.
 const MEET_RESULT_WAITED: i8 = 3; //  When the knocking was done, but the passive is still busy, they actually have not met like intended.
 
+/// Sleep recovered per tick of resting (also compensates the sleep's decay).
+pub const REST_SLEEP_PER_TICK: i16 = 4;
+
 /// Proceed the task in this world.
 pub fn proceed(world: &mut world::World, task: tasks::Task) {
     // World proceeds task.
@@ -23,17 +29,27 @@ pub fn proceed(world: &mut world::World, task: tasks::Task) {
     let actor_id = task.get_active_actor_id();
     let actor_index = world.get_wusels_index_by_id(actor_id);
 
-    if actor_index == None {
+    if actor_index.is_none() {
         return; // abort, because actor unavailable
     }
 
     let actor_index = actor_index.unwrap();
 
+    // Not everybody may do everything (eg. babies cannot cook).
+    if !world.wusels[actor_index]
+        .get_life_stage()
+        .may_do(&task.get_passive_part())
+    {
+        log::info!("Wusel {} is too young for {}.", actor_id, task.get_name());
+        world.wusels[actor_index].pop_ongoing_task();
+        return;
+    }
+
     let start_time = match task.has_started() {
         true => task.get_start_time(),
         false => {
             // Notify the start of the task (for the wusel).
-            world.wusels[actor_id].start_ongoing_task(world.clock);
+            world.wusels[actor_index].start_ongoing_task(world.clock);
 
             world.clock // starting now
         }
@@ -58,9 +74,10 @@ pub fn proceed(world: &mut world::World, task: tasks::Task) {
                     None => true,
 
                     // Other party is busy.
-                    Some(t) => {
-                        !matches!(t.get_passive_part(), tasks::TaskTag::MeetWith(id, _nice, _love) if id == actor_id)
-                    }
+                    Some(t) => !matches!(
+                        t.get_passive_part(),
+                        tasks::TaskTag::MeetWith(id, _, _) | tasks::TaskTag::Romance(id) if id == actor_id
+                    ),
                 }
             } else {
                 // => proceed, since the other party was invalid.
@@ -68,74 +85,85 @@ pub fn proceed(world: &mut world::World, task: tasks::Task) {
             }
         }
         tasks::TaskTag::MeetWith(other_id, nice, romantically) => {
-            let other_index = world.get_wusels_index_by_id(other_id);
+            let met = let_wusel_meet(world, actor_index, other_id, start_time, nice, romantically);
 
-            // Other wusel needs also to exist.
-            if other_index == None {
-                world.wusels[actor_index].pop_ongoing_task();
-                return; // task can not be done, without target.
+            if met && task.get_rest_time() <= 1 {
+                let_wusels_practise_communication(world, actor_index, other_id);
             }
-
-            let other_index = other_index.unwrap();
-
-            // Check all preconditions, maybe solve one and maybe do the actually meeting.
-            // 0, when they met, like the C-ish "OK".
-            // 1, when the actor walked.
-            // 2, when the actual knocking was just applied.
-            // 3, when the knocking was done, but the passive is still busy.
-            let meeting_result =
-                let_two_wusels_meet(world, actor_index, other_index, nice, romantically);
-
-            // On Final Success with own step,
-            // also let the BeMetFrom() succeed.
-
-            match meeting_result {
-                // waiting, but don't wait too long.
-                MEET_RESULT_WAITED => {
-                    if world.clock - start_time >= tasks::Task::PATIENCE_TO_MEET {
-                        world.wusels[actor_index].pop_ongoing_task();
-                    }
-                    false // => do not notify succession
+            met
+        }
+        tasks::TaskTag::Romance(other_id) => {
+            let met = let_wusel_meet(world, actor_index, other_id, start_time, true, true);
+
+            // After the romantic time, they may conceive a child.
+            if met && task.get_rest_time() <= 1 {
+                let_wusels_practise_communication(world, actor_index, other_id);
+                if let Some(other_index) = world.get_wusels_index_by_id(other_id) {
+                    let_two_wusels_conceive(world, actor_index, other_index);
                 }
-
-                // They met and the task is over.
-                MEET_RESULT_OK => true, // => notify process
-                _ => false,             // => no process (FOLLOWED, KNOCKED or an unexpected)
             }
+            met
         }
         tasks::TaskTag::MoveToPos(position) => {
             // Let the wusel walk; check if they stopped.
             let stopped: bool = let_wusel_walk_to_position(world, actor_index, position);
 
+            // Arriving at the goal is good exercise.
+            if stopped
+                && world.wusels_index_on_position_index[actor_index]
+                    == world.position_to_index(position)
+            {
+                world.wusels[actor_index].improve(wusels::abilities::Ability::FITNESS);
+            }
+
             stopped // true == stop == success.
         }
         tasks::TaskTag::UseObject(object_id, action_id) => {
-            // TODO: get index for the given object ID.
-            let object_index = world
-                .objects
-                .iter()
-                .position(|wo| wo.get_object_id() == object_id);
-
-            // TODO: get index for the given action ID.
-            let action_index = if action_id >= world.actions.len() {
-                None
+            let object_index = world.object_id_to_index(object_id);
+            let action = world.action_get(action_id).cloned();
+
+            if let (Some(object_index), Some(action)) = (object_index, action) {
+                let is_last_step = task.get_rest_time() <= 1;
+                let_wusel_use_object(
+                    world,
+                    actor_index,
+                    object_index,
+                    action_id,
+                    &action,
+                    is_last_step,
+                )
             } else {
-                Some(action_id)
-            };
-
-            if object_index == None || action_index == None {
                 log::warn!(
                     "Object[{:?}] or Action[{}] could not be found.",
                     object_id,
                     action_id
                 );
                 true // proceed to next action.
-            } else {
-                let object_index = object_index.unwrap(); // TODO
-                let action_index = action_index.unwrap(); // TODO
-                let_wusel_use_object(world, actor_index, object_index, action_index)
             }
         }
+        tasks::TaskTag::StoreObject(object_id, storage_id) => {
+            let_wusel_store_object(world, actor_index, object_id, storage_id)
+        }
+        tasks::TaskTag::UseDoor(door_id, door_action) => {
+            let_wusel_use_door(world, actor_index, door_id, door_action)
+        }
+        tasks::TaskTag::Craft(blueprint_id) => {
+            let is_first_step = task.get_done_steps() == 0;
+            let is_last_step = task.get_rest_time() <= 1;
+            let_wusel_craft(
+                world,
+                actor_index,
+                blueprint_id,
+                is_first_step,
+                is_last_step,
+            )
+        }
+        tasks::TaskTag::Rest => {
+            // Recover a bit of sleep with every step.
+            world.wusels[actor_index]
+                .set_need_relative(wusels::needs::Need::SLEEP, REST_SLEEP_PER_TICK);
+            true
+        }
     };
 
     // Notify the task succeeded to do a step.
@@ -144,6 +172,121 @@ pub fn proceed(world: &mut world::World, task: tasks::Task) {
     }
 }
 
+/// Let the actor meet another wusel, given by their ID.
+///
+/// If the other wusel is gone, or the actor waited too long for them, the actor gives up the meeting.
+///
+/// Returns if they met (and the meeting proceeds a step).
+fn let_wusel_meet(
+    world: &mut world::World,
+    actor_index: usize,
+    other_id: wusels::WuselId,
+    start_time: usize,
+    nice: bool,
+    romantically: bool,
+) -> bool {
+    let other_index = world.get_wusels_index_by_id(other_id);
+
+    // Other wusel needs also to exist.
+    if other_index.is_none() {
+        world.wusels[actor_index].pop_ongoing_task();
+        return false; // task can not be done, without target.
+    }
+
+    let other_index = other_index.unwrap();
+
+    // Check all preconditions, maybe solve one and maybe do the actually meeting.
+    // 0, when they met, like the C-ish "OK".
+    // 1, when the actor walked.
+    // 2, when the actual knocking was just applied.
+    // 3, when the knocking was done, but the passive is still busy.
+    let meeting_result = let_two_wusels_meet(world, actor_index, other_index, nice, romantically);
+
+    // On Final Success with own step,
+    // also let the BeMetFrom() succeed.
+
+    match meeting_result {
+        // waiting, but don't wait too long.
+        MEET_RESULT_WAITED => {
+            if world.clock - start_time >= tasks::Task::PATIENCE_TO_MEET {
+                world.wusels[actor_index].pop_ongoing_task();
+            }
+            false // => do not notify succession
+        }
+
+        // They met and the task is over.
+        MEET_RESULT_OK => true, // => notify process
+        _ => false,             // => no process (FOLLOWED, KNOCKED or an unexpected)
+    }
+}
+
+/// Let the actor and the other wusel (if still there) practise communicating, after they met.
+fn let_wusels_practise_communication(
+    world: &mut world::World,
+    actor_index: usize,
+    other_id: wusels::WuselId,
+) {
+    let communication = wusels::abilities::Ability::COMMUNICATION;
+    world.wusels[actor_index].improve(communication);
+    if let Some(other_index) = world.get_wusels_index_by_id(other_id) {
+        world.wusels[other_index].improve(communication);
+    }
+}
+
+/// Let two wusels, who just had a romantic time, maybe conceive a child.
+///
+/// Both must consent (see `World::wusels_consent_to_romance`),
+/// one must be able to carry (and not be pregnant yet), the other to impregnate,
+/// and both must be in the stage to conceive (see `LifeStage::may_conceive`).
+/// If both could carry the child, chance decides, and even then it may not happen.
+///
+/// Returns if a pregnancy started.
+fn let_two_wusels_conceive(
+    world: &mut world::World,
+    active_index: usize,
+    passive_index: usize,
+) -> bool {
+    let active = world.wusel_handle_at(active_index);
+    let passive = world.wusel_handle_at(passive_index);
+
+    if !world.wusels_consent_to_romance(active, passive) {
+        return false;
+    }
+
+    let carriers: Vec<(usize, wusels::WuselId)> =
+        [(active_index, passive_index), (passive_index, active_index)]
+            .iter()
+            .filter(|&&(carrier, other)| {
+                !world.wusels[carrier].is_pregnant()
+                    && world.wusels[carrier].get_life_stage().may_conceive()
+                    && world.wusels[other].get_life_stage().may_conceive()
+                    && world.wusels[carrier]
+                        .get_reproduction()
+                        .may_conceive_with(&world.wusels[other].get_reproduction())
+            })
+            .map(|&(carrier, other)| (carrier, world.wusels[other].get_id()))
+            .collect();
+
+    if carriers.is_empty() {
+        return false;
+    }
+
+    let (carrier_index, other_parent_id) = carriers[world.get_rng().gen_range(0..carriers.len())];
+
+    if world.get_rng().gen_range(0..100) >= world::World::CONCEPTION_PERCENT {
+        return false; // not this time.
+    }
+
+    log::info!(
+        "Wusel {} is pregnant with a child of {}.",
+        world.wusels[carrier_index].get_id(),
+        other_parent_id
+    );
+    let pregnancy_days = world.get_pregnancy_days();
+    world.wusels[carrier_index].set_pregnancy(Some(other_parent_id), Some(pregnancy_days));
+    true
+}
+
 /// Arrange the meeting of two wusels.
 /// They must both exist.
 /// They must be close to each other (neighbour fields or shared desk/bench...).
@@ -184,7 +327,7 @@ fn let_two_wusels_meet(
 
     let position_passive_wusel = world.position_from_index(passive_position_index);
 
-    if position_passive_wusel == None {
+    if position_passive_wusel.is_none() {
         return MEET_RESULT_ERROR; // No position.
     }
 
@@ -216,23 +359,13 @@ fn let_two_wusels_meet(
     let handshake_okay = matches!(&passives_ongoing_tasktag, Some(tag) if *tag == *active_is_met);
 
     if handshake_okay {
-        let performance: bool; // how well is the communication
-
-        performance = true;
-        // random influence of 10%
-        // current value and intention
-        // communication ability
-
-        // Update the relation between active and passive.
-        world.wusel_update_relations(
-            active_id,
-            passive_id,
-            intention_good && performance,
-            if romantically {
-                wusels::relations::RelationType::Romance
-            } else {
-                wusels::relations::RelationType::Friendship
-            },
+        // How well they communicate changes their relation and how they feel.
+        world::communication::let_two_wusels_communicate(
+            world,
+            active_index,
+            passive_index,
+            intention_good,
+            romantically,
         );
 
         return MEET_RESULT_OK; // they actually met.
@@ -242,7 +375,10 @@ fn let_two_wusels_meet(
     let passive_is_waiting = world.wusels[passive_index].has_task_with(active_is_met);
 
     // Check if they both want an (actively) Meeting each other.
-    let mutuall_meeting_as_actives = matches!(&passives_ongoing_tasktag, Some(tasks::TaskTag::MeetWith(id, _, _)) if *id == active_id);
+    let mutuall_meeting_as_actives = matches!(
+        &passives_ongoing_tasktag,
+        Some(tasks::TaskTag::MeetWith(id, _, _) | tasks::TaskTag::Romance(id)) if *id == active_id
+    );
 
     // They are blocking each other by waiting.
     // A: "I want to talk with you, but wait until you're done with your task."
@@ -299,10 +435,11 @@ fn let_two_wusels_meet(
             _ => (passive_index, active_index),
         };
 
+        let more_active_id = world.wusels[more_active].get_id();
         world.wusel_assign_to_task(
-            more_passive,
-            tasks::TaskBuilder::be_met_from(more_active)
-                .set_name(format!("Be met by {}", more_active)),
+            world.wusel_handle_at(more_passive),
+            tasks::TaskBuilder::be_met_from(more_active_id)
+                .set_name(format!("Be met by {}", more_active_id)),
         );
 
         return MEET_RESULT_KNOCKED;
@@ -312,7 +449,10 @@ fn let_two_wusels_meet(
     // I am there and wait for them to be ready.
     if !passive_is_waiting {
         // Tell passive to be ready for active.
-        world.wusel_assign_to_task(passive_index, tasks::TaskBuilder::be_met_from(active_id));
+        world.wusel_assign_to_task(
+            world.wusel_handle_at(passive_index),
+            tasks::TaskBuilder::be_met_from(active_id),
+        );
         return MEET_RESULT_KNOCKED;
     }
 
@@ -327,10 +467,12 @@ const TASK_PROCEED: bool = true;
 ///
 /// If the object is held by the wusel themselves, use it directly.
 /// If the object is placed in the world, go to the object.
-/// If the object is held by an accessable inventory, find the inventory and get the object (hold it).
-/// If the object is held by another wusel, it cannot be done.
+/// If the object is stored in an accessable storage, go to the storage and get the object (hold it).
+/// If the action's preconditions do not hold (eg. the object is held by another wusel),
+/// the wusel gives up the task.
 ///
-/// Using the object may change the needs and abilities of the wusel (given by an preset).
+/// Using the object may change the needs and abilities of the wusel
+/// (as advertised by the object and given by the action's effects).
 /// Using the object may also consume the object.
 ///
 /// Returns if an interaction happend (true) or not (false).
@@ -344,153 +486,491 @@ fn let_wusel_use_object(
     world: &mut world::World,
     wusel_index: usize,
     object_index: usize,
-    action_index: usize,
+    action_id: tasks::ActionId,
+    action: &world::actions::Action,
+    is_last_step: bool,
 ) -> bool {
     // Invalid wusel index.
     if !world.check_valid_wusel_index(wusel_index) {
         return false;
     }
 
-    let wusel_id = world.wusels[wusel_index].get_id();
+    let wusel = world.wusel_handle_at(wusel_index);
+    let wusel_id = wusel.get_id();
 
-    // Invalid object index.
     if object_index >= world.objects.len() {
         log::warn!("No such object.");
         return false;
     }
 
-    let opt_object_id = world
-        .objects
-        .get(object_index)
-        .map(|object| object.get_object_id());
+    let object = world.object_handle_at(object_index);
+    let object_id = object.get_id();
 
-    if opt_object_id.is_none() {
-        log::warn!("No such object.");
-        return false;
-    }
-
-    let object_id = opt_object_id.unwrap();
-    let _object_type = world.objects_index_with_type[object_index];
+    let object_type = world.objects[object_index].get_object_type();
 
     // Check where the object is.
     // If AtPosition(position) => go to position (position).
     // If StoredIn(storage) => get from storage.
     // If HeldBy(holder_id) => holder_id ==~ wusel_id => ok, else abort.
-    let object_position = world.object_get_position(object_id);
-
-    // If not close to object, go to it.
-    let close_enough = if let Some(object_position) = object_position {
-        log::debug!("Go to object's position.");
-        let_wusel_walk_to_position_if_not_close(
-            world,
-            wusel_index,
-            object_position, // current object position.
-            1.2,             // max distance.
-        )
-    } else {
-        false
+    let object_position = match world.object_get_position(object) {
+        Some(object_position) => object_position,
+        None => {
+            log::warn!("Object[{}] is nowhere to be found.", object_id);
+            return TASK_PROCEED; // cannot be reached, give up.
+        }
     };
 
-    let object_position = object_position.unwrap();
-    let object_position_index = world.position_to_index(object_position);
+    // If not close to object, go to it.
+    log::debug!("Go to object's position.");
+    let close_enough = let_wusel_walk_to_position_if_not_close(
+        world,
+        wusel_index,
+        object_position, // current object position.
+        1.5,             // max distance, also diagonal neighbours.
+    );
 
     if !close_enough {
         return false;
     }
 
-    let object_whereabouts = &world
-        .objects_index_with_whereabouts
-        .get(object_index)
-        .unwrap_or(&(world::InWorld::Nowhere));
+    // Check the action's preconditions and the object's requirements, otherwise give up.
+    let advertisement = world.advertisements.find(object_type, action_id).cloned();
 
-    // Invalid action index.
-    if action_index >= world.actions.len() {
-        log::warn!("No such action.");
-        return false;
+    let failed_precondition = action
+        .get_preconditions()
+        .iter()
+        .find(|&&precondition| !precondition_holds(world, wusel_index, object_index, precondition));
+
+    let lacks_requirements = advertisement
+        .as_ref()
+        .is_some_and(|advertisement| !advertisement.may_be_used_by(&world.wusels[wusel_index]));
+
+    if failed_precondition.is_some() || lacks_requirements {
+        log::info!(
+            "Wusel {} cannot {} {:?}: {:?} or {:?}.",
+            wusel_id,
+            action.get_name(),
+            object_type,
+            failed_precondition,
+            advertisement.map(|advertisement| advertisement.get_requirements().to_vec())
+        );
+        world.wusels[wusel_index].pop_ongoing_task();
+        return TASK_HOLD; // given up.
+    }
+
+    // Fetch the object out of its storage, if the hands are free, otherwise use it within.
+    if world.object_get_storage(object).is_some()
+        && world.object_get_holder(object).is_none()
+        && world.wusel_take_object(wusel, object)
+    {
+        log::debug!("Fetched object {} from its storage.", object_id);
     }
 
     log::debug!(
-        "Used object ({:?} on {:?}).",
-        world.actions[action_index],
+        "Used object ({} on {:?}).",
+        action.get_name(),
         world.objects[object_index]
     );
 
-    // Get the effect of interacting with the object.
-    let effect = world
-        .actions_effects
-        .iter()
-        .find(|(obj_id, act_id, _effect_str, _effect_vec)| {
-            matches!(world.get_object_type_by_id(*obj_id), Some(_object_type))
-                && *act_id == action_index
-        });
-
-    if let Some(effect) = effect {
-        log::debug!("Using the object has the following effect: {:?}", effect);
-        let (_, _, _, effect_vec) = effect;
-        for e in effect_vec {
-            log::debug!("- Apply effect: {:?}", e);
-            world.wusels[wusel_index].set_need_relative(e.0, e.1);
+    // Apply what the object advertises.
+    if let Some(advertisement) = advertisement {
+        log::debug!(
+            "Using the object has the following effect: {:?}",
+            advertisement
+        );
+        for &(need, change) in advertisement.get_effects() {
+            log::debug!("- Apply effect: {:?} {}", need, change);
+            world.wusels[wusel_index].set_need_relative(need, change);
         }
     }
 
     // Do the actual action.
-    return match world.actions[action_index].as_ref() {
-        "View" => {
-            log::info!("Just view.");
-            // TODO can u view sth, if it's held by another wusel?
-            TASK_PROCEED
-        }
-        "Take" => {
-            // if close, or already holding. => update whereabouts and TASK_PROCEED
-            if let world::InWorld::OnPositionIndex(_) | world::InWorld::InStorageId(_) =
-                object_whereabouts
-            {
-                log::info!("Get it, if possible.");
-                world.object_set_whereabouts(object_index, world::InWorld::HeldByWuselId(wusel_id));
-                return TASK_PROCEED;
-            }
-            log::warn!("Item is already hold, just look and stop.");
-            TASK_PROCEED // if already held, cannot be held, but just stop to do so.
-        }
-        "Drop" => {
-            if let world::InWorld::HeldByWuselId(holder_id) = object_whereabouts {
-                if *holder_id == wusel_id {
-                    log::info!("Drop it, if held by wusel themself.");
-                    world.object_set_whereabouts(
-                        object_index,
-                        world::InWorld::OnPositionIndex(object_position_index),
-                    ); // == wusel_position, as position of all containers
-                    log::debug!("Object placed, somewhere.");
-                    return TASK_PROCEED;
-                }
+    // Effects may destroy the object, so the completion effects are applied first.
+    let mut effects: Vec<world::actions::Effect> = vec![];
+    if is_last_step {
+        effects.extend_from_slice(action.get_completion_effects());
+    }
+    effects.extend_from_slice(action.get_step_effects());
+
+    for effect in effects {
+        if !apply_action_effect(world, wusel_index, object_id, effect) {
+            break; // the object is gone.
+        }
+    }
+
+    TASK_PROCEED
+}
+
+/// Let a wusel put an object into a storage.
+///
+/// If the wusel does not hold the object yet, they go to the object and take it first.
+/// Then they go to the storage and put the object into it.
+/// If the object cannot be taken or stored (eg. the storage is full), the wusel gives up the task.
+///
+/// Returns if the object was stored (true) or not yet (false).
+fn let_wusel_store_object(
+    world: &mut world::World,
+    wusel_index: usize,
+    object_id: objects::ObjectId,
+    storage_id: objects::ObjectId,
+) -> bool {
+    // Invalid wusel index.
+    if !world.check_valid_wusel_index(wusel_index) {
+        return false;
+    }
+
+    let wusel = world.wusel_handle_at(wusel_index);
+    let wusel_id = wusel.get_id();
+
+    let (object, storage) = match (
+        world.object_handle(object_id),
+        world.object_handle(storage_id),
+    ) {
+        (Some(object), Some(storage)) => (object, storage),
+        _ => {
+            log::warn!("Object[{}] or storage[{}] is gone.", object_id, storage_id);
+            world.wusels[wusel_index].pop_ongoing_task();
+            return TASK_HOLD; // given up.
+        }
+    };
+
+    let held = world.wusel_list_inventory(wusel).contains(&object);
+
+    // Go to the object or the storage, whatever is next.
+    let goal = match held {
+        false => world.object_get_position(object),
+        true => world.object_get_position(storage),
+    };
+
+    let goal = match goal {
+        Some(goal) => goal,
+        None => {
+            log::warn!(
+                "Object[{}] or storage[{}] is nowhere to be found.",
+                object_id,
+                storage_id
+            );
+            world.wusels[wusel_index].pop_ongoing_task();
+            return TASK_HOLD; // given up.
+        }
+    };
+
+    let close_enough = let_wusel_walk_to_position_if_not_close(world, wusel_index, goal, 1.5);
+
+    if !close_enough {
+        return TASK_HOLD;
+    }
+
+    let done = match held {
+        false => world.wusel_take_object(wusel, object),
+        true => world.object_store(object, storage),
+    };
+
+    if !done {
+        log::info!(
+            "Wusel {} cannot store object {} in {}.",
+            wusel_id,
+            object_id,
+            storage_id
+        );
+        world.wusels[wusel_index].pop_ongoing_task();
+        return TASK_HOLD; // given up.
+    }
+
+    // Storing something neatly needs finesse.
+    if held {
+        world.wusels[wusel_index].improve(wusels::abilities::Ability::FINESSE);
+    }
+
+    held // stored, if it was held before.
+}
+
+/// Let a wusel craft the product of a blueprint.
+///
+/// The wusel goes to the closest free workstation (if the blueprint needs one) and reserves it.
+/// If all workstations are taken, the wusel waits.
+/// On the first step, the components are gathered from the wusel's bags and the storages within reach,
+/// and consumed.
+/// On the last step, the product is created, the better the wusel's ability, the better the product.
+/// Skilled wusels may do more steps at once, wusels below the difficulty may fail (see `Blueprint`).
+/// The product is held by the wusel, or put into the workstation or next to the wusel.
+/// If a component or the workstation is missing at all, the wusel gives up the task.
+///
+/// Returns if the wusel worked on the product (true) or not yet (false).
+fn let_wusel_craft(
+    world: &mut world::World,
+    wusel_index: usize,
+    blueprint_id: world::items::BlueprintId,
+    is_first_step: bool,
+    is_last_step: bool,
+) -> bool {
+    // Invalid wusel index.
+    if !world.check_valid_wusel_index(wusel_index) {
+        return false;
+    }
+
+    let wusel = world.wusel_handle_at(wusel_index);
+    let wusel_id = wusel.get_id();
+
+    let blueprint = match world.blueprint_get(blueprint_id) {
+        Some(blueprint) => blueprint.clone(),
+        None => {
+            log::warn!("No such blueprint: {}.", blueprint_id);
+            world.wusels[wusel_index].pop_ongoing_task();
+            return TASK_HOLD; // given up.
+        }
+    };
+
+    // Go to a free workstation and reserve it.
+    let workstation = match blueprint.get_workstation() {
+        None => None,
+        Some(workstation_type) => {
+            let wusel_position = match world.wusel_get_position(wusel) {
+                Some(wusel_position) => wusel_position,
+                None => return TASK_HOLD,
+            };
+
+            let workstation =
+                match world.blueprint_find_workstation(blueprint_id, wusel, wusel_position) {
+                    Some(workstation) => workstation,
+                    None if world
+                        .object_get_all()
+                        .into_iter()
+                        .any(|object| world.object_get_type(object) == Some(workstation_type)) =>
+                    {
+                        log::debug!(
+                            "Wusel {} waits for a free {:?}.",
+                            wusel_id,
+                            workstation_type
+                        );
+                        return TASK_HOLD; // all taken, wait.
+                    }
+                    None => {
+                        log::info!("Wusel {} finds no {:?}.", wusel_id, workstation_type);
+                        world.wusels[wusel_index].pop_ongoing_task();
+                        return TASK_HOLD; // given up.
+                    }
+                };
+
+            let workstation_position = world.object_get_position(workstation).unwrap(); // found by position.
+            let close_enough = let_wusel_walk_to_position_if_not_close(
+                world,
+                wusel_index,
+                workstation_position,
+                1.5,
+            );
+
+            if !close_enough || !world.workstation_reserve(workstation, wusel) {
+                return TASK_HOLD;
             }
-            TASK_PROCEED // if not held, it cannot be dropped, but the wusel will be done, to drop the object.
-        }
-        "Consume" => {
-            let consumable = world.objects[object_index].get_consumable();
-            if consumable != 0 {
-                let left_over = world.objects[object_index].get_consumable_left();
-                // TODO als interact with wusel.
-                log::debug!("Consume a part of the consumable object.");
-
-                if left_over <= 1 {
-                    world.object_destroy(object_index); // delete from world.
-                    log::debug!("Consumable Object fully consumed.");
-                    return TASK_PROCEED;
+
+            Some(workstation)
+        }
+    };
+
+    // Gather and consume the components (from own bags or storages within reach).
+    if is_first_step {
+        let crafting_position = match workstation
+            .and_then(|workstation| world.object_get_position(workstation))
+            .or_else(|| world.wusel_get_position(wusel))
+        {
+            Some(crafting_position) => crafting_position,
+            None => return TASK_HOLD,
+        };
+        let components =
+            match world.blueprint_find_components(blueprint_id, wusel, crafting_position) {
+                Some(components) => components,
+                None => {
+                    log::info!(
+                        "Wusel {} misses components for {}.",
+                        wusel_id,
+                        blueprint.get_name()
+                    );
+                    if let Some(workstation) = workstation {
+                        world.workstation_release(workstation, wusel);
+                    }
+                    world.wusels[wusel_index].pop_ongoing_task();
+                    return TASK_HOLD; // given up.
                 }
-                world.objects[object_index].set_consumable_left(left_over - 1);
+            };
 
-                // return TASK_PROCEED;
-                return TASK_HOLD; // ddbug.
+        for component in components {
+            if let Some(component_index) = world.object_index(component) {
+                world.object_destroy(component_index);
             }
-            log::warn!("Tried to consume something  unconsumable");
-            TASK_HOLD // if not held, it cannot be dropped, but the wusel will be done, to drop the object.
         }
-        _ => {
-            log::info!("Undefined action?");
-            TASK_HOLD
+    }
+
+    let ability = blueprint.get_ability();
+    let ability_level = world.wusels[wusel_index].get_ability(ability);
+
+    if !is_last_step {
+        // Skilled wusels are faster, but never skip the last step.
+        let rest_time = world.wusels[wusel_index]
+            .peek_ongoing_task()
+            .map_or(0, |task| task.get_rest_time());
+        if rest_time > 2
+            && world.get_rng().gen_range(0..100) < blueprint.get_haste_percent_by(ability_level)
+        {
+            world.wusels[wusel_index].increase_ongoing_task_steps();
         }
+        return TASK_PROCEED;
+    }
+
+    // Unskilled wusels may fail, but they still learn from it.
+    if world.get_rng().gen_range(0..100) >= blueprint.get_success_percent_by(ability_level) {
+        log::info!(
+            "Wusel {} failed to craft {}.",
+            wusel_id,
+            blueprint.get_name()
+        );
+        world.wusels[wusel_index].improve(ability);
+        if let Some(workstation) = workstation {
+            world.workstation_release(workstation, wusel);
+        }
+        return TASK_PROCEED;
+    }
+
+    // Create the product, influenced by the wusel's ability.
+    let bites = blueprint.get_product_bites_by(ability_level);
+    let product = blueprint.get_product();
+
+    let product_object = world.object_new(
+        product,
+        product.get_subtype().to_string(),
+        true,
+        true,
+        bites,
+        0,
+    );
+
+    let placed = world.wusel_take_object(wusel, product_object)
+        || workstation.is_some_and(|workstation| world.object_store(product_object, workstation))
+        || world
+            .wusel_get_position(wusel)
+            .and_then(|position| world.object_find_nearest_free(product_object, position))
+            .is_some_and(|position| world.object_set_position(product_object, position));
+
+    if !placed {
+        log::warn!("Product {} could not be placed.", product_object);
+    }
+
+    log::info!(
+        "Wusel {} crafted {} ({} bites).",
+        wusel_id,
+        blueprint.get_name(),
+        bites
+    );
+
+    world.wusels[wusel_index].improve(ability);
+
+    if let Some(workstation) = workstation {
+        world.workstation_release(workstation, wusel);
+    }
+
+    TASK_PROCEED
+}
+
+/// Check, if a precondition of an action holds for the wusel and the object.
+fn precondition_holds(
+    world: &world::World,
+    wusel_index: usize,
+    object_index: usize,
+    precondition: world::actions::Precondition,
+) -> bool {
+    let wusel = &world.wusels[wusel_index];
+    let object_whereabouts = world
+        .objects
+        .get(object_index)
+        .map(objects::Object::get_whereabouts)
+        .unwrap_or(objects::Whereabouts::Nowhere);
+    let object_holder = world.objects_index_get_holder(object_index);
+
+    match precondition {
+        world::actions::Precondition::NotHeld => {
+            object_holder.is_none() && object_whereabouts != objects::Whereabouts::Nowhere
+        }
+        world::actions::Precondition::NotHeldByOthers => {
+            object_holder.is_none_or(|holder_id| holder_id == wusel.get_id())
+        }
+        world::actions::Precondition::HeldByActor => {
+            object_whereabouts == objects::Whereabouts::HeldByWuselId(wusel.get_id())
+        }
+        world::actions::Precondition::Consumable => {
+            world.objects[object_index].get_consumable_left() > 0
+        }
+        world::actions::Precondition::Ability(ability, level) => {
+            wusel.get_ability(ability) >= level
+        }
+    }
+}
+
+/// Apply an effect of an action on the wusel and the object.
+///
+/// Returns false, if the object does not exist anymore (after the effect).
+fn apply_action_effect(
+    world: &mut world::World,
+    wusel_index: usize,
+    object_id: objects::ObjectId,
+    effect: world::actions::Effect,
+) -> bool {
+    let object_index = match world.object_id_to_index(object_id) {
+        Some(object_index) => object_index,
+        None => return false,
     };
+
+    let wusel = world.wusel_handle_at(wusel_index);
+    let object = world.object_handle_at(object_index);
+
+    match effect {
+        world::actions::Effect::Need(need, change) => {
+            world.wusels[wusel_index].set_need_relative(need, change);
+        }
+        world::actions::Effect::Improve(ability) => {
+            world.wusels[wusel_index].improve(ability);
+        }
+        world::actions::Effect::Take => {
+            log::info!("Get it.");
+            if !world.wusel_take_object(wusel, object) {
+                log::info!("Wusel {} cannot take object {}.", wusel, object);
+            }
+        }
+        world::actions::Effect::Drop => {
+            log::info!("Drop it, where the wusel is.");
+            if !world.wusel_drop_object(wusel, object) {
+                log::info!("Wusel {} cannot drop object {}.", wusel, object);
+            }
+        }
+        world::actions::Effect::ConsumeBite => {
+            let left_over = world.objects[object_index].get_consumable_left();
+            log::debug!("Consume a part of the consumable object.");
+
+            if let Some(consumable) = world.objects[object_index]
+                .get_consumable_component()
+                .cloned()
+            {
+                for (&need, &change) in consumable.get_need_changes().iter() {
+                    world.wusels[wusel_index].set_need_relative(need, change);
+                }
+                if consumable.is_spoiled() {
+                    log::info!("Wusel {} ate something spoiled.", wusel);
+                    world.wusels[wusel_index].set_need_relative(
+                        wusels::needs::Need::HEALTH,
+                        world::World::SPOILED_HEALTH_PER_BITE,
+                    );
+                }
+            }
+
+            if left_over <= 1 {
+                world.object_destroy(object_index); // delete from world.
+                log::debug!("Consumable Object fully consumed.");
+                return false;
+            }
+            world.objects[object_index].set_consumable_left(left_over - 1);
+        }
+    }
+
+    true
 }
 
 /// Let the wusel walk to a position, if they are not close.
@@ -507,7 +987,7 @@ fn let_wusel_walk_to_position_if_not_close(
         .map(|&position_index| world.position_from_index(position_index))
         .map(|opt_opt_position| opt_opt_position.unwrap());
 
-    if wusel_position == None {
+    if wusel_position.is_none() {
         return false; // wusel itself has no position.
     }
 
@@ -521,6 +1001,42 @@ fn let_wusel_walk_to_position_if_not_close(
     }
 }
 
+/// Let the wusel walk to the door and open, close, lock or unlock it.
+///
+/// #Return, if the wusel is done with the door (true), otherwise false, if they are still walking.
+fn let_wusel_use_door(
+    world: &mut world::World,
+    wusel_index: usize,
+    door_id: world::items::ConstructionId,
+    door_action: tasks::DoorAction,
+) -> bool {
+    let door_position = match world.door_get_position(door_id) {
+        Some(door_position) => door_position,
+        None => {
+            log::warn!("Door[{}] could not be found.", door_id);
+            return true; // proceed to next action.
+        }
+    };
+
+    // Go to the door, to be next to it.
+    if !let_wusel_walk_to_position_if_not_close(world, wusel_index, door_position, 1.5) {
+        return false; // still walking.
+    }
+
+    let wusel = world.wusel_handle_at(wusel_index);
+
+    if !world.door_use(door_id, wusel, door_action) {
+        log::info!(
+            "Wusel {} could not {:?} Door[{}].",
+            wusel,
+            door_action,
+            door_id
+        );
+    }
+
+    true // done, either way.
+}
+
 /// Let the wusel walk to a position.
 /// If the path is already calculated, let it walk the pre-calculated path.
 /// If not, calculate a new path.
@@ -539,58 +1055,180 @@ fn let_wusel_walk_to_position(
         .map(|&position_index| world.position_from_index(position_index))
         .map(|opt_opt_position| opt_opt_position.unwrap());
 
-    if opt_wusel_position == None {
+    if opt_wusel_position.is_none() {
         return true; // couldn't move => stopped walking.
     }
 
     let wusel_position = opt_wusel_position.unwrap();
+    let wusel = world.wusel_handle_at(wusel_index);
 
     // Check if the goal is already reached.
     if wusel_position.x == goal.x && wusel_position.y == goal.y && wusel_position.z == goal.z {
         log::info!("Reached Goal ({},{},{}).", goal.x, goal.y, goal.z);
+        world.wusels[wusel_index].set_ongoing_task_route(None);
         return true; // stopped walking.
     }
 
     log::info!("Move to Goal {:?}.", goal);
 
+    // Get the pre-calculated path, if it still leads to the goal.
+    let mut opt_route: Option<tasks::Route> = world.wusels[wusel_index]
+        .peek_ongoing_task()
+        .and_then(|task| task.get_route())
+        .filter(|route| route.get_goal() == goal)
+        .cloned();
+
     // Check, if the pre-calculated path is blocked.
-    if false {
-        // Abort the pre-calculated, but blocked path.
-        // TODO implement me.
+    // This is only necessary, if the world has changed since it was checked.
+    let revision = world.get_topology_revision();
+
+    if let Some(route) = opt_route.as_mut() {
+        let is_next_neighbour = route
+            .peek_next_step()
+            .is_some_and(|next_step| wusel_position.distance_to(&next_step) < 1.5);
+
+        let is_walkable = route.get_revision() == revision
+            || route.is_walkable(&|step| world.wusel_may_walk_through(wusel, *step));
+
+        if !is_next_neighbour || !is_walkable {
+            // Abort the pre-calculated, but blocked path.
+            log::info!("Path to {:?} is blocked.", goal);
+            opt_route = None;
+        } else {
+            route.set_revision(revision);
+        }
     }
 
     // Check, if the path is (still) pre-calculated.
-    if true {
-        // Walk the path.
-        // XXX easy placeholder walking, ignoring all obstacles.
+    let mut route = match opt_route {
+        Some(route) => route,
+        None => {
+            // Calculate the path.
+            log::info!("Calculate the path to {:?}", goal);
+            match world.wusel_plan_path(wusel, wusel_position, goal) {
+                Some(steps) => tasks::Route::new(goal, steps, revision),
+                None => {
+                    log::info!("Wusel cannot reach {:?}, stop walking.", goal);
+                    world.wusels[wusel_index].set_ongoing_task_route(None);
+                    return true;
+                }
+            }
+        }
+    };
 
-        // Get the current positions neighbours.
-        let neighbours = world.position_get_all_neighbours(wusel_position);
+    // Walk the path.
+    match route.peek_next_step() {
+        Some(next_step) if next_step == goal && !world.position_is_passable(goal) => {
+            // The goal itself is blocking, they are as close as possible.
+            log::info!("Goal {:?} is blocked, stop next to it.", goal);
+            world.wusels[wusel_index].set_ongoing_task_route(None);
+            true // stopped walking.
+        }
+        Some(next_step) if !world.position_is_passable(next_step) => {
+            // A closed door on the way: Open it (this takes the step).
+            let opened = world
+                .door_at(next_step)
+                .is_some_and(|door_id| world.door_use(door_id, wusel, tasks::DoorAction::Open));
+
+            if opened {
+                log::info!("Wusel {} opened the door at {:?}.", wusel, next_step);
+                world.wusels[wusel_index].set_ongoing_task_route(Some(route));
+            } else {
+                world.wusels[wusel_index].set_ongoing_task_route(None); // re-plan.
+            }
+            false // still walking.
+        }
+        Some(next_step) if !world.wusel_set_position_by_index(wusel_index, next_step) => {
+            // Another wusel is standing in the way.
+            let_wusel_pass_other_wusel(world, wusel_index, wusel_position, route)
+        }
+        Some(_) => {
+            route.pop_next_step(); // walked.
+            world.wusels[wusel_index].set_ongoing_task_route(Some(route));
+            false // still walking.
+        }
+        None => {
+            world.wusels[wusel_index].set_ongoing_task_route(None);
+            true // nothing left to walk.
+        }
+    }
+}
 
-        if neighbours.is_empty() {
-            log::info!("Wusel cannot move, it's enclosed, wait forever");
-            return true;
+/// Let the wusel handle another wusel standing on their next step.
+///
+/// If they walk against each other, the one with the higher ID yields and steps aside.
+/// Otherwise wait for a moment, and if the other is still there, walk around them.
+/// If the other one stands on the goal or there is no way around, just wait,
+/// but not longer than the world's patience allows.
+///
+/// #Return, if wusel has stopped walking (true), otherwise false, if they are still walking.
+fn let_wusel_pass_other_wusel(
+    world: &mut world::World,
+    wusel_index: usize,
+    wusel_position: areas::Position,
+    mut route: tasks::Route,
+) -> bool {
+    let wusel = world.wusel_handle_at(wusel_index);
+    let goal = route.get_goal();
+    let next_step = route.peek_next_step().unwrap_or(goal);
+    let policy = world.get_occupancy_policy();
+
+    // Other wusels, who also want to go to this wusel's position.
+    let head_on = world
+        .position_get_wusels(next_step)
+        .iter()
+        .filter_map(|&other| world.wusel_index(other))
+        .any(|other_index| {
+            world.wusels[other_index]
+                .peek_ongoing_task()
+                .and_then(|task| task.get_route())
+                .and_then(|other_route| other_route.peek_next_step())
+                == Some(wusel_position)
+                && world.wusels[other_index].get_id() < wusel.get_id()
+        });
+
+    if head_on {
+        // Yield: Step aside to a free position, which is not on the way of the other.
+        log::info!("Wusel {} yields at {:?}.", wusel, wusel_position);
+        let aside = world
+            .position_get_all_neighbours(wusel_position)
+            .into_iter()
+            .filter(|&p| p != next_step && world.wusel_can_pass(wusel, p))
+            .min_by_key(|p| p.step_cost_estimate(&goal));
+
+        if let Some(aside) = aside {
+            world.wusel_set_position_by_index(wusel_index, aside);
+            world.wusels[wusel_index].set_ongoing_task_route(None); // re-plan from aside.
+            return false; // still walking.
         }
+    }
 
-        let mut closest: areas::Position = neighbours[0];
-        let mut closest_distance: f32 = f32::MAX;
+    let waited = route.wait();
 
-        // Find closest neighbour to goal.
-        for p in neighbours.iter() {
-            let distance = goal.distance_to(p);
+    if waited > policy.patience_to_give_up {
+        log::info!("Wusel {} waited too long to pass, stop walking.", wusel);
+        world.wusels[wusel_index].set_ongoing_task_route(None);
+        return true; // stopped walking.
+    }
+
+    if next_step != goal && waited > policy.patience_to_pass {
+        // Side-step: Walk around the other wusels.
+        let detour = world.wusel_find_path(wusel, wusel_position, goal);
+
+        if let Some(detour) = detour {
+            let mut detour = tasks::Route::new(goal, detour, world.get_topology_revision());
 
-            if distance < closest_distance {
-                closest = *p;
-                closest_distance = distance;
+            if let Some(detour_step) = detour.peek_next_step() {
+                if world.wusel_set_position_by_index(wusel_index, detour_step) {
+                    detour.pop_next_step();
+                    world.wusels[wusel_index].set_ongoing_task_route(Some(detour));
+                    return false; // still walking.
+                }
             }
         }
-
-        // move to closest position.
-        world.wusel_set_position_by_index(wusel_index, closest);
-        false // still walking.
-    } else {
-        // Calculate the path and go it next time.
-        log::info!("Calculate the path to {:?}", goal);
-        false // still walking.
     }
+
+    // Wait for now.
+    world.wusels[wusel_index].set_ongoing_task_route(Some(route));
+    false // still walking.
 }
@@ -9,24 +9,19 @@ fn test_walking() {
 
     let mut test_world: super::World = super::World::new(width, depth); // small world.
 
-    // indices
-    let wusel_on_x: usize = 0;
-    let wusel_on_y: usize = 1;
-    let wusel_wild: usize = 2;
-
-    test_world.wusel_new(
+    let wusel_on_x = test_world.wusel_new(
         "on_x".to_string(),
         super::wusels::WuselGender::Female,
         super::areas::Position { x: 0, y: 0, z: 0 },
     );
 
-    test_world.wusel_new(
+    let wusel_on_y = test_world.wusel_new(
         "on_y".to_string(),
         super::wusels::WuselGender::Male,
         super::areas::Position { x: 2, y: 2, z: 0 },
     );
 
-    test_world.wusel_new(
+    let wusel_wild = test_world.wusel_new(
         "random".to_string(),
         super::wusels::WuselGender::Male,
         super::areas::Position { x: 3, y: 4, z: 0 },
@@ -69,7 +64,7 @@ fn test_walking() {
 
         // assign random walking (y axis).
         if test_world.wusel_get_tasklist_len(wusel_on_y) == Some(0) {
-            goal_y = (rand::random::<u32>() + 1 + goal_y) % depth;
+            goal_y = 1 + (rand::random::<u32>() + goal_y) % (depth - 1); // not on x's way.
             wusely_tofront = goal_y < last_y_position.y;
             println!("{:03}$ Wusel y goal to ( 0, {:2}, 0)", i, goal_y);
             test_world.wusel_assign_to_task(
@@ -85,6 +80,8 @@ fn test_walking() {
         // assign random walking (x-y plane).
         if test_world.wusel_get_tasklist_len(wusel_wild) == Some(0) {
             goal_rand = test_world.position_random();
+            goal_rand.x = goal_rand.x.max(1); // not on y's way.
+            goal_rand.y = goal_rand.y.max(1); // not on x's way.
             println!(
                 "{:03}$ Wusel ? goal to ({:2},{:2}, 0)",
                 i, goal_rand.x, goal_rand.y
@@ -167,6 +164,496 @@ fn test_walking() {
     }
 }
 
+/// Build the example house: A room with walls and one door at (20, 10).
+fn build_house(test_world: &mut super::World, door: super::items::ConstructionType) {
+    test_world.construction_new(
+        super::items::ConstructionType::Wall(super::items::WALL_UD, 10),
+        super::areas::Position { x: 10, y: 10, z: 0 },
+    );
+    test_world.construction_new(
+        super::items::ConstructionType::Wall(super::items::WALL_LR, 20),
+        super::areas::Position { x: 11, y: 19, z: 0 },
+    );
+    test_world.construction_new(
+        super::items::ConstructionType::Wall(super::items::WALL_UD, 10),
+        super::areas::Position { x: 30, y: 10, z: 0 },
+    );
+    test_world.construction_new(door, super::areas::Position { x: 20, y: 10, z: 0 });
+    test_world.construction_new(
+        super::items::ConstructionType::Wall(super::items::WALL_LR, 20),
+        super::areas::Position { x: 10, y: 10, z: 0 },
+    );
+}
+
+#[test]
+fn test_walking_through_door() {
+    let mut test_world: super::World = super::World::new(40, 25);
+    build_house(
+        &mut test_world,
+        super::items::ConstructionType::Door(super::items::DOOR_OPEN),
+    );
+
+    // outside, left of the house.
+    let visitor = test_world.wusel_new(
+        "Visitor".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 5, y: 15, z: 0 },
+    );
+
+    let goal = super::areas::Position { x: 25, y: 15, z: 0 };
+    test_world.wusel_assign_to_task(visitor, super::tasks::TaskBuilder::move_to(goal));
+
+    let mut passed_door = false;
+
+    for _ in 0..100 {
+        test_world.tick();
+
+        let position = test_world.wusel_get_position(visitor).unwrap();
+        assert!(
+            test_world.position_is_passable(position),
+            "Walked into a wall."
+        );
+
+        passed_door |= position == super::areas::Position { x: 20, y: 10, z: 0 };
+
+        if test_world.wusel_get_tasklist_len(visitor) == Some(0) {
+            break;
+        }
+    }
+
+    assert_eq!(test_world.wusel_get_position(visitor), Some(goal));
+    assert!(passed_door);
+}
+
+#[test]
+fn test_walking_into_locked_house() {
+    let mut test_world: super::World = super::World::new(40, 25);
+    build_house(
+        &mut test_world,
+        super::items::ConstructionType::Door(super::items::DOOR_CLOSED),
+    );
+
+    let start = super::areas::Position { x: 5, y: 15, z: 0 };
+    let visitor = test_world.wusel_new("Visitor".to_string(), wusels::WuselGender::Male, start);
+    let owner = test_world.wusel_new(
+        "Owner".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 35, y: 5, z: 0 },
+    );
+
+    let door = test_world.door_get_all()[0];
+    test_world.door_set_owner(door, Some(owner));
+    assert!(test_world.door_use(door, owner, super::tasks::DoorAction::Lock));
+    assert!(!test_world.door_may_be_opened_by(door, visitor));
+    assert!(test_world.door_may_be_opened_by(door, owner));
+
+    let goal = super::areas::Position { x: 25, y: 15, z: 0 };
+    assert_eq!(test_world.position_find_path(start, goal), None);
+    assert_eq!(test_world.wusel_plan_path(visitor, start, goal), None);
+
+    // No way in: The wusel gives up instead of walking through the walls.
+    test_world.wusel_assign_to_task(visitor, super::tasks::TaskBuilder::move_to(goal));
+    test_world.tick(); // give up.
+    test_world.tick(); // clean up the task.
+
+    assert_eq!(test_world.wusel_get_tasklist_len(visitor), Some(0));
+    assert_eq!(test_world.wusel_get_position(visitor), Some(start));
+}
+
+#[test]
+fn test_walking_opens_closed_door() {
+    let mut test_world: super::World = super::World::new(40, 25);
+    build_house(
+        &mut test_world,
+        super::items::ConstructionType::Door(super::items::DOOR_CLOSED),
+    );
+
+    let door_position = super::areas::Position { x: 20, y: 10, z: 0 };
+    let door = test_world.door_at(door_position).unwrap();
+    assert_eq!(test_world.door_is_open(door), Some(false));
+    assert!(!test_world.position_is_passable(door_position));
+
+    let start = super::areas::Position { x: 20, y: 5, z: 0 };
+    let goal = super::areas::Position { x: 20, y: 15, z: 0 };
+    let resident = test_world.wusel_new("Resident".to_string(), wusels::WuselGender::Female, start);
+
+    // The closed door is on the way, it is opened when it's reached.
+    test_world.wusel_assign_to_task(resident, super::tasks::TaskBuilder::move_to(goal));
+    for _ in 0..20 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.wusel_get_position(resident), Some(goal));
+    assert_eq!(test_world.door_is_open(door), Some(true));
+
+    // Close and lock the door again as a task.
+    test_world.door_set_owner(door, Some(resident));
+    test_world.wusel_assign_to_task(
+        resident,
+        super::tasks::TaskBuilder::use_door(door, super::tasks::DoorAction::Lock),
+    );
+    for _ in 0..10 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.door_is_open(door), Some(false));
+    assert_eq!(test_world.door_is_locked(door), Some(true));
+
+    // Others are not allowed to unlock it.
+    let neighbour = test_world.wusel_new(
+        "Neighbour".to_string(),
+        wusels::WuselGender::Male,
+        super::areas::Position { x: 5, y: 5, z: 0 },
+    );
+    assert!(!test_world.door_use(door, neighbour, super::tasks::DoorAction::Unlock));
+    assert!(!test_world.door_use(door, neighbour, super::tasks::DoorAction::Open));
+    assert_eq!(test_world.door_get_owner(door), Some(resident));
+}
+
+#[test]
+fn test_hungry_wusel_feeds_themself() {
+    let mut test_world: super::World = super::World::new(30, 10);
+    test_world.set_seed(12);
+    test_world.set_autonomous(true);
+
+    let hungry = test_world.wusel_new(
+        "Hungry".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 1, y: 1, z: 0 },
+    );
+    let sated = test_world.wusel_new(
+        "Sated".to_string(),
+        wusels::WuselGender::Male,
+        super::areas::Position { x: 28, y: 8, z: 0 },
+    );
+
+    let food = wusels::needs::Need::FOOD;
+    let starving = food.get_full() / 5;
+    test_world.wusel_set_need(hungry, &food, starving);
+
+    let bread = test_world.food_new("Bread", 3);
+    let bread_position = super::areas::Position { x: 12, y: 5, z: 0 };
+    test_world.object_set_position(bread, bread_position);
+
+    // The hungry wusel walks to the bread and eats it up.
+    for _ in 0..40 {
+        test_world.tick();
+    }
+
+    assert!(test_world.wusel_get_need(hungry, food) > starving);
+    assert_eq!(test_world.object_get_position(bread), None);
+    assert!(
+        test_world
+            .wusel_get_position(hungry)
+            .unwrap()
+            .distance_to(&bread_position)
+            <= 1.5
+    );
+
+    // The sated wusel was never interested in the bread.
+    assert!(test_world.wusel_get_need(sated, food) < food.get_full());
+    assert!(test_world.wusel_get_need(sated, food) > food.get_full() - 100);
+}
+
+#[test]
+fn test_hungry_wusel_fetches_food_from_fridge() {
+    let mut test_world: super::World = super::World::new(20, 10);
+    test_world.set_seed(7);
+    test_world.set_autonomous(true);
+
+    let hungry = test_world.wusel_new(
+        "Hungry".to_string(),
+        wusels::WuselGender::Male,
+        super::areas::Position { x: 1, y: 1, z: 0 },
+    );
+
+    let food = wusels::needs::Need::FOOD;
+    let starving = food.get_full() / 5;
+    test_world.wusel_set_need(hungry, &food, starving);
+
+    let fridge = test_world.object_new(
+        super::objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        4,
+    );
+    test_world.object_set_position(fridge, super::areas::Position { x: 10, y: 5, z: 0 });
+
+    let kimchi = test_world.food_new("Kimchi", 4);
+    assert!(test_world.object_store(kimchi, fridge));
+
+    // The hungry wusel walks to the fridge, gets the food out and eats it.
+    for _ in 0..20 {
+        test_world.tick();
+    }
+
+    assert!(test_world.wusel_get_need(hungry, food) > starving);
+    assert!(test_world.object_list_contents(fridge).is_empty());
+    assert!(test_world.object_get_storage(kimchi).is_none());
+}
+
+#[test]
+fn test_walking_replans_on_world_change() {
+    let mut test_world: super::World = super::World::new(20, 10);
+
+    let walker = test_world.wusel_new(
+        "Walker".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 0, y: 5, z: 0 },
+    );
+
+    let goal = super::areas::Position { x: 19, y: 5, z: 0 };
+    test_world.wusel_assign_to_task(walker, super::tasks::TaskBuilder::move_to(goal));
+
+    test_world.tick();
+    test_world.tick();
+
+    // The route is calculated once and remembered with the current topology.
+    let revision = test_world.get_topology_revision();
+    let route = test_world
+        .wusel_peek_ongoing_task(walker)
+        .and_then(|task| task.get_route())
+        .cloned()
+        .unwrap();
+    assert_eq!(route.get_revision(), revision);
+    assert_eq!(route.get_goal(), goal);
+
+    // Block the straight way.
+    let obstacle_position = super::areas::Position { x: 10, y: 5, z: 0 };
+    let obstacle = test_world.object_new(
+        super::objects::ObjectType::Furniture("Wardrobe"),
+        "Wardrobe".to_string(),
+        false, // not transportable
+        false, // not passable
+        0,
+        0,
+    );
+    test_world.object_set_position(obstacle, obstacle_position);
+    assert!(test_world.get_topology_revision() > revision);
+
+    for _ in 0..40 {
+        test_world.tick();
+        assert_ne!(
+            test_world.wusel_get_position(walker),
+            Some(obstacle_position)
+        );
+    }
+
+    assert_eq!(test_world.wusel_get_position(walker), Some(goal));
+}
+
+#[test]
+fn test_walking_against_each_other() {
+    let mut test_world: super::World = super::World::new(20, 3);
+
+    let left = super::areas::Position { x: 0, y: 1, z: 0 };
+    let right = super::areas::Position { x: 10, y: 1, z: 0 };
+
+    let from_left = test_world.wusel_new("Left".to_string(), wusels::WuselGender::Female, left);
+    let from_right = test_world.wusel_new("Right".to_string(), wusels::WuselGender::Male, right);
+
+    test_world.wusel_assign_to_task(from_left, super::tasks::TaskBuilder::move_to(right));
+    test_world.wusel_assign_to_task(from_right, super::tasks::TaskBuilder::move_to(left));
+
+    for _ in 0..40 {
+        test_world.tick();
+
+        // Never on the same position.
+        assert_ne!(
+            test_world.wusel_get_position(from_left),
+            test_world.wusel_get_position(from_right)
+        );
+    }
+
+    assert_eq!(test_world.wusel_get_position(from_left), Some(right));
+    assert_eq!(test_world.wusel_get_position(from_right), Some(left));
+}
+
+#[test]
+fn test_walking_waits_for_each_blocked_step_anew() {
+    // A corridor, nobody can walk around another.
+    let mut test_world: super::World = super::World::new_with_levels(20, 1, 2);
+
+    let mut policy = test_world.get_occupancy_policy();
+    policy.patience_to_give_up = 4;
+    test_world.set_occupancy_policy(policy);
+
+    let goal = super::areas::Position { x: 19, y: 0, z: 0 };
+    let walker = test_world.wusel_new(
+        "Walker".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 0, y: 0, z: 0 },
+    );
+    test_world.wusel_assign_to_task(walker, super::tasks::TaskBuilder::move_to(goal));
+
+    // Two short blockages, together longer than the walker's patience.
+    for (blocked_x, name) in [(4, "First"), (10, "Second")].iter() {
+        let blocked = super::areas::Position {
+            x: *blocked_x,
+            y: 0,
+            z: 0,
+        };
+        let blocker = test_world.wusel_new(name.to_string(), wusels::WuselGender::Male, blocked);
+        test_world.wusel_assign_to_task(blocker, super::tasks::TaskBuilder::rest(100));
+
+        let before_blocked = super::areas::Position {
+            x: blocked_x - 1,
+            y: 0,
+            z: 0,
+        };
+        for _ in 0..20 {
+            if test_world.wusel_get_position(walker) == Some(before_blocked) {
+                break;
+            }
+            test_world.tick();
+        }
+
+        for _ in 0..3 {
+            test_world.tick();
+            assert_eq!(test_world.wusel_get_position(walker), Some(before_blocked));
+        }
+
+        // The blocker leaves (to the upper level).
+        test_world.wusel_set_position(
+            blocker,
+            super::areas::Position {
+                x: *blocked_x,
+                y: 0,
+                z: 1,
+            },
+        );
+    }
+
+    for _ in 0..20 {
+        test_world.tick();
+    }
+
+    assert_eq!(test_world.wusel_get_position(walker), Some(goal));
+}
+
+#[test]
+fn test_walking_upstairs() {
+    let mut test_world: super::World = super::World::new_with_levels(20, 10, 2);
+    assert_eq!(test_world.get_dimensions(), (20, 10, 2));
+
+    let stairs = super::areas::Position { x: 10, y: 5, z: 0 };
+    test_world.construction_new(super::items::ConstructionType::Stairs(true), stairs);
+
+    let start = super::areas::Position { x: 2, y: 5, z: 0 };
+    let goal = super::areas::Position { x: 18, y: 5, z: 1 };
+
+    // Another level is only reachable by the stairs.
+    let path = test_world.position_find_path(start, goal).unwrap();
+    assert!(path.contains(&stairs));
+    assert!(path.contains(&super::areas::Position { z: 1, ..stairs }));
+
+    let climber = test_world.wusel_new("Climber".to_string(), wusels::WuselGender::Female, start);
+    test_world.wusel_assign_to_task(climber, super::tasks::TaskBuilder::move_to(goal));
+
+    for _ in 0..40 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.wusel_get_position(climber), Some(goal));
+
+    // The upper level is shown separately.
+    let upper_level = test_world.positions_for_level(1);
+    assert_eq!(upper_level.len(), 20 * 10);
+    assert!(matches!(
+        upper_level[(goal.x + 20 * goal.y) as usize].first(),
+        Some(super::PlaceTaker::Wusel(id)) if *id == climber.get_id()
+    ));
+    assert!(test_world.positions_for_level(0)[(goal.x + 20 * goal.y) as usize].is_empty());
+}
+
+#[test]
+fn test_house_with_floor_and_window() {
+    let mut test_world: super::World = super::World::new(40, 25);
+    build_house(
+        &mut test_world,
+        super::items::ConstructionType::Door(super::items::DOOR_CLOSED),
+    );
+
+    // Floor inside the house, window in the right wall.
+    for x in 21..30 {
+        for y in 11..19 {
+            let position = super::areas::Position { x, y, z: 0 };
+            test_world.construction_new(super::items::ConstructionType::Floor, position);
+        }
+    }
+    let window = super::areas::Position { x: 30, y: 15, z: 0 };
+    test_world.construction_new(super::items::ConstructionType::Window, window);
+
+    let inside = super::areas::Position { x: 25, y: 15, z: 0 };
+    let outside = super::areas::Position { x: 35, y: 15, z: 0 };
+
+    assert!(test_world.position_is_indoors(inside));
+    assert!(!test_world.position_is_indoors(outside));
+
+    // Floors can be walked on, windows only looked through.
+    assert!(test_world.position_is_passable(inside));
+    assert!(!test_world.position_is_passable(window));
+    assert!(test_world.position_is_transparent(window));
+    assert!(!test_world.position_is_transparent(super::areas::Position { x: 30, y: 12, z: 0 }));
+
+    // The window is no way in.
+    assert_eq!(test_world.position_find_path(outside, inside), None);
+}
+
+#[test]
+fn test_rooms_in_house() {
+    let mut test_world: super::World = super::World::new(40, 25);
+    assert!(test_world.room_get_all().is_empty());
+
+    build_house(
+        &mut test_world,
+        super::items::ConstructionType::Door(super::items::DOOR_OPEN),
+    );
+
+    let inside = super::areas::Position { x: 25, y: 15, z: 0 };
+    let outside = super::areas::Position { x: 35, y: 15, z: 0 };
+    let wall = super::areas::Position { x: 30, y: 15, z: 0 };
+
+    // One room, the inner space of the house.
+    assert_eq!(test_world.room_get_all().len(), 1);
+    let room = test_world.room_at(inside).unwrap();
+    assert_eq!(test_world.room_at(outside), None);
+    assert_eq!(test_world.room_at(wall), None);
+    assert_eq!(test_world.room_get_positions(room).len(), 19 * 8);
+
+    test_world.room_set_name(room, "Living Room".to_string());
+    assert_eq!(
+        test_world.room_get_name(room),
+        Some("Living Room".to_string())
+    );
+
+    // Listings of wusels and objects inside.
+    let resident = test_world.wusel_new("Inside".to_string(), wusels::WuselGender::Female, inside);
+    test_world.wusel_new("Outside".to_string(), wusels::WuselGender::Male, outside);
+    let bread = test_world.food_new("Bread", 1);
+    test_world.object_set_position(bread, inside);
+
+    assert_eq!(test_world.room_get_wusels(room), vec![resident]);
+    assert_eq!(test_world.room_get_objects(room), vec![bread]);
+
+    // Split the house: The bigger part keeps the room (and its name).
+    test_world.construction_new(
+        super::items::ConstructionType::Wall(super::items::WALL_UD, 8),
+        super::areas::Position { x: 15, y: 11, z: 0 },
+    );
+    assert_eq!(test_world.room_get_all().len(), 2);
+    assert_eq!(test_world.room_at(inside), Some(room));
+    assert_eq!(
+        test_world.room_get_name(room),
+        Some("Living Room".to_string())
+    );
+
+    let small_room = test_world
+        .room_at(super::areas::Position { x: 12, y: 15, z: 0 })
+        .unwrap();
+    assert_ne!(small_room, room);
+    assert_eq!(test_world.room_get_positions(small_room).len(), 4 * 8);
+}
+
 #[test]
 fn test_consume_bread() {
     // TODO refactor test.
@@ -179,13 +666,13 @@ fn test_consume_bread() {
     test_world.tick();
     log::debug!("Test World ticked");
 
-    test_world.wusel_new(
+    let eater = test_world.wusel_new(
         "Eater".to_string(),
         wusels::WuselGender::Female,
         super::areas::Position { x: 1, y: 0, z: 0 },
     );
 
-    test_world.wusel_new(
+    let starver = test_world.wusel_new(
         "Starver".to_string(),
         wusels::WuselGender::Male,
         super::areas::Position { x: 2, y: 0, z: 0 },
@@ -196,65 +683,57 @@ fn test_consume_bread() {
     // Create food: transportable, no storage.
     let food1 = test_world.food_new("Bread", 100);
 
-    let food1_id = food1;
+    let food1_id = food1.get_id();
 
     log::debug!("Test World's food created, index: {}.", food1_id);
 
-    let food2 = test_world.object_duplicate(0).unwrap(); // unsafe, but must be true.
+    let food2 = test_world.object_duplicate(food1).unwrap(); // unsafe, but must be true.
 
-    let food2_id = food2;
-    test_world.object_set_position(food2_id, test_world.position_random());
+    let food2_id = food2.get_id();
+    let position = test_world.position_random();
+    test_world.object_set_position(food2, position);
 
     log::debug!("Test World's food duplicated, index: {}.", food2_id);
 
     // Put a copy into the world.
-    test_world.object_set_position(food1_id, test_world.position_random());
+    let position = test_world.position_random();
+    test_world.object_set_position(food1, position);
 
     log::debug!("Test World's food put onto a position.");
 
     // Get the food and transport it somewhere else.
-    test_world.wusel_assign_to_task(1, super::tasks::TaskBuilder::use_object(food1_id, 1)); // take
-    test_world.wusel_assign_to_task(
-        1,
-        super::tasks::TaskBuilder::move_to(test_world.position_random()),
-    );
-    test_world.wusel_assign_to_task(1, super::tasks::TaskBuilder::use_object(food1_id, 2)); // drop
-    test_world.wusel_assign_to_task(
-        1,
-        super::tasks::TaskBuilder::move_to(test_world.position_random()),
-    );
-    test_world.wusel_assign_to_task(1, super::tasks::TaskBuilder::use_object(food1_id, 1)); // take not exisiting?
+    test_world.wusel_assign_to_task(starver, super::tasks::TaskBuilder::use_object(food1, 1)); // take
+    let position = test_world.position_random();
+    test_world.wusel_assign_to_task(starver, super::tasks::TaskBuilder::move_to(position));
+    test_world.wusel_assign_to_task(starver, super::tasks::TaskBuilder::use_object(food1, 2)); // drop
+    let position = test_world.position_random();
+    test_world.wusel_assign_to_task(starver, super::tasks::TaskBuilder::move_to(position));
+    test_world.wusel_assign_to_task(starver, super::tasks::TaskBuilder::use_object(food1, 1)); // take not exisiting?
 
     // Let the other wusel wait, than it's tries to get the food as well, and consume it.
     test_world.wusel_assign_to_task(
-        0,
+        eater,
         super::tasks::TaskBuilder::move_to(super::areas::Position {
             x: test_world.get_width() - 1,
             y: test_world.get_depth() - 1,
             z: 0,
         }),
     );
-    test_world.wusel_assign_to_task(0, super::tasks::TaskBuilder::use_object(food1_id, 1)); // take as well.
-    test_world.wusel_assign_to_task(
-        0,
-        super::tasks::TaskBuilder::move_to(test_world.position_random()),
-    );
-    test_world.wusel_assign_to_task(0, super::tasks::TaskBuilder::use_object(food1_id, 3)); // consume.
-    test_world.wusel_assign_to_task(
-        0,
-        super::tasks::TaskBuilder::move_to(test_world.position_random()),
-    );
-    test_world.wusel_assign_to_task(
-        0,
-        super::tasks::TaskBuilder::move_to(test_world.position_random()),
-    );
+    test_world.wusel_assign_to_task(eater, super::tasks::TaskBuilder::use_object(food1, 1)); // take as well.
+    let position = test_world.position_random();
+    test_world.wusel_assign_to_task(eater, super::tasks::TaskBuilder::move_to(position));
+    test_world.wusel_assign_to_task(eater, super::tasks::TaskBuilder::use_object(food1, 3)); // consume.
+    let position = test_world.position_random();
+    test_world.wusel_assign_to_task(eater, super::tasks::TaskBuilder::move_to(position));
+    let position = test_world.position_random();
+    test_world.wusel_assign_to_task(eater, super::tasks::TaskBuilder::move_to(position));
     log::debug!("Test World's task to work at the workbench assigned.");
 
     // show everyone's stats.
-    for i in 0usize..2 {
-        // test_world.wusel_show_tasklist(i); // tasks
+    for &wusel in [eater, starver].iter() {
+        // test_world.wusel_show_tasklist(wusel); // tasks
         for n in wusels::needs::Need::VALUES.iter() {
-            test_world.wusel_set_need(i, n, 100);
+            test_world.wusel_set_need(wusel, n, 100);
         }
     }
     log::debug!("Test World's wusels' needs artificially reduced.");
@@ -290,37 +769,134 @@ fn test_consume_bread() {
 }
 
 /// Test doing tasks.
+/// Example: Wusels want to cook.
+/// 1. Go to (free) cooking station: (move)
+/// 2. Wait for the Station to be free
+/// 3. Work on station.
+/// 4. Fetch tomatoes to be cut and prepared (needs Tomatoes within reach)
+/// 5. Cut (consume) tomatoes, create soup, the better the cook, the more soup.
 #[test]
-fn test_create_bread() {
-    // TODO refactor test.
+fn test_cook_tomato_soup() {
+    let mut test_world: super::World = super::World::new(20, 10);
+
+    let soup = test_world.blueprint_find("Tomato Soup").unwrap();
+    let blueprint = test_world.blueprint_get(soup).unwrap().clone();
+
+    let stove = test_world.object_new(
+        super::objects::ObjectType::Furniture("Stove"),
+        "Stove".to_string(),
+        false,
+        false,
+        0,
+        0,
+    );
+    test_world.object_set_position(stove, super::areas::Position { x: 10, y: 5, z: 0 });
+
+    let fridge = test_world.object_new(
+        super::objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        4,
+    );
+    test_world.object_set_position(fridge, super::areas::Position { x: 8, y: 5, z: 0 });
 
-    // Example: Wusel wants to cook.
-    // 1. Go to (free) cooking station: (move)
-    // 2. Wait for the Station to be free
-    // 3. Work on station.
-    // 4. Fetch tomatoes to be cut and prepared (needs Tomatoes)
-    // 5. Cut (consume) tomatoes, create sauce
-    // 6. Heat up sauce. (> use up cold <? Consumable with extra states?)
-    // 7. Creates hot tomato sauce. (can get cold or be eaten.)
-    //
-    // OPTIONAL
-    // Or should tools also be "Consumed" after 1M uses?
-    // Knife dull and then .. gone
-    // Couch is sit broken after several times?
-
-    // Or knife and Pot as part of the station.
-    // Cut a meal, boil the meal => consumes tomatoes, creates tomato soup.
-
-    // abort if difficulty is too high
-    // walk to station.position, wait until free, block
-    // get required ingredients
-    // do required steps, eg. station changing, prbly a list of subtasks?
-
-    // using objects may influence the needs and skills.
-    // eg.
-    // * eating uses energy, but fills water and hunger
-    // * sleeping fills energy
-    // * doing sports uses energy and water and fills sportivité abilities.
+    for _ in 0..4 {
+        let tomato = test_world.food_new("Tomato", 1);
+        test_world.object_store(tomato, fridge);
+    }
+
+    let chef = test_world.wusel_new(
+        "Chef".to_string(),
+        wusels::WuselGender::Female,
+        super::areas::Position { x: 9, y: 4, z: 0 },
+    );
+    let novice = test_world.wusel_new(
+        "Novice".to_string(),
+        wusels::WuselGender::Male,
+        super::areas::Position { x: 1, y: 8, z: 0 },
+    );
+    let cooking = wusels::abilities::Ability::COOKING;
+    test_world.wusel_set_ability(chef, cooking, 5);
+    test_world.wusel_set_ability(novice, cooking, blueprint.get_difficulty()); // surely succeeds.
+
+    for &cook in [chef, novice].iter() {
+        test_world.wusel_assign_to_task(
+            cook,
+            wusels::tasks::TaskBuilder::craft(soup, blueprint.get_steps()),
+        );
+    }
+
+    // The chef is closer and reserves the stove, the novice waits.
+    for _ in 0..10 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.workstation_get_worker(stove), Some(chef));
+    assert!(!test_world.workstation_is_free_for(stove, novice));
+    assert_eq!(test_world.object_list_contents(fridge).len(), 2);
+
+    for _ in 0..(3 * blueprint.get_steps()) {
+        test_world.tick();
+    }
+
+    // Both cooked with the tomatoes, the chef made more soup.
+    assert!(test_world.object_list_contents(fridge).is_empty());
+    assert_eq!(test_world.workstation_get_worker(stove), None);
+
+    let soup_bites = |cook| {
+        let held = test_world.wusel_list_inventory(cook);
+        assert_eq!(held.len(), 1);
+        let index = test_world.object_index(held[0]).unwrap();
+        assert_eq!(
+            test_world.objects[index].get_object_type(),
+            blueprint.get_product()
+        );
+        test_world.objects[index].get_consumable_left()
+    };
+    assert_eq!(soup_bites(chef), blueprint.get_product_bites_by(5));
+    assert_eq!(
+        soup_bites(novice),
+        blueprint.get_product_bites_by(blueprint.get_difficulty())
+    );
+    assert!(soup_bites(chef) > soup_bites(novice));
+
+    // Both practised, the higher levels need more practice.
+    for &(cook, level) in [(chef, 5), (novice, blueprint.get_difficulty())].iter() {
+        assert_eq!(test_world.wusel_get_ability(cook, cooking), Some(level));
+        assert_eq!(
+            test_world.wusel_get_ability_experience(cook, cooking),
+            Some(
+                wusels::abilities::experience_for_level(level)
+                    + wusels::abilities::EXPERIENCE_PER_PRACTICE
+            )
+        );
+    }
+
+    // Without tomatoes within reach of the stove, there is no more soup.
+    let far_fridge = test_world.object_new(
+        super::objects::ObjectType::Furniture("Fridge"),
+        "Far Fridge".to_string(),
+        false,
+        false,
+        0,
+        4,
+    );
+    test_world.object_set_position(far_fridge, super::areas::Position { x: 18, y: 1, z: 0 });
+    for _ in 0..2 {
+        let tomato = test_world.food_new("Tomato", 1);
+        test_world.object_store(tomato, far_fridge);
+    }
+    test_world.wusel_assign_to_task(
+        chef,
+        wusels::tasks::TaskBuilder::craft(soup, blueprint.get_steps()),
+    );
+    for _ in 0..5 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.wusel_get_tasklist_len(chef), Some(0));
+    assert_eq!(test_world.wusel_list_inventory(chef).len(), 1);
+    assert_eq!(test_world.object_list_contents(far_fridge).len(), 2);
 }
 
 /// Test mutually meeting, which may cause deadlocks.
@@ -367,24 +943,24 @@ fn test_mutual_meeting() {
     // Empty test_world tick.
     test_world.tick();
 
-    test_world.wusel_new(
+    let first = test_world.wusel_new(
         "1st".to_string(),
         wusels::WuselGender::Female,
         super::areas::Position { x: 1, y: 0, z: 0 },
     );
 
-    test_world.wusel_new(
+    let second = test_world.wusel_new(
         "2nd".to_string(),
         wusels::WuselGender::Female,
         super::areas::Position { x: 3, y: 0, z: 0 },
     );
-    test_world.wusel_new(
+    let third = test_world.wusel_new(
         "3rd".to_string(),
         wusels::WuselGender::Male,
         super::areas::Position { x: 5, y: 0, z: 0 },
     );
 
-    test_world.wusel_new(
+    let fourth = test_world.wusel_new(
         "4th".to_string(),
         wusels::WuselGender::Male,
         super::areas::Position { x: 9, y: 0, z: 0 },
@@ -401,28 +977,28 @@ fn test_mutual_meeting() {
     test_world.tick();
 
     // first wusel is also doing something else
-    test_world.wusel_assign_to_task(0, reading.clone()); // do reading.
+    test_world.wusel_assign_to_task(first, reading.clone()); // do reading.
 
     // scenario: everyone wants too meet the next one.
     // mutual meeting.
     test_world.wusel_assign_to_task(
-        0,
-        super::tasks::TaskBuilder::meet_with(1, true, false).set_duration(7),
+        first,
+        super::tasks::TaskBuilder::meet_with(second, true, false).set_duration(7),
     );
     // mutual meeting.
     test_world.wusel_assign_to_task(
-        1,
-        super::tasks::TaskBuilder::meet_with(2, true, false).set_duration(7),
+        second,
+        super::tasks::TaskBuilder::meet_with(third, true, false).set_duration(7),
     );
     // mutual meeting.
     test_world.wusel_assign_to_task(
-        2,
-        super::tasks::TaskBuilder::meet_with(3, true, false).set_duration(7),
+        third,
+        super::tasks::TaskBuilder::meet_with(fourth, true, false).set_duration(7),
     );
     // mutual meeting.
     test_world.wusel_assign_to_task(
-        3,
-        super::tasks::TaskBuilder::meet_with(0, true, false).set_duration(7),
+        fourth,
+        super::tasks::TaskBuilder::meet_with(first, true, false).set_duration(7),
     );
 
     // 90 ticks later.
@@ -16,9 +16,7 @@ fn init_log() {
 fn wusel_init() {
     init_log();
 
-    let wusel: wusels::Wusel;
-
-    wusel = wusels::Wusel::new(
+    let wusel: wusels::Wusel = wusels::Wusel::new(
         0,
         "Wusel Name Start".to_string(),
         wusels::WuselGender::Female,
@@ -28,7 +26,7 @@ fn wusel_init() {
     assert_eq!(wusel.get_name(), "Wusel Name Start".to_string());
     assert_eq!(wusel.get_gender(), wusels::WuselGender::Female);
 
-    assert_eq!(wusel.is_alive(), true);
+    assert!(wusel.is_alive());
 
     for &need in wusels::needs::Need::VALUES.iter() {
         assert_eq!(wusel.get_need(need), need.get_full()); // init_log full.
@@ -52,9 +50,7 @@ fn wusel_init() {
 fn wusel_rename_and_co() {
     init_log();
 
-    let mut wusel: wusels::Wusel;
-
-    wusel = wusels::Wusel::new(
+    let mut wusel: wusels::Wusel = wusels::Wusel::new(
         0,
         "Wusel Name Start".to_string(),
         wusels::WuselGender::Female,
@@ -72,7 +68,7 @@ fn wusel_rename_and_co() {
 
     // rest stayes the same.
 
-    assert_eq!(wusel.is_alive(), true);
+    assert!(wusel.is_alive());
 
     for &need in wusels::needs::Need::VALUES.iter() {
         assert_eq!(wusel.get_need(need), need.get_full()); // init_log full.
@@ -96,9 +92,7 @@ fn wusel_rename_and_co() {
 fn wusel_tick_to_death() {
     init_log();
 
-    let mut wusel: wusels::Wusel;
-
-    wusel = wusels::Wusel::new(
+    let mut wusel: wusels::Wusel = wusels::Wusel::new(
         0,
         "Wusel Name Start".to_string(),
         wusels::WuselGender::Female,
@@ -152,7 +146,7 @@ fn wusel_tick_to_death() {
     assert_eq!(wusel.get_next_task_index_with(&|_| true), None);
 
     wusel.wusel_tick(true);
-    assert_eq!(wusel.is_alive(), false); // just died.
+    assert!(!wusel.is_alive()); // just died.
 }
 
 #[test]
@@ -189,7 +183,11 @@ fn wusel_impregnate() {
     // define gender.
     // set parents.
 
-    let baby = wusels::Wusel::new(2, "Baby".to_string(), wusels::WuselGender::random());
+    let baby = wusels::Wusel::new(
+        2,
+        "Baby".to_string(),
+        wusels::WuselGender::random(&mut rand::thread_rng()),
+    );
     wusel0.set_pregnancy(None, None); // done.
 
     assert_eq!(baby.get_lived_days(), 0);
@@ -220,11 +218,15 @@ fn wusel_test_assignment() {
     );
     wusel0.assign_to_task(
         init_time,
-        wusels::tasks::TaskBuilder::use_object(food1_id, 1),
+        wusels::tasks::TaskBuilder::use_object(objects::ObjectHandle::from_id(food1_id), 1),
     );
     wusel0.assign_to_task(
         init_time,
-        wusels::tasks::TaskBuilder::meet_with(wusel1.get_id(), friendly, romantically),
+        wusels::tasks::TaskBuilder::meet_with(
+            wusels::WuselHandle::from_id(wusel1.get_id()),
+            friendly,
+            romantically,
+        ),
     );
 
     assert!(!wusel0.has_tasklist_empty());
@@ -239,10 +241,10 @@ fn wusel_test_assignment() {
             assert_eq!(y, 4);
             assert_eq!(z, 0);
         } else {
-            assert!(false);
+            panic!("Ongoing task is not moving.");
         }
     } else {
-        assert!(false);
+        panic!("No ongoing task.");
     }
 
     let opt_index: Option<usize> = wusel0.get_next_task_index_with(
@@ -264,3 +266,1730 @@ fn wusel_test_assignment() {
     let prioritize_task_success = wusel0.prioritize_task(index);
     assert!(prioritize_task_success);
 }
+
+#[test]
+fn world_occupancy() {
+    init_log();
+
+    let mut test_world = world::World::new(10, 10);
+    let position = world::areas::Position { x: 4, y: 4, z: 0 };
+
+    // Second wusel on the same position is put next to the first one.
+    let first = test_world.wusel_new("First".to_string(), wusels::WuselGender::Female, position);
+    let second = test_world.wusel_new("Second".to_string(), wusels::WuselGender::Male, position);
+
+    assert_eq!(test_world.wusel_get_position(first), Some(position));
+    assert_ne!(test_world.wusel_get_position(second), Some(position));
+    assert_eq!(test_world.position_get_wusels(position), vec![first]);
+
+    assert!(test_world.wusel_can_pass(first, position));
+    assert!(!test_world.wusel_can_pass(second, position));
+
+    // Blocking objects need an empty position, stackables share.
+    let wardrobe = test_world.object_new(
+        objects::ObjectType::Furniture("Wardrobe"),
+        "Wardrobe".to_string(),
+        false,
+        false,
+        0,
+        0,
+    );
+    assert!(!test_world.object_set_position(wardrobe, position));
+
+    let bread = test_world.food_new("Bread", 3);
+    let apple = test_world.food_new("Apple", 1);
+    let free_position = world::areas::Position { x: 0, y: 0, z: 0 };
+    assert!(test_world.object_set_position(bread, free_position));
+    assert!(!test_world.object_set_position(apple, free_position)); // not stackable.
+
+    // Shared positions, if allowed.
+    let mut policy = test_world.get_occupancy_policy();
+    policy.wusels_exclusive = false;
+    policy.only_stackables_share = false;
+    test_world.set_occupancy_policy(policy);
+
+    assert!(test_world.wusel_can_pass(second, position));
+    assert!(test_world.object_set_position(apple, free_position));
+}
+
+#[test]
+fn world_area_levels() {
+    init_log();
+
+    let area = world::areas::Area::new(world::areas::Position::ROOT, 4, 3, 2);
+
+    let positions: Vec<world::areas::Position> = area.collect();
+    assert_eq!(positions.len(), 4 * 3 * 2);
+
+    for (index, position) in positions.iter().enumerate() {
+        assert_eq!(area.position_from_index(index as u32), Some(*position));
+        assert!(area.contains_position(position));
+    }
+
+    assert_eq!(
+        area.position_from_index(13),
+        Some(world::areas::Position { x: 1, y: 0, z: 1 })
+    );
+    assert_eq!(area.position_from_index(24), None);
+}
+
+#[test]
+fn world_save_and_load() {
+    init_log();
+
+    let mut test_world = world::World::new_with_levels(20, 12, 2);
+
+    test_world.construction_new(
+        world::items::ConstructionType::Wall(world::items::WALL_LR, 6),
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+    test_world.construction_new(
+        world::items::ConstructionType::Wall(world::items::WALL_LR, 6),
+        world::areas::Position { x: 2, y: 6, z: 0 },
+    );
+    test_world.construction_new(
+        world::items::ConstructionType::Wall(world::items::WALL_UD, 3),
+        world::areas::Position { x: 2, y: 3, z: 0 },
+    );
+    test_world.construction_new(
+        world::items::ConstructionType::Wall(world::items::WALL_UD, 3),
+        world::areas::Position { x: 7, y: 3, z: 0 },
+    );
+    test_world.construction_new(
+        world::items::ConstructionType::Door(world::items::DOOR_CLOSED),
+        world::areas::Position { x: 7, y: 4, z: 0 },
+    );
+
+    let inside = world::areas::Position { x: 4, y: 4, z: 0 };
+    let room = test_world.room_at(inside).unwrap();
+    test_world.room_set_name(room, "Kitchen".to_string());
+
+    let saved = test_world.wusel_new(
+        "Saved".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 10, y: 8, z: 1 },
+    );
+    let other = test_world.wusel_new("Other".to_string(), wusels::WuselGender::Male, inside);
+    test_world.wusel_update_relations(
+        saved,
+        other,
+        true,
+        wusels::relations::RelationType::Friendship,
+    );
+
+    let bread = test_world.food_new("Bread", 3);
+    test_world.object_set_position(bread, world::areas::Position { x: 5, y: 4, z: 0 });
+
+    test_world.wusel_assign_to_task(
+        saved,
+        wusels::tasks::TaskBuilder::move_to(world::areas::Position { x: 15, y: 8, z: 1 }),
+    );
+    for _ in 0..3 {
+        test_world.tick();
+    }
+
+    let path = std::env::temp_dir().join(format!("wusel_save_{}.json", std::process::id()));
+    test_world.save(&path).unwrap();
+    let mut loaded = world::World::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded.get_time(), test_world.get_time());
+    assert_eq!(loaded.get_dimensions(), (20, 12, 2));
+    assert_eq!(
+        loaded.wusel_get_all_alive(),
+        test_world.wusel_get_all_alive()
+    );
+
+    // Handles stay valid in the loaded world.
+    for wusel in [saved, other] {
+        assert_eq!(
+            loaded.wusel_get_position(wusel),
+            test_world.wusel_get_position(wusel)
+        );
+        assert_eq!(
+            loaded.wusel_get_name(wusel),
+            test_world.wusel_get_name(wusel)
+        );
+        assert_eq!(
+            loaded.wusel_get_tasklist_names(wusel),
+            test_world.wusel_get_tasklist_names(wusel)
+        );
+        for &need in wusels::needs::Need::VALUES.iter() {
+            assert_eq!(
+                loaded.wusel_get_need(wusel, need),
+                test_world.wusel_get_need(wusel, need)
+            );
+        }
+    }
+
+    assert_eq!(
+        loaded.wusel_get_relation(saved, other),
+        test_world.wusel_get_relation(saved, other)
+    );
+    assert!(loaded.wusel_get_relation(saved, other).is_some());
+
+    assert_eq!(loaded.room_at(inside), Some(room));
+    assert_eq!(loaded.room_get_name(room), Some("Kitchen".to_string()));
+    assert_eq!(loaded.room_get_objects(room), vec![bread]);
+    assert_eq!(loaded.door_get_all().len(), 1);
+    assert_eq!(loaded.door_is_open(loaded.door_get_all()[0]), Some(false));
+    assert_eq!(
+        loaded.positions_for_all_placetakers().len(),
+        test_world.positions_for_all_placetakers().len()
+    );
+    assert!(matches!(
+        loaded.positions_for_level(0)[5 + 20 * 4].first(),
+        Some(world::PlaceTaker::Object(id, objects::ObjectType::Food("Bread"))) if *id == bread.get_id()
+    ));
+
+    // Loading again reuses the loaded subtypes, instead of leaking them again.
+    let path = std::env::temp_dir().join(format!("wusel_save_again_{}.json", std::process::id()));
+    loaded.save(&path).unwrap();
+    let reloaded = world::World::load(&path).unwrap();
+    match (
+        loaded.object_get_type(bread),
+        reloaded.object_get_type(bread),
+    ) {
+        (Some(objects::ObjectType::Food(first)), Some(objects::ObjectType::Food(second))) => {
+            assert!(std::ptr::eq(first, second))
+        }
+        types => panic!("Unexpected object types {:?}", types),
+    }
+
+    // A save file with an overflowing size is rejected.
+    let mut document: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    document["world"]["width"] = serde_json::json!(u32::MAX);
+    document["world"]["depth"] = serde_json::json!(u32::MAX);
+    document["world"]["height"] = serde_json::json!(u32::MAX);
+    std::fs::write(&path, document.to_string()).unwrap();
+    assert!(world::World::load(&path).is_err());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn world_replays_recordings() {
+    init_log();
+
+    let mut scenario =
+        world::scenario::Scenario::parse(include_str!("../../../scenarios/example.json")).unwrap();
+    scenario.set_default_size(40, 25);
+    scenario.seed = Some(17);
+    let mut test_world = scenario.build().unwrap();
+
+    let mut recording = world::save::Recording::start(&test_world).unwrap();
+    for _ in 0..500 {
+        test_world.tick();
+        recording.record_tick();
+    }
+
+    let path = std::env::temp_dir().join(format!("wusel_recording_{}.json", std::process::id()));
+    recording.save(&path).unwrap();
+    let loaded = world::save::Recording::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(loaded, recording);
+    assert_eq!(loaded.get_ticks(), 500);
+
+    // Replaying the recorded ticks leads to exactly the same world.
+    let mut replayed = loaded.get_start().unwrap();
+    assert_eq!(replayed.get_time(), 0);
+    for _ in 0..loaded.get_ticks() {
+        replayed.tick();
+    }
+    assert_eq!(
+        replayed.to_document().unwrap(),
+        test_world.to_document().unwrap()
+    );
+
+    // A save is no recording.
+    let path = std::env::temp_dir().join(format!("wusel_no_recording_{}.json", std::process::id()));
+    test_world.save(&path).unwrap();
+    assert!(world::save::Recording::load(&path).is_err());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn world_load_rejects_unknown_versions() {
+    init_log();
+
+    let future = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": world::save::VERSION + 1,
+        "world": {},
+    });
+    assert!(world::save::unwrap_and_migrate(future).is_err());
+
+    let unknown = serde_json::json!({ "format": "something else", "version": 1 });
+    assert!(world::save::unwrap_and_migrate(unknown).is_err());
+
+    // Version 1 had no random generator yet.
+    let v1 =
+        serde_json::json!({ "format": world::save::FORMAT, "version": 1, "world": { "clock": 1 } });
+    let migrated = world::save::unwrap_and_migrate(v1).unwrap();
+    assert_eq!(migrated["seed"], serde_json::json!(0));
+    assert!(migrated.get("rng").is_some());
+    assert_eq!(
+        migrated["advertisements"],
+        serde_json::to_value(world::World::default_advertisements()).unwrap()
+    );
+    assert_eq!(
+        migrated["actions"],
+        serde_json::to_value(world::World::default_actions()).unwrap()
+    );
+    assert_eq!(
+        migrated["blueprints"],
+        serde_json::to_value(world::World::default_blueprints()).unwrap()
+    );
+
+    // Version 2 kept the actions' effects, they become advertisements of the exact object type.
+    let v2 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 2,
+        "world": {
+            "objects": [{ "name": "Kimchi", "id": 4, "consumable_bites": 3, "consumable_bites_left": 3 }],
+            "objects_index_with_id": [4],
+            "objects_index_with_type": [{ "Food": "Kimchi" }],
+            "objects_index_with_whereabouts": [{ "OnPositionIndex": 3 }],
+            "actions_effects": [
+                [4, 0, "Consume", [["FOOD", 100], ["FUN", 5]]],
+                [4, 0, "Dance", [["FUN", 50]]], // unknown action.
+                [9, 0, "View", [["FUN", 1]]], // unknown object.
+            ],
+        },
+    });
+    let migrated = world::save::unwrap_and_migrate(v2).unwrap();
+    let mut expected = world::World::default_advertisements();
+    expected.register(
+        world::advertisements::Advertisement::new(
+            world::advertisements::Advertiser::Exactly(objects::ObjectType::Food("Kimchi")),
+            world::World::ACTION_CONSUME,
+        )
+        .with_effect(wusels::needs::Need::FOOD, 100)
+        .with_effect(wusels::needs::Need::FUN, 5),
+    );
+    assert_eq!(
+        migrated["advertisements"],
+        serde_json::to_value(expected).unwrap()
+    );
+    assert!(migrated.get("actions_effects").is_none());
+
+    // Version 5 kept the objects' ids, types and whereabouts apart, constructions had own ids.
+    let v5 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 5,
+        "world": {
+            "sequential_object_id": 2,
+            "objects": [
+                { "name": "Fridge", "id": 0, "object_attributes": 1,
+                  "consumable_bites": 0, "consumable_bites_left": 0,
+                  "storage_capacity": 2, "storage_capacity_left": 1, "aging_percent": 25 },
+                { "name": "Kimchi", "id": 0, "object_attributes": 4,
+                  "consumable_bites": 3, "consumable_bites_left": 2,
+                  "storage_capacity": 0, "storage_capacity_left": 0,
+                  "consumable_state": { "spoils_after": 60, "age": 5, "need_change": {} } },
+            ],
+            "objects_index_with_id": [0, 1],
+            "objects_index_with_type": [{ "Furniture": "Fridge" }, { "Food": "Kimchi" }],
+            "objects_index_with_whereabouts": [{ "OnPositionIndex": 3 }, { "InStorageId": 0 }],
+            "constructions": [{ "id": 0, "construction_type": { "Door": false } }],
+            "constructions_index_on_position_index": [7],
+            "wusels": [{ "tasklist": [{ "passive_part": { "UseDoor": [0, "Open"] } }] }],
+        },
+    });
+    let migrated = world::save::unwrap_and_migrate(v5).unwrap();
+    assert_eq!(migrated["sequential_entity_id"], serde_json::json!(3));
+    assert_eq!(migrated["constructions"][0]["id"], serde_json::json!(2));
+    assert_eq!(
+        migrated["constructions"][0]["position_index"],
+        serde_json::json!(7)
+    );
+    assert_eq!(
+        migrated["wusels"][0]["tasklist"][0]["passive_part"]["UseDoor"][0],
+        serde_json::json!(2)
+    );
+    assert_eq!(migrated["objects"][1]["id"], serde_json::json!(1));
+    assert_eq!(
+        migrated["objects"][1]["whereabouts"],
+        serde_json::json!({ "InStorageId": 0 })
+    );
+    assert_eq!(
+        migrated["objects"][1]["consumable"]["bites_left"],
+        serde_json::json!(2)
+    );
+    assert_eq!(
+        migrated["objects"][1]["consumable"]["spoils_after"],
+        serde_json::json!(60)
+    );
+    assert!(migrated["objects"][1]["storage"].is_null());
+    assert!(migrated["objects"][0]["consumable"].is_null());
+    assert_eq!(
+        migrated["objects"][0]["storage"]["aging_percent"],
+        serde_json::json!(25)
+    );
+    assert!(migrated.get("objects_index_with_id").is_none());
+
+    let malformed = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 5,
+        "world": { "constructions": [42] },
+    });
+    assert!(world::save::unwrap_and_migrate(malformed).is_err());
+
+    // Version 6 derived the reproductive traits from the gender.
+    let v6 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 6,
+        "world": { "wusels": [{ "gender": "Female" }], "dead_wusels": [{ "gender": "Male" }] },
+    });
+    let migrated = world::save::unwrap_and_migrate(v6).unwrap();
+    assert_eq!(
+        migrated["wusels"][0]["reproduction"],
+        serde_json::json!({ "can_carry": true, "can_impregnate": false })
+    );
+    assert_eq!(
+        migrated["dead_wusels"][0]["reproduction"],
+        serde_json::json!({ "can_carry": false, "can_impregnate": true })
+    );
+    assert_eq!(
+        migrated["pregnancy_days"],
+        serde_json::json!(world::World::PREGNANCY_DAYS)
+    );
+
+    // Version 7 had no life stages: Every wusel was grown up.
+    let v7 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 7,
+        "world": { "wusels": [{ "lived_days": 2 }], "dead_wusels": [{ "lived_days": 70 }] },
+    });
+    let migrated = world::save::unwrap_and_migrate(v7).unwrap();
+    let life_stages = wusels::stages::LifeStages::default();
+    assert_eq!(
+        migrated["wusels"][0]["lived_days"],
+        serde_json::json!(life_stages.adult)
+    );
+    assert_eq!(
+        migrated["wusels"][0]["life_stage"],
+        serde_json::json!("Adult")
+    );
+    assert_eq!(
+        migrated["dead_wusels"][0]["life_stage"],
+        serde_json::json!("Elder")
+    );
+    assert_eq!(
+        migrated["life_stages"],
+        serde_json::to_value(life_stages).unwrap()
+    );
+
+    // Version 8 kept the ability levels only.
+    let v8 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 8,
+        "world": { "wusels": [{ "abilities": { "COOKING": 2 } }] },
+    });
+    let migrated = world::save::unwrap_and_migrate(v8).unwrap();
+    assert_eq!(
+        migrated["wusels"][0]["abilities"]["COOKING"],
+        serde_json::json!({ "experience": wusels::abilities::experience_for_level(2), "idle_days": 0 })
+    );
+
+    // Version 9 kept the constructions' positions apart.
+    let v9 = serde_json::json!({
+        "format": world::save::FORMAT,
+        "version": 9,
+        "world": {
+            "constructions": [{ "id": 4, "construction_type": "Window" }],
+            "constructions_index_on_position_index": [12],
+        },
+    });
+    let migrated = world::save::unwrap_and_migrate(v9).unwrap();
+    assert_eq!(
+        migrated["constructions"][0]["position_index"],
+        serde_json::json!(12)
+    );
+    assert!(migrated
+        .get("constructions_index_on_position_index")
+        .is_none());
+
+    let current = world::save::wrap(serde_json::json!({ "clock": 1 }));
+    assert_eq!(
+        world::save::unwrap_and_migrate(current).unwrap(),
+        serde_json::json!({ "clock": 1 })
+    );
+}
+
+#[test]
+fn world_from_scenario() {
+    init_log();
+
+    let mut example =
+        world::scenario::Scenario::parse(include_str!("../../../scenarios/example.json")).unwrap();
+    example.set_default_size(40, 25);
+    let example_world = example.build().unwrap();
+    assert_eq!(example_world.get_dimensions(), (40, 25, 1));
+    assert_eq!(example_world.wusel_count(), 6);
+    assert_eq!(example_world.door_get_all().len(), 1);
+    let living_room = example_world
+        .room_at(world::areas::Position { x: 15, y: 15, z: 0 })
+        .unwrap();
+    assert_eq!(
+        example_world.room_get_name(living_room),
+        Some("Living Room".to_string())
+    );
+    let example_objects = example_world.object_get_all();
+    assert_eq!(
+        example_world.object_list_contents(example_objects[0]),
+        example_objects[2..8].to_vec()
+    );
+
+    let scenario = world::scenario::Scenario::parse(
+        r#"{
+            "name": "Locked Room",
+            "width": 20, "depth": 10,
+            "wusels": [
+                { "name": "Owner", "gender": "Female", "position": { "x": 2, "y": 2, "z": 0 },
+                  "needs": { "FOOD": 20 }, "abilities": { "COOKING": 7 },
+                  "tasks": [
+                    { "type": "use_object", "object": 0, "action": 3 },
+                    { "type": "move_to", "position": { "x": 15, "y": 2, "z": 0 } }
+                  ] },
+                { "name": "Guest", "gender": "Male", "position": { "x": 2, "y": 2, "z": 0 } }
+            ],
+            "objects": [
+                { "object_type": { "Food": "Soup" }, "bites": 2, "position": { "x": 15, "y": 8, "z": 0 } }
+            ],
+            "constructions": [
+                { "type": "door", "locked": true, "owner": 0, "position": { "x": 10, "y": 5, "z": 0 } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let mut scenario_world = scenario.build().unwrap();
+    let owner = scenario_world.wusel_get_all()[0];
+    let guest = scenario_world.wusel_get_all()[1];
+
+    assert_eq!(
+        scenario_world.wusel_get_need(owner, wusels::needs::Need::FOOD),
+        20
+    );
+    assert_eq!(
+        scenario_world.wusel_get_ability(owner, wusels::abilities::Ability::COOKING),
+        Some(7)
+    );
+    assert_eq!(scenario_world.wusel_get_tasklist_len(owner), Some(2));
+    assert_ne!(
+        scenario_world.wusel_get_position(guest),
+        scenario_world.wusel_get_position(owner)
+    );
+
+    let door = scenario_world.door_get_all()[0];
+    assert_eq!(scenario_world.door_get_owner(door), Some(owner));
+    assert_eq!(scenario_world.door_is_locked(door), Some(true));
+    let soup = scenario_world.object_get_all()[0];
+    assert_ne!(soup.get_id(), door); // objects and constructions share one id space.
+    assert_eq!(
+        scenario_world.object_get_position(soup),
+        Some(world::areas::Position { x: 15, y: 8, z: 0 })
+    );
+
+    // Invalid scenarios are rejected.
+    for invalid in [
+        r#"{ "wusels": [] }"#,
+        r#"{ "width": 5, "depth": 5, "wusels": [ { "name": "Far", "position": { "x": 9, "y": 0, "z": 0 } } ] }"#,
+        r#"{ "width": 5, "depth": 5, "wusels": [ { "name": "Lonely", "tasks": [ { "type": "meet_with", "wusel": 3 } ] } ] }"#,
+        r#"{ "width": 5, "depth": 5, "unknown": true }"#,
+        r#"{ "width": 5, "depth": 5, "objects": [ { "object_type": { "Food": "Soup" }, "stored_in": 0 } ] }"#,
+        r#"{ "width": 100000, "depth": 100000 }"#,
+        r#"{ "width": 4294967295, "depth": 4294967295, "height": 4294967295 }"#,
+        r#"{ "width": 5, "depth": 5, "constructions": [ { "type": "floor", "width": 4294967295, "depth": 1, "position": { "x": 2, "y": 2, "z": 0 } } ] }"#,
+    ] {
+        let result = world::scenario::Scenario::parse(invalid).and_then(|s| s.build());
+        assert!(result.is_err(), "Accepted: {}", invalid);
+    }
+}
+
+#[test]
+fn world_is_reproducible_with_seed() {
+    init_log();
+
+    // Let the wusels wander randomly, with the world's random generator.
+    fn live(test_world: &mut world::World, ticks: usize) {
+        for _ in 0..ticks {
+            test_world.tick();
+            for wusel in test_world.wusel_get_all_unbusy() {
+                let goal = test_world.position_random();
+                test_world.wusel_assign_to_task(wusel, wusels::tasks::TaskBuilder::move_to(goal));
+            }
+        }
+    }
+
+    let mut example =
+        world::scenario::Scenario::parse(include_str!("../../../scenarios/example.json")).unwrap();
+    example.set_default_size(40, 25);
+    example.seed = Some(7);
+
+    let mut world_a = example.build().unwrap();
+    let mut world_b = example.build().unwrap();
+    assert_eq!(world_a.get_seed(), 7);
+
+    live(&mut world_a, 100);
+    live(&mut world_b, 100);
+    assert_eq!(
+        serde_json::to_value(&world_a).unwrap(),
+        serde_json::to_value(&world_b).unwrap()
+    );
+
+    // A loaded world continues the same.
+    let path = std::env::temp_dir().join(format!("wusel_seed_{}.json", std::process::id()));
+    world_a.save(&path).unwrap();
+    let mut loaded = world::World::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    live(&mut world_a, 100);
+    live(&mut loaded, 100);
+    assert_eq!(
+        serde_json::to_value(&world_a).unwrap(),
+        serde_json::to_value(&loaded).unwrap()
+    );
+
+    // Another seed, another life.
+    example.seed = Some(8);
+    let mut world_c = example.build().unwrap();
+    live(&mut world_c, 100);
+    assert_ne!(
+        serde_json::to_value(&world_b).unwrap(),
+        serde_json::to_value(&world_c).unwrap()
+    );
+}
+
+#[test]
+fn world_object_advertisements() {
+    init_log();
+
+    use world::advertisements::{Advertisement, Advertiser};
+    use wusels::abilities::Ability;
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(40, 10);
+    let here = world::areas::Position { x: 0, y: 0, z: 0 };
+
+    // Every world knows, that food satisfies hunger.
+    let bread = test_world.food_new("Bread", 2);
+    test_world.object_set_position(bread, world::areas::Position { x: 3, y: 0, z: 0 });
+    assert_eq!(
+        test_world.object_find_satisfying(Need::FOOD, here, 20.0),
+        vec![(bread, world::World::ACTION_CONSUME, 3.0)]
+    );
+
+    // Toys are fun to look at, but only the close ones are found.
+    test_world.advertisement_register(
+        Advertisement::new(Advertiser::AnyMiscellaneous, 0)
+            .with_effect(Need::FUN, 10)
+            .with_duration(5),
+    );
+    let ball = test_world.object_new(
+        objects::ObjectType::Miscellaneous("Ball"),
+        "Ball".to_string(),
+        true,
+        true,
+        0,
+        0,
+    );
+    let kite = test_world.object_new(
+        objects::ObjectType::Miscellaneous("Kite"),
+        "Kite".to_string(),
+        true,
+        true,
+        0,
+        0,
+    );
+    test_world.object_set_position(ball, world::areas::Position { x: 10, y: 0, z: 0 });
+    test_world.object_set_position(kite, world::areas::Position { x: 30, y: 0, z: 0 });
+
+    let fun: Vec<objects::ObjectHandle> = test_world
+        .object_find_satisfying(Need::FUN, here, 20.0)
+        .into_iter()
+        .map(|(object, _, _)| object)
+        .collect();
+    assert_eq!(fun, vec![ball]);
+    assert!(test_world
+        .object_find_satisfying(Need::FOOD, here, 20.0)
+        .iter()
+        .all(|(object, _, _)| *object == bread));
+
+    // An exact subtype wins over the whole object type: Flying a kite needs finesse.
+    test_world.advertisement_register(
+        Advertisement::new(
+            Advertiser::Exactly(objects::ObjectType::Miscellaneous("Kite")),
+            0,
+        )
+        .with_effect(Need::FUN, 50)
+        .with_requirement(Ability::FINESSE, 3),
+    );
+    let kite_advertisements = test_world.object_get_advertisements(kite);
+    assert_eq!(kite_advertisements.len(), 1);
+    assert_eq!(
+        kite_advertisements[0].get_requirements(),
+        &[(Ability::FINESSE, 3)]
+    );
+    assert_eq!(
+        test_world.object_get_advertisements(ball)[0].get_effect_on(Need::FUN),
+        50
+    );
+
+    // Only wusels with the required abilities get the effects.
+    let clumsy = test_world.wusel_new(
+        "Clumsy".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 29, y: 1, z: 0 },
+    );
+    let skilled = test_world.wusel_new(
+        "Skilled".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 31, y: 1, z: 0 },
+    );
+    test_world.wusel_set_ability(skilled, Ability::FINESSE, 5);
+    for &wusel in [clumsy, skilled].iter() {
+        test_world.wusel_set_need(wusel, &Need::FUN, 100);
+        test_world.wusel_assign_to_task(wusel, wusels::tasks::TaskBuilder::use_object(kite, 0));
+    }
+    for _ in 0..3 {
+        test_world.tick();
+    }
+    assert!(test_world.wusel_get_need(clumsy, Need::FUN) < 100);
+    assert!(test_world.wusel_get_need(skilled, Need::FUN) > 100);
+}
+
+#[test]
+fn world_registers_new_actions() {
+    init_log();
+
+    use world::actions::{Action, Effect, Precondition};
+    use world::advertisements::{Advertisement, Advertiser};
+    use wusels::abilities::Ability;
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(20, 10);
+
+    // Registering an existing verb replaces it, but keeps its ID.
+    let view = test_world
+        .action_register(Action::new("View").with_step_effect(Effect::Need(Need::FUN, 1)));
+    assert_eq!(view, world::World::ACTION_VIEW);
+    assert_eq!(
+        test_world.action_find("View"),
+        Some(world::World::ACTION_VIEW)
+    );
+
+    // New verbs: Sitting is comfy, washing is done after a while and needs some finesse.
+    let sit = test_world.action_register(
+        Action::new("Sit")
+            .with_precondition(Precondition::NotHeld)
+            .with_step_effect(Effect::Need(Need::SLEEP, 2)),
+    );
+    let wash = test_world.action_register(
+        Action::new("Wash")
+            .with_precondition(Precondition::Ability(Ability::FINESSE, 1))
+            .with_completion_effect(Effect::Improve(Ability::FINESSE))
+            .with_completion_effect(Effect::Need(Need::HEALTH, 10)),
+    );
+    assert_eq!(test_world.action_find("Sit"), Some(sit));
+    assert_eq!(
+        test_world.action_get(wash).map(Action::get_name),
+        Some("Wash")
+    );
+
+    test_world.advertisement_register(
+        Advertisement::new(
+            Advertiser::Exactly(objects::ObjectType::Furniture("Chair")),
+            sit,
+        )
+        .with_effect(Need::FUN, 1),
+    );
+
+    let chair = test_world.object_new(
+        objects::ObjectType::Furniture("Chair"),
+        "Chair".to_string(),
+        false,
+        false,
+        0,
+        0,
+    );
+    let tub = test_world.object_new(
+        objects::ObjectType::Furniture("Tub"),
+        "Tub".to_string(),
+        false,
+        false,
+        0,
+        0,
+    );
+    test_world.object_set_position(chair, world::areas::Position { x: 1, y: 0, z: 0 });
+    test_world.object_set_position(tub, world::areas::Position { x: 1, y: 2, z: 0 });
+
+    let sitter = test_world.wusel_new(
+        "Sitter".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 0, y: 0, z: 0 },
+    );
+    let washer = test_world.wusel_new(
+        "Washer".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 0, y: 2, z: 0 },
+    );
+    let unskilled = test_world.wusel_new(
+        "Unskilled".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+
+    for &wusel in [sitter, washer].iter() {
+        test_world.wusel_set_need(wusel, &Need::SLEEP, 100);
+        test_world.wusel_set_need(wusel, &Need::FUN, 100);
+    }
+    test_world.wusel_set_ability(washer, Ability::FINESSE, 1);
+    test_world.wusel_set_need(washer, &Need::HEALTH, 100);
+    test_world.wusel_set_need(unskilled, &Need::HEALTH, 100);
+
+    test_world.wusel_assign_to_task(
+        sitter,
+        wusels::tasks::TaskBuilder::use_object(chair, sit).set_duration(3),
+    );
+    for &wusel in [washer, unskilled].iter() {
+        test_world.wusel_assign_to_task(
+            wusel,
+            wusels::tasks::TaskBuilder::use_object(tub, wash).set_duration(3),
+        );
+    }
+
+    for _ in 0..5 {
+        test_world.tick();
+    }
+
+    // Every step of sitting was restful (and fun, as advertised).
+    assert!(
+        test_world.wusel_get_need(sitter, Need::SLEEP)
+            > test_world.wusel_get_need(washer, Need::SLEEP)
+    );
+    assert!(
+        test_world.wusel_get_need(sitter, Need::FUN) > test_world.wusel_get_need(washer, Need::FUN)
+    );
+
+    // Washing is only done by the skilled wusel, the effects come with the last step.
+    assert_eq!(
+        test_world.wusel_get_ability_experience(washer, Ability::FINESSE),
+        Some(
+            wusels::abilities::experience_for_level(1) + wusels::abilities::EXPERIENCE_PER_PRACTICE
+        )
+    );
+    assert!(test_world.wusel_get_need(washer, Need::HEALTH) > 100);
+    assert!(test_world.wusel_get_need(unskilled, Need::HEALTH) <= 100);
+    assert_eq!(test_world.wusel_get_tasklist_len(unskilled), Some(0));
+}
+
+#[test]
+fn world_storage_and_inventory() {
+    init_log();
+
+    let mut test_world = world::World::new(10, 10);
+
+    let fridge = test_world.object_new(
+        objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        2,
+    );
+    let bag = test_world.object_new(
+        objects::ObjectType::Miscellaneous("Bag"),
+        "Bag".to_string(),
+        true,
+        true,
+        0,
+        1,
+    );
+    let bread = test_world.food_new("Bread", 2);
+    let soup = test_world.food_new("Soup", 2);
+    let cake = test_world.food_new("Cake", 2);
+
+    let fridge_position = world::areas::Position { x: 5, y: 5, z: 0 };
+    assert!(test_world.object_set_position(fridge, fridge_position));
+
+    // Storages have a capacity, stored objects are where their storage is.
+    assert!(test_world.object_store(bread, fridge));
+    assert!(test_world.object_store(soup, fridge));
+    assert!(!test_world.object_store(cake, fridge));
+    assert_eq!(test_world.object_list_contents(fridge), vec![bread, soup]);
+    assert_eq!(test_world.object_get_storage(bread), Some(fridge));
+    assert_eq!(test_world.object_get_position(soup), Some(fridge_position));
+
+    // Non portable objects cannot be stored, nor held.
+    assert!(!test_world.object_store(fridge, bag));
+
+    // Storages can be nested, but not in themselves.
+    assert!(test_world.object_store(cake, bag));
+    assert!(!test_world.object_store(bag, bag));
+    assert!(!test_world.object_store(bag, cake));
+
+    // Wusels hold their inventory in their hands, within their limits.
+    let wusel = test_world.wusel_new(
+        "Carrier".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 1, y: 1, z: 0 },
+    );
+    assert!(test_world.wusel_take_object(wusel, bag));
+    assert!(test_world.wusel_take_object(wusel, bread)); // taken out of the fridge.
+    assert!(!test_world.wusel_take_object(wusel, soup));
+    assert_eq!(
+        test_world.wusel_list_inventory(wusel).len(),
+        world::World::WUSEL_CARRY_LIMIT
+    );
+    assert_eq!(test_world.object_list_contents(fridge), vec![soup]);
+    assert_eq!(test_world.object_get_holder(cake), Some(wusel)); // in the held bag.
+    assert_eq!(
+        test_world.object_get_position(cake),
+        test_world.wusel_get_position(wusel)
+    );
+
+    // The fridge has room again.
+    assert!(test_world.object_store(cake, fridge));
+    assert!(test_world.object_list_contents(bag).is_empty());
+
+    // Dropped objects lie, where the wusel stands.
+    assert!(test_world.wusel_drop_object(wusel, bag));
+    assert!(!test_world.wusel_drop_object(wusel, soup));
+    assert_eq!(test_world.wusel_list_inventory(wusel), vec![bread]);
+    assert_eq!(
+        test_world.object_get_position(bag),
+        test_world.wusel_get_position(wusel)
+    );
+}
+
+#[test]
+fn world_food_spoils() {
+    init_log();
+
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(10, 10);
+
+    let fridge = test_world.object_new(
+        objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        2,
+    );
+    test_world.object_set_aging_percent(fridge, 25);
+    test_world.object_set_position(fridge, world::areas::Position { x: 5, y: 5, z: 0 });
+
+    let outside = test_world.food_new("Bread", 2);
+    let cooled = test_world.food_new("Bread", 2);
+    test_world.object_set_position(outside, world::areas::Position { x: 2, y: 2, z: 0 });
+    test_world.object_store(cooled, fridge);
+    assert!(test_world.object_get_age(fridge).is_none()); // furniture does not age.
+
+    test_world.object_set_spoils_after(outside, 1);
+    test_world.object_set_spoils_after(cooled, 1);
+
+    for _ in 0..world::World::TICKS_PER_DAY {
+        test_world.tick();
+    }
+
+    // Outside, the bread spoiled within a day, in the fridge it is still good.
+    assert_eq!(test_world.object_get_age(outside), Some(1));
+    assert_eq!(test_world.object_get_age(cooled), Some(0));
+    assert!(test_world.object_is_spoiled(outside));
+    assert!(!test_world.object_is_spoiled(cooled));
+
+    // Huge days and percentages (eg. from a scenario) do not overflow.
+    let mut preserved = world::items::Consumable::new(1, u32::MAX);
+    assert!(!preserved.age_by(100));
+    assert!(!preserved.is_spoiled());
+    test_world.object_set_aging_percent(fridge, u32::MAX);
+    test_world.object_set_spoils_after(cooled, u32::MAX);
+    for _ in 0..world::World::TICKS_PER_DAY {
+        test_world.tick();
+    }
+    assert!(!test_world.object_is_spoiled(cooled));
+    test_world.object_set_aging_percent(fridge, 25);
+
+    // Eating spoiled food harms the health.
+    let eater = test_world.wusel_new(
+        "Eater".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 3, y: 3, z: 0 },
+    );
+    let healthy = test_world.wusel_get_need(eater, Need::HEALTH);
+    test_world.wusel_assign_to_task(
+        eater,
+        wusels::tasks::TaskBuilder::use_object(outside, world::World::ACTION_CONSUME),
+    );
+    test_world.tick();
+    assert_eq!(
+        test_world.wusel_get_need(eater, Need::HEALTH) as i64,
+        healthy as i64 + world::World::SPOILED_HEALTH_PER_BITE as i64
+    );
+
+    // But fresh food does not.
+    test_world.wusel_assign_to_task(
+        eater,
+        wusels::tasks::TaskBuilder::use_object(cooled, world::World::ACTION_CONSUME),
+    );
+    for _ in 0..5 {
+        test_world.tick();
+    }
+    assert_eq!(
+        test_world.wusel_get_need(eater, Need::HEALTH) as i64,
+        healthy as i64 + world::World::SPOILED_HEALTH_PER_BITE as i64
+    );
+}
+
+#[test]
+fn world_entities_share_one_id_space() {
+    init_log();
+
+    let mut test_world = world::World::new(10, 10);
+
+    let bread = test_world.food_new("Bread", 3);
+    test_world.construction_new(
+        world::items::ConstructionType::Door(false),
+        world::areas::Position { x: 4, y: 4, z: 0 },
+    );
+    let door = test_world.door_get_all()[0];
+    let fridge = test_world.object_new(
+        objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        2,
+    );
+    let copy = test_world.object_duplicate(bread).unwrap();
+
+    let mut ids = vec![bread.get_id(), door, fridge.get_id(), copy.get_id()];
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), 4);
+    assert_eq!(test_world.object_get_all(), vec![bread, fridge, copy]);
+    assert_eq!(
+        test_world.object_get_type(copy),
+        Some(objects::ObjectType::Food("Bread"))
+    );
+
+    // The type index stays aligned, when an object is destroyed.
+    test_world.object_store(bread, fridge);
+    test_world.object_destroy(0);
+    assert_eq!(test_world.object_get_all(), vec![fridge, copy]);
+    assert_eq!(
+        test_world.object_get_type(copy),
+        Some(objects::ObjectType::Food("Bread"))
+    );
+    assert!(test_world.object_list_contents(fridge).is_empty());
+}
+
+#[test]
+fn world_handles_outlive_shifts_and_go_stale() {
+    init_log();
+
+    let mut test_world = world::World::new(10, 10);
+
+    let bread = test_world.food_new("Bread", 1);
+    let apple = test_world.food_new("Apple", 1);
+    let fridge = test_world.object_new(
+        objects::ObjectType::Furniture("Fridge"),
+        "Fridge".to_string(),
+        false,
+        false,
+        0,
+        2,
+    );
+    test_world.object_set_position(fridge, world::areas::Position { x: 5, y: 5, z: 0 });
+    assert!(test_world.object_store(apple, fridge));
+
+    let eater = test_world.wusel_new(
+        "Eater".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 1, y: 1, z: 0 },
+    );
+    assert_eq!(test_world.wusel_handle(eater.get_id()), Some(eater));
+    assert!(test_world.wusel_take_object(eater, bread));
+
+    // The bread is eaten up: Its handle is stale, the others moved one slot.
+    test_world.wusel_assign_to_task(
+        eater,
+        wusels::tasks::TaskBuilder::use_object(bread, world::World::ACTION_CONSUME),
+    );
+    test_world.tick();
+    assert_eq!(test_world.object_handle(bread.get_id()), None);
+    assert_eq!(test_world.object_get_all(), vec![apple, fridge]);
+
+    // Stale handles answer with nothing and change nothing.
+    assert_eq!(test_world.object_get_type(bread), None);
+    assert_eq!(test_world.object_get_holder(bread), None);
+    assert!(test_world.wusel_list_inventory(eater).is_empty());
+    assert!(!test_world.object_store(bread, fridge));
+    assert!(!test_world.wusel_take_object(eater, bread));
+    assert_eq!(test_world.object_duplicate(bread), None);
+
+    // Shifted handles are still found by their identifier.
+    assert_eq!(test_world.object_list_contents(fridge), vec![apple]);
+    assert_eq!(test_world.object_get_storage(apple), Some(fridge));
+    assert!(test_world.wusel_take_object(eater, apple));
+    assert_eq!(test_world.object_get_holder(apple), Some(eater));
+}
+
+#[test]
+fn world_births_with_kinship() {
+    init_log();
+
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(3);
+
+    let position = world::areas::Position { x: 5, y: 5, z: 0 };
+    let grandmother = test_world.wusel_new(
+        "Grandmother".to_string(),
+        wusels::WuselGender::Female,
+        position,
+    );
+    let father = test_world.wusel_new(
+        "Father".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 1, y: 1, z: 0 },
+    );
+
+    // Give birth, as soon as the pregnancy is due.
+    fn birth(
+        test_world: &mut world::World,
+        parent: wusels::WuselHandle,
+        other_parent: Option<wusels::WuselHandle>,
+    ) -> wusels::WuselHandle {
+        test_world.wusel_set_pregnancy(parent, other_parent, Some(0));
+        for _ in 0..100 {
+            test_world.tick();
+            if let Some((_, world::events::Event::Birth { child, .. })) =
+                test_world.events_take().pop()
+            {
+                return child;
+            }
+        }
+        panic!("No baby was born.");
+    }
+
+    let mother = birth(&mut test_world, grandmother, None);
+    assert_eq!(test_world.wusel_count(), 3);
+    assert!(!test_world.wusel_is_pregnant(grandmother).unwrap());
+    assert!(
+        test_world
+            .wusel_get_position(mother)
+            .unwrap()
+            .distance_to(&position)
+            < 1.5
+    );
+
+    let child = birth(&mut test_world, mother, Some(father));
+
+    // Related to both parents and further to the parents' kin.
+    let kindred_distance = |wusel0, wusel1| {
+        test_world
+            .wusel_get_relation(wusel0, wusel1)
+            .and_then(|relation| relation.get_kindred_distance())
+    };
+    assert_eq!(kindred_distance(child, mother), Some(1));
+    assert_eq!(kindred_distance(child, father), Some(1));
+    assert_eq!(kindred_distance(child, grandmother), Some(2));
+    assert_eq!(kindred_distance(mother, father), None);
+
+    // Newborns start without abilities, hungry and sleepy.
+    assert_eq!(test_world.wusel_get_lived_days(child), Some(0));
+    assert!(!test_world.wusel_get_name(child).unwrap().is_empty());
+    for &ability in wusels::abilities::Ability::VALUES.iter() {
+        assert_eq!(test_world.wusel_get_ability(child, ability), Some(0));
+    }
+    assert!(test_world.wusel_get_need(child, Need::FOOD) <= Need::FOOD.get_full() / 2);
+    assert!(test_world.wusel_get_need(child, Need::WATER) > Need::WATER.get_full() / 2);
+
+    // Close kin never flirt, even when grown up; unrelated adults may.
+    test_world.wusel_set_lived_days(mother, 20);
+    assert!(!test_world.wusels_may_flirt(mother, grandmother));
+    assert!(test_world.wusels_may_flirt(mother, father));
+}
+
+#[test]
+fn world_romance_may_lead_to_pregnancy() {
+    init_log();
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(5);
+    test_world.set_pregnancy_days(4);
+
+    let lover = test_world.wusel_new(
+        "Lover".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+    let beloved = test_world.wusel_new(
+        "Beloved".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 3, y: 2, z: 0 },
+    );
+
+    // The traits are independent from the gender: Here, he carries the child.
+    test_world.wusel_set_reproduction(
+        lover,
+        wusels::Reproduction {
+            can_carry: false,
+            can_impregnate: true,
+        },
+    );
+    test_world.wusel_set_reproduction(
+        beloved,
+        wusels::Reproduction {
+            can_carry: true,
+            can_impregnate: false,
+        },
+    );
+    test_world.wusel_set_gender(beloved, wusels::WuselGender::Undefined);
+    assert!(
+        test_world
+            .wusel_get_reproduction(beloved)
+            .unwrap()
+            .can_carry
+    );
+
+    let romance = |test_world: &mut world::World| {
+        test_world.wusel_assign_to_task(
+            lover,
+            wusels::tasks::TaskBuilder::romance_with(beloved).set_duration(2),
+        );
+        for _ in 0..10 {
+            test_world.tick();
+        }
+    };
+
+    // Strangers do not consent, no child comes from it.
+    assert!(!test_world.wusels_consent_to_romance(lover, beloved));
+    romance(&mut test_world);
+    assert!(test_world
+        .wusel_get_relation(lover, beloved)
+        .is_some_and(|relation| relation.get_romance() > 0));
+    assert_eq!(test_world.wusel_is_pregnant(beloved), Some(false));
+
+    // Romantically close, a child may come with time.
+    while !test_world.wusels_consent_to_romance(lover, beloved) {
+        romance(&mut test_world);
+    }
+    for _ in 0..30 {
+        if test_world.wusel_is_pregnant(beloved) == Some(true) {
+            break;
+        }
+        romance(&mut test_world);
+    }
+    assert_eq!(test_world.wusel_is_pregnant(beloved), Some(true));
+    assert_eq!(test_world.wusel_is_pregnant(lover), Some(false));
+    assert_eq!(test_world.wusel_get_other_parent(beloved), Some(lover));
+    assert_eq!(
+        test_world.wusel_get_remaining_pregnancy_days(beloved),
+        Some(4)
+    );
+}
+
+#[test]
+fn world_autonomous_adults_flirt_into_pregnancy() {
+    init_log();
+
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(3);
+    test_world.set_autonomous(true);
+
+    let first = test_world.wusel_new(
+        "First".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+    let second = test_world.wusel_new(
+        "Second".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 7, y: 7, z: 0 },
+    );
+
+    // Strangers, nobody tells them what to do.
+    assert!(test_world.wusels_may_flirt(first, second));
+    assert!(!test_world.wusels_consent_to_romance(first, second));
+
+    let mut ticks = 0;
+    while ticks < 3 * world::World::TICKS_PER_DAY
+        && test_world.wusel_is_pregnant(first) != Some(true)
+        && test_world.wusel_is_pregnant(second) != Some(true)
+    {
+        for &wusel in [first, second].iter() {
+            for &need in Need::VALUES.iter() {
+                if test_world.wusel_get_need(wusel, need) < need.get_full() / 2 {
+                    test_world.wusel_set_need(wusel, &need, need.get_full());
+                }
+            }
+        }
+        test_world.tick();
+        ticks += 1;
+    }
+
+    assert!(test_world.wusels_consent_to_romance(first, second));
+    assert!(
+        test_world.wusel_is_pregnant(first) == Some(true)
+            || test_world.wusel_is_pregnant(second) == Some(true),
+        "No pregnancy after {} ticks.",
+        ticks
+    );
+}
+
+#[test]
+fn world_pregnancy_lasts_the_configured_days() {
+    init_log();
+
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(7);
+    test_world.set_pregnancy_days(4);
+
+    let carrier = test_world.wusel_new(
+        "Carrier".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 5, y: 5, z: 0 },
+    );
+    test_world.wusel_set_pregnancy(carrier, None, Some(test_world.get_pregnancy_days()));
+
+    let mut ticks = 0;
+    let mut born = false;
+    while !born && ticks < 5 * world::World::TICKS_PER_DAY {
+        // Keep the carrier well, the pregnancy lasts longer than the needs.
+        for &need in Need::VALUES.iter() {
+            test_world.wusel_set_need(carrier, &need, need.get_full());
+        }
+        test_world.tick();
+        ticks += 1;
+
+        born = matches!(
+            test_world.events_take().pop(),
+            Some((_, world::events::Event::Birth { .. }))
+        );
+    }
+
+    // Never born before the due day (only rarely on the day before).
+    assert!(born);
+    assert!(
+        ticks >= 3 * world::World::TICKS_PER_DAY,
+        "Born after {} ticks",
+        ticks
+    );
+    assert_eq!(test_world.wusel_is_pregnant(carrier), Some(false));
+    assert_eq!(test_world.wusel_count(), 2);
+}
+
+#[test]
+fn world_death_buries_grieves_and_haunts() {
+    init_log();
+
+    use wusels::needs::Need;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(5);
+
+    let position = world::areas::Position { x: 5, y: 5, z: 0 };
+    let dying = test_world.wusel_new("Dying".to_string(), wusels::WuselGender::Female, position);
+    let friend = test_world.wusel_new(
+        "Friend".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 1, y: 1, z: 0 },
+    );
+    let stranger = test_world.wusel_new(
+        "Stranger".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 8, y: 8, z: 0 },
+    );
+
+    let bread = test_world.food_new("Bread", 3);
+    test_world.object_set_position(bread, position);
+    assert!(test_world.wusel_take_object(dying, bread));
+
+    test_world.wusel_update_relations(
+        dying,
+        friend,
+        true,
+        wusels::relations::RelationType::Friendship,
+    );
+    test_world.wusel_assign_to_task(
+        stranger,
+        wusels::tasks::TaskBuilder::meet_with(dying, true, false),
+    );
+
+    let fun_before = test_world.wusel_get_need(friend, Need::FUN);
+    let stranger_fun_before = test_world.wusel_get_need(stranger, Need::FUN);
+
+    // Dies of thirst with the next tick.
+    test_world.wusel_set_need(dying, &Need::WATER, 1);
+    test_world.tick();
+
+    let dying_id = dying.get_id();
+    assert_eq!(
+        test_world.events_take(),
+        vec![(
+            1,
+            world::events::Event::Death {
+                wusel: dying,
+                cause: wusels::CauseOfDeath::Need(Need::WATER),
+            }
+        )]
+    );
+
+    // Buried: No zombie is left in the world.
+    assert_eq!(test_world.wusel_count(), 2);
+    assert_eq!(test_world.wusel_is_alive(dying), None);
+    assert!(test_world.position_get_wusels(position).is_empty());
+    assert_eq!(test_world.dead_wusel_get_all(), vec![dying]);
+    assert_eq!(
+        test_world.dead_wusel_get_name(dying),
+        Some("Dying".to_string())
+    );
+    assert_eq!(
+        test_world.dead_wusel_get_cause_of_death(dying),
+        Some(wusels::CauseOfDeath::Need(Need::WATER))
+    );
+
+    // What they held is dropped, a tombstone is placed (outdoors).
+    assert!(test_world.object_get_holder(bread).is_none());
+    assert!(test_world.object_get_position(bread).is_some());
+
+    let tombstone = *test_world
+        .object_get_all()
+        .iter()
+        .find(|&&object| test_world.memorial_get_wusel(object) == Some(dying))
+        .unwrap();
+    assert_eq!(
+        test_world.object_get_type(tombstone),
+        Some(objects::ObjectType::Furniture(
+            world::World::MEMORIAL_TOMBSTONE
+        ))
+    );
+    assert_eq!(
+        test_world.object_get_name(tombstone),
+        Some("Tombstone of Dying".to_string())
+    );
+    assert!(
+        test_world
+            .object_get_position(tombstone)
+            .unwrap()
+            .distance_to(&position)
+            < 1.5
+    );
+
+    // Nobody meets the dead anymore, only who was close grieves.
+    assert_eq!(test_world.wusel_get_tasklist_len(stranger), Some(0));
+    assert!(test_world.wusel_get_need(friend, Need::FUN) + world::World::GRIEF / 2 <= fun_before);
+    assert!(test_world.wusel_get_need(stranger, Need::FUN) + 2 >= stranger_fun_before);
+
+    // They return as ghost at their tombstone, keeping their identifier.
+    let ghost = test_world.dead_wusel_return_as_ghost(dying).unwrap();
+    assert_eq!(ghost.get_id(), dying_id);
+    assert_eq!(test_world.wusel_handle(dying_id), Some(ghost));
+    assert_eq!(test_world.wusel_get_name(dying), Some("Dying".to_string())); // valid again.
+    assert_eq!(test_world.wusel_is_ghost(ghost), Some(true));
+    assert_eq!(test_world.wusel_is_alive(ghost), Some(false));
+    assert!(test_world.dead_wusel_get_all().is_empty());
+    assert_eq!(
+        test_world.events_take().pop().map(|(_, event)| event),
+        Some(world::events::Event::Haunting { ghost: dying })
+    );
+
+    // Ghosts do not need anything and do not die again, but they still wander.
+    let food = test_world.wusel_get_need(ghost, Need::FOOD);
+    let goal = world::areas::Position { x: 5, y: 8, z: 0 };
+    test_world.wusel_assign_to_task(ghost, wusels::tasks::TaskBuilder::move_to(goal));
+    for _ in 0..20 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.wusel_get_need(ghost, Need::FOOD), food);
+    assert_eq!(test_world.wusel_get_need(ghost, Need::WATER), 0);
+    assert_eq!(test_world.wusel_get_position(ghost), Some(goal));
+    assert_eq!(test_world.wusel_count(), 3);
+    assert_eq!(
+        test_world
+            .wusel_new("Next".to_string(), wusels::WuselGender::Female, goal)
+            .get_id(),
+        3
+    );
+}
+
+#[test]
+fn world_life_stages() {
+    init_log();
+
+    use wusels::needs::Need;
+    use wusels::stages::LifeStage;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(7);
+    test_world.set_life_stages(wusels::stages::LifeStages {
+        child: 1,
+        teen: 2,
+        adult: 3,
+        elder: 4,
+        old_age: 5,
+    });
+
+    // New wusels join grown up.
+    let adult = test_world.wusel_new(
+        "Adult".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 1, y: 1, z: 0 },
+    );
+    assert_eq!(test_world.wusel_get_lived_days(adult), Some(3));
+    assert_eq!(
+        test_world.wusel_get_life_stage(adult),
+        Some(LifeStage::Adult)
+    );
+
+    let baby = test_world.wusel_new(
+        "Baby".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 5, y: 5, z: 0 },
+    );
+    test_world.wusel_set_lived_days(baby, 0);
+    assert_eq!(test_world.wusel_get_life_stage(baby), Some(LifeStage::Baby));
+
+    // Babies cannot cook, and they are too young for romance.
+    test_world.wusel_assign_to_task(baby, wusels::tasks::TaskBuilder::craft(0, 3));
+    test_world.tick();
+    assert_eq!(test_world.wusel_get_tasklist_len(baby), Some(0));
+    assert!(!test_world.wusels_consent_to_romance(baby, adult));
+
+    // Babies get hungry faster.
+    let adult_food = test_world.wusel_get_need(adult, Need::FOOD);
+    let baby_food = test_world.wusel_get_need(baby, Need::FOOD);
+    test_world.tick();
+    assert_eq!(test_world.wusel_get_need(adult, Need::FOOD), adult_food - 1);
+    assert_eq!(test_world.wusel_get_need(baby, Need::FOOD), baby_food - 2);
+
+    // A new day: The baby grows up, a child learns faster.
+    while !test_world
+        .get_time()
+        .is_multiple_of(world::World::TICKS_PER_DAY)
+    {
+        test_world.tick();
+    }
+    assert_eq!(
+        test_world.wusel_get_life_stage(baby),
+        Some(LifeStage::Child)
+    );
+
+    let cooking = wusels::abilities::Ability::COOKING;
+    test_world.wusel_improve(baby, cooking);
+    test_world.wusel_improve(adult, cooking);
+    assert_eq!(test_world.wusel_get_ability(baby, cooking), Some(2));
+    assert_eq!(test_world.wusel_get_ability(adult, cooking), Some(1));
+
+    // Long beyond old age, the next day is the last.
+    test_world.wusel_set_lived_days(adult, 30);
+    assert_eq!(
+        test_world.wusel_get_life_stage(adult),
+        Some(LifeStage::Elder)
+    );
+    test_world.events_take();
+    for _ in 0..world::World::TICKS_PER_DAY {
+        test_world.tick();
+    }
+    assert_eq!(
+        test_world.events_take(),
+        vec![(
+            2 * world::World::TICKS_PER_DAY,
+            world::events::Event::Death {
+                wusel: adult,
+                cause: wusels::CauseOfDeath::OldAge,
+            }
+        )]
+    );
+    assert_eq!(test_world.wusel_get_life_stage(baby), Some(LifeStage::Teen));
+}
+
+#[test]
+fn wusel_learns_by_doing_and_forgets_unpractised() {
+    init_log();
+
+    use wusels::abilities;
+    use wusels::abilities::Ability;
+
+    // Every level needs more practice than the last.
+    assert_eq!(abilities::experience_for_level(1), 10);
+    assert_eq!(abilities::experience_for_level(2), 30);
+    assert_eq!(abilities::level_for_experience(29), 1);
+    assert_eq!(
+        abilities::level_for_experience(u32::MAX),
+        abilities::MAX_LEVEL
+    );
+
+    let mut wusel = wusels::Wusel::new(0, "Learner".to_string(), wusels::WuselGender::Female);
+    assert!(wusel.improve(Ability::COOKING));
+    assert_eq!(wusel.get_ability(Ability::COOKING), 1);
+    assert!(!wusel.improve(Ability::COOKING));
+    assert!(wusel.improve(Ability::COOKING));
+    assert_eq!(wusel.get_ability(Ability::COOKING), 2);
+
+    // Only unpractised for too long, the ability decays.
+    let experience = wusel.get_ability_experience(Ability::COOKING);
+    for _ in 0..abilities::IDLE_DAYS_BEFORE_DECAY {
+        wusel.wusel_tick(true);
+    }
+    assert_eq!(wusel.get_ability_experience(Ability::COOKING), experience);
+    wusel.wusel_tick(true);
+    assert_eq!(
+        wusel.get_ability_experience(Ability::COOKING),
+        experience - abilities::EXPERIENCE_DECAY_PER_DAY
+    );
+    assert_eq!(wusel.get_ability(Ability::COOKING), 1);
+}
+
+#[test]
+fn world_skills_from_tasks() {
+    init_log();
+
+    use wusels::abilities::Ability;
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(11);
+
+    let talker = test_world.wusel_new(
+        "Talker".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+    let listener = test_world.wusel_new(
+        "Listener".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 3, y: 2, z: 0 },
+    );
+
+    // Meetings practise communication on both sides, walking practises fitness.
+    test_world.wusel_assign_to_task(
+        talker,
+        wusels::tasks::TaskBuilder::meet_with(listener, true, false).set_duration(2),
+    );
+    test_world.wusel_assign_to_task(
+        listener,
+        wusels::tasks::TaskBuilder::move_to(world::areas::Position { x: 3, y: 5, z: 0 }),
+    );
+    for _ in 0..20 {
+        test_world.tick();
+    }
+    assert_eq!(
+        test_world.wusel_get_ability(talker, Ability::COMMUNICATION),
+        Some(1)
+    );
+    assert_eq!(
+        test_world.wusel_get_ability(listener, Ability::COMMUNICATION),
+        Some(1)
+    );
+    assert_eq!(
+        test_world.wusel_get_ability(listener, Ability::FITNESS),
+        Some(1)
+    );
+
+    // Far below the difficulty, crafting fails, but the wusel learns from it.
+    let origami = test_world.blueprint_register(
+        world::items::Blueprint::new("Origami", objects::ObjectType::Miscellaneous("Crane"), 1)
+            .with_difficulty(Ability::FINESSE, 4),
+    );
+    let blueprint = test_world.blueprint_get(origami).unwrap().clone();
+    assert_eq!(blueprint.get_success_percent_by(0), 0);
+    assert_eq!(blueprint.get_success_percent_by(3), 75);
+    assert_eq!(blueprint.get_haste_percent_by(6), 20);
+
+    test_world.wusel_assign_to_task(talker, wusels::tasks::TaskBuilder::craft(origami, 1));
+    for _ in 0..3 {
+        test_world.tick();
+    }
+    assert!(test_world.wusel_list_inventory(talker).is_empty());
+    assert_eq!(test_world.object_get_all().len(), 0);
+    assert_eq!(
+        test_world.wusel_get_ability(talker, Ability::FINESSE),
+        Some(1)
+    );
+
+    // Skilled enough, it surely succeeds.
+    test_world.wusel_set_ability(talker, Ability::FINESSE, 4);
+    test_world.wusel_assign_to_task(talker, wusels::tasks::TaskBuilder::craft(origami, 1));
+    for _ in 0..3 {
+        test_world.tick();
+    }
+    assert_eq!(test_world.wusel_list_inventory(talker).len(), 1);
+}
+
+#[test]
+fn world_meetings_by_communication() {
+    init_log();
+
+    use world::communication;
+    use wusels::abilities::Ability;
+    use wusels::needs::Need;
+
+    // Skilled, happy and familiar wusels get along better than novices, sad ones or strangers.
+    let good = communication::quality(1.0, 1.0, 1.0, 0.0, true);
+    assert!(good > communication::quality(0.0, 1.0, 1.0, 0.0, true));
+    assert!(good > communication::quality(1.0, 0.0, 1.0, 0.0, true));
+    assert!(good > communication::quality(1.0, 1.0, 0.0, 0.0, true));
+    assert!(communication::quality(1.0, 1.0, 1.0, 1.0, false) < 0.0);
+    assert_eq!(communication::outcome(-0.5, false).love_change, 0);
+    assert!(
+        communication::outcome(0.5, true).love_change
+            > communication::outcome(0.5, false).love_change
+    );
+
+    let mut test_world = world::World::new(10, 10);
+    test_world.set_seed(3);
+
+    let talker = test_world.wusel_new(
+        "Talker".to_string(),
+        wusels::WuselGender::Female,
+        world::areas::Position { x: 2, y: 2, z: 0 },
+    );
+    let listener = test_world.wusel_new(
+        "Listener".to_string(),
+        wusels::WuselGender::Male,
+        world::areas::Position { x: 3, y: 2, z: 0 },
+    );
+
+    // Eloquent wusels become friends quickly, and the meeting lets them feel loved.
+    for wusel in [talker, listener] {
+        test_world.wusel_set_ability(wusel, Ability::COMMUNICATION, 10);
+    }
+    let half_love = Need::LOVE.get_full() / 2;
+    test_world.wusel_set_need(listener, &Need::LOVE, half_love);
+
+    test_world.wusel_assign_to_task(
+        talker,
+        wusels::tasks::TaskBuilder::meet_with(listener, true, false).set_duration(2),
+    );
+    for _ in 0..10 {
+        test_world.tick();
+    }
+    let friendship = test_world
+        .wusel_get_relation(talker, listener)
+        .map(|relation| relation.get_friendship())
+        .unwrap_or_default();
+    assert!(friendship >= 4, "friendship {}", friendship);
+    assert!(test_world.wusel_get_need(listener, Need::LOVE) > half_love);
+
+    // A rude meeting estranges them again.
+    test_world.wusel_assign_to_task(
+        talker,
+        wusels::tasks::TaskBuilder::meet_with(listener, false, false).set_duration(2),
+    );
+    for _ in 0..10 {
+        test_world.tick();
+    }
+    assert!(test_world
+        .wusel_get_relation(talker, listener)
+        .is_some_and(|relation| relation.get_friendship() < friendship));
+}
@@ -1,5 +1,10 @@
+//! # Abilities
+//!
+//! Abilities are learnt by doing: Practising gains experience, which reaches levels.
+//! Every level needs more practice than the last, and an ability left unpractised for too long decays.
+
 /// An ability, the Wusel can learn to improve their lifestyle.
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Ability {
     COOKING,
     COMMUNICATION,
@@ -24,3 +29,82 @@ impl Ability {
         }
     }
 }
+
+/// Experience, a wusel gains by practising an ability once (multiplied by their learning rate).
+pub const EXPERIENCE_PER_PRACTICE: u32 = 10;
+
+/// Experience needed for the first level: Every further level needs that much more than the last.
+pub const EXPERIENCE_PER_LEVEL: u32 = 10;
+
+/// The highest level of an ability.
+pub const MAX_LEVEL: u32 = 10;
+
+/// Days, an ability can be left unpractised, before it decays.
+pub const IDLE_DAYS_BEFORE_DECAY: u32 = 7;
+
+/// Experience lost on each day, an ability is left unpractised for too long.
+pub const EXPERIENCE_DECAY_PER_DAY: u32 = 5;
+
+/// Get the experience needed to reach the level.
+/// The higher the level, the more practice it needs to get to the next one (diminishing returns).
+pub fn experience_for_level(level: u32) -> u32 {
+    let level = u32::min(level, MAX_LEVEL);
+    EXPERIENCE_PER_LEVEL * level * (level + 1) / 2
+}
+
+/// Get the level reached with the experience.
+pub fn level_for_experience(experience: u32) -> u32 {
+    (1..=MAX_LEVEL)
+        .take_while(|&level| experience >= experience_for_level(level))
+        .last()
+        .unwrap_or(0)
+}
+
+/// How well a wusel knows an ability: Their experience and for how long they did not practise it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Skill {
+    experience: u32,
+    idle_days: u32, // days since the last practice.
+}
+
+impl Skill {
+    /// Create a skill, which just reached the level.
+    pub fn with_level(level: u32) -> Self {
+        Self {
+            experience: experience_for_level(level),
+            idle_days: 0,
+        }
+    }
+
+    pub fn get_level(&self) -> u32 {
+        level_for_experience(self.experience)
+    }
+
+    pub fn get_experience(&self) -> u32 {
+        self.experience
+    }
+
+    pub fn get_idle_days(&self) -> u32 {
+        self.idle_days
+    }
+
+    /// Practise the skill and gain the experience (up to the highest level).
+    /// Returns if a new level was reached.
+    pub fn practise(&mut self, experience: u32) -> bool {
+        let level = self.get_level();
+        self.experience = u32::min(
+            self.experience.saturating_add(experience),
+            experience_for_level(MAX_LEVEL),
+        );
+        self.idle_days = 0;
+        self.get_level() > level
+    }
+
+    /// A day passed: After being idle for too long, the skill decays.
+    pub fn pass_day(&mut self) {
+        self.idle_days = self.idle_days.saturating_add(1);
+        if self.idle_days > IDLE_DAYS_BEFORE_DECAY {
+            self.experience = self.experience.saturating_sub(EXPERIENCE_DECAY_PER_DAY);
+        }
+    }
+}
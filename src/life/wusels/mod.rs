@@ -1,6 +1,6 @@
 //! # Wusel
 //!
-//! ... and gender, lifestates, needs, abilities.
+//! ... and gender, lifestates, life stages, needs, abilities.
 //!
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
@@ -8,36 +8,62 @@
 pub mod abilities;
 pub mod needs;
 pub mod relations;
+pub mod stages;
 pub mod tasks;
 
 pub type WuselId = usize;
 
+/// Handle to a wusel in the world (see [handles](crate::life::handles)).
+pub type WuselHandle = crate::life::handles::Handle<Wusel>;
+
+/// Needs, a newborn wusel has only half satisfied: They eat and sleep a lot.
+const NEWBORN_HALF_NEEDS: [needs::Need; 2] = [needs::Need::FOOD, needs::Need::SLEEP];
+
 /// Life state of a Wusel.
 /// All but alive leads to a not living state, though a ghost may wander and interact.
-#[derive(Copy, Clone, PartialEq, Debug)]
+///
+/// A dead wusel is buried by the world (it leaves for the graveyard).
+/// A ghost stays: Their needs do not change anymore, they neither age, conceive nor die again.
+#[derive(Copy, Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum Life {
     ALIVE,
-    #[allow(unused)]
     DEAD,
-    #[allow(unused)]
     GHOST,
 }
 
+/// Why a wusel died.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CauseOfDeath {
+    Need(needs::Need), // a fatal need was not satisfied, eg. they starved.
+    OldAge,            // they lived long enough (see stages::LifeStages::old_age).
+    Unknown,           // eg. they were just declared dead.
+}
+
+impl std::fmt::Display for CauseOfDeath {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Need(need) => write!(fmt, "lack of {}", need.get_name()),
+            Self::OldAge => write!(fmt, "old age"),
+            Self::Unknown => write!(fmt, "unknown causes"),
+        }
+    }
+}
+
 /// A non-binary gender type for a Wusel
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum WuselGender {
     Female,
     Male,
     Undefined,
     // TODO (2021-12-11) Though it is still discrete.
-    // Make something like: can carry / can impregnate (not mutually exclusive, independent from gender identification
+    // The reproductive traits are independent from the gender (see Reproduction).
 }
 
 impl WuselGender {
     pub const VALUES: [Self; 2] = [Self::Female, Self::Male];
 
-    pub fn random() -> Self {
-        Self::VALUES[rand::random::<usize>() % Self::VALUES.len()]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::VALUES[rng.gen_range(0..Self::VALUES.len())]
     }
 
     pub fn to_char(&self) -> char {
@@ -57,17 +83,46 @@ impl WuselGender {
     }
 }
 
+/// Reproductive traits of a wusel, independent from their gender.
+///
+/// They are not mutually exclusive: A wusel may carry and impregnate, or neither.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Reproduction {
+    pub can_carry: bool,      // can be pregnant and give birth.
+    pub can_impregnate: bool, // can make another wusel pregnant.
+}
+
+impl Reproduction {
+    /// Get the traits, most wusels of the gender have.
+    pub fn typical_for(gender: WuselGender) -> Self {
+        Self {
+            can_carry: gender == WuselGender::Female,
+            can_impregnate: gender == WuselGender::Male,
+        }
+    }
+
+    /// Check, if this wusel may carry a child of the other wusel.
+    pub fn may_conceive_with(&self, other: &Self) -> bool {
+        self.can_carry && other.can_impregnate
+    }
+}
+
 /// Wusel.
 /// Bundle of information on a certain position and abilities.
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct Wusel {
     id: WuselId,
     name: String,
     gender: WuselGender,
+    reproduction: Reproduction,
     pregnancy: Option<(Option<WuselId>, u8)>, // other partner optional
     life: Life,
+    #[serde(default)]
+    cause_of_death: Option<CauseOfDeath>,
     lived_days: u32,
+    life_stage: stages::LifeStage, // grown up by the lived days (see stages::LifeStages).
     needs: std::collections::HashMap<needs::Need, u32>,
-    abilities: std::collections::HashMap<abilities::Ability, u32>,
+    abilities: std::collections::HashMap<abilities::Ability, abilities::Skill>,
     tasklist: Vec<tasks::Task>,
 }
 
@@ -83,21 +138,25 @@ impl std::fmt::Display for Wusel {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             fmt,
-            "{}: {} (days: {}, status: {:?}, gender: {:?})",
-            self.id, self.name, self.lived_days, self.life, self.gender,
+            "{}: {} (days: {}, stage: {:?}, status: {:?}, gender: {:?})",
+            self.id, self.name, self.lived_days, self.life_stage, self.life, self.gender,
         )
     }
 }
 
 impl Wusel {
+    /// Create a new (grown up) wusel.
     pub fn new(id: WuselId, name: String, gender: WuselGender) -> Self {
         let mut new = Self {
             id,
             name,
             gender,
+            reproduction: Reproduction::typical_for(gender),
             pregnancy: None,
             life: Life::ALIVE,
+            cause_of_death: None,
             lived_days: 0,
+            life_stage: stages::LifeStage::Adult,
             needs: std::collections::HashMap::new(),
             abilities: std::collections::HashMap::new(),
             tasklist: vec![],
@@ -110,6 +169,18 @@ impl Wusel {
         new
     }
 
+    /// Create a newborn wusel: Without any abilities, hungry and sleepy.
+    pub fn new_born(id: WuselId, name: String, gender: WuselGender) -> Self {
+        let mut new = Self::new(id, name, gender);
+        new.life_stage = stages::LifeStage::Baby;
+
+        for need in NEWBORN_HALF_NEEDS.iter() {
+            new.set_need(*need, need.get_full() / 2);
+        }
+
+        new
+    }
+
     pub fn get_id(&self) -> WuselId {
         self.id
     }
@@ -118,15 +189,56 @@ impl Wusel {
         matches!(self.life, Life::ALIVE)
     }
 
+    pub fn is_ghost(&self) -> bool {
+        matches!(self.life, Life::GHOST)
+    }
+
+    /// Check if the wusel may still do something: If they are alive or a ghost.
+    pub fn can_act(&self) -> bool {
+        !matches!(self.life, Life::DEAD)
+    }
+
     pub fn get_lived_days(&self) -> u32 {
         self.lived_days
     }
 
+    /// Set the days, the wusel lived, without growing them up (see [Wusel::set_life_stage]).
+    pub fn set_lived_days(&mut self, lived_days: u32) {
+        self.lived_days = lived_days;
+    }
+
+    pub fn get_life_stage(&self) -> stages::LifeStage {
+        self.life_stage
+    }
+
+    pub fn set_life_stage(&mut self, life_stage: stages::LifeStage) {
+        self.life_stage = life_stage;
+    }
+
+    pub fn get_life_state(&self) -> Life {
+        self.life
+    }
+
+    /// Set the life state, a wusel who dies like this dies of unknown causes.
     pub fn set_life_state(&mut self, life_state: Life) -> bool {
+        if life_state == Life::DEAD && self.cause_of_death.is_none() {
+            self.cause_of_death = Some(CauseOfDeath::Unknown);
+        }
         self.life = life_state;
         self.is_alive()
     }
 
+    /// Let the wusel die of the cause.
+    pub fn die(&mut self, cause: CauseOfDeath) {
+        self.life = Life::DEAD;
+        self.cause_of_death = Some(cause);
+    }
+
+    /// Get why the wusel died, if they are dead (or a ghost).
+    pub fn get_cause_of_death(&self) -> Option<CauseOfDeath> {
+        self.cause_of_death
+    }
+
     pub fn get_name(&self) -> String {
         self.name.clone()
     }
@@ -143,19 +255,32 @@ impl Wusel {
         self.gender = new_gender;
     }
 
+    pub fn get_reproduction(&self) -> Reproduction {
+        self.reproduction
+    }
+
+    pub fn set_reproduction(&mut self, reproduction: Reproduction) {
+        self.reproduction = reproduction;
+    }
+
     /// Tick one unit.
     /// Reduce the satisfaction of each needs by default values.
     /// Maybe let it age one day.
     /// @return if the wusel is still alive in the end.
     pub fn wusel_tick(&mut self, add_day: bool) -> bool {
+        // The dead and ghosts do not change anymore.
+        if !self.is_alive() {
+            return false;
+        }
+
         let is_ill = false;
         let in_cold_environment = false;
 
-        let mut life_state = Life::ALIVE;
+        let mut cause_of_death: Option<CauseOfDeath> = None;
 
         // Decrease every value by DEFAULT_NEED_DECAY_PER_MINUTE * minutes.
         for (need, value) in self.needs.iter_mut() {
-            let mut decay = need.get_default_decay();
+            let mut decay = need.get_default_decay() * self.life_stage.get_need_decay_factor(*need);
 
             if is_ill {
                 // XXX when SICK: decay health
@@ -170,12 +295,12 @@ impl Wusel {
             *value = value.saturating_sub(decay);
 
             if *value < 1 && need.is_fatal() {
-                life_state = Life::DEAD;
+                cause_of_death = Some(CauseOfDeath::Need(*need));
             }
         }
 
-        if life_state != Life::ALIVE {
-            self.life = life_state;
+        if let Some(cause) = cause_of_death {
+            self.die(cause);
         }
 
         if add_day {
@@ -189,8 +314,8 @@ impl Wusel {
         if self.is_alive() {
             self.lived_days += 1;
 
-            for (_, value) in self.abilities.iter_mut() {
-                *value = value.saturating_sub(1);
+            for (_, skill) in self.abilities.iter_mut() {
+                skill.pass_day();
             }
 
             if let Some((other_parent, days)) = self.pregnancy {
@@ -218,17 +343,35 @@ impl Wusel {
         self.set_need(need, changed as u32) // change the value.
     }
 
+    /// Get the level of the ability.
     pub fn get_ability(&self, ability: abilities::Ability) -> u32 {
-        *self.abilities.get(&ability).unwrap_or(&0u32)
+        self.abilities
+            .get(&ability)
+            .map_or(0, |skill| skill.get_level())
     }
 
+    /// Set the level of the ability, as if it was just reached.
+    /// @return the old level.
     pub fn set_ability(&mut self, ability: abilities::Ability, new_value: u32) -> u32 {
-        self.abilities.insert(ability, new_value).unwrap_or(0u32)
+        self.abilities
+            .insert(ability, abilities::Skill::with_level(new_value))
+            .map_or(0, |skill| skill.get_level())
+    }
+
+    pub fn get_ability_experience(&self, ability: abilities::Ability) -> u32 {
+        self.abilities
+            .get(&ability)
+            .map_or(0, |skill| skill.get_experience())
     }
 
-    pub fn improve(&mut self, ability: abilities::Ability) {
-        let value = *self.abilities.get(&ability).unwrap_or(&0u32);
-        self.abilities.insert(ability, value.saturating_add(1));
+    /// Improve the ability by practising it once, as fast as the life stage learns.
+    /// @return if a new level was reached.
+    pub fn improve(&mut self, ability: abilities::Ability) -> bool {
+        let experience = abilities::EXPERIENCE_PER_PRACTICE * self.life_stage.get_learning_rate();
+        self.abilities
+            .entry(ability)
+            .or_default()
+            .practise(experience)
     }
 
     pub fn has_tasklist_empty(&self) -> bool {
@@ -240,7 +383,7 @@ impl Wusel {
     }
 
     pub fn get_tasklist_names(&self) -> Vec<String> {
-        return self.tasklist.iter().map(|task| task.get_name()).collect();
+        self.tasklist.iter().map(|task| task.get_name()).collect()
     }
 
     pub fn assign_to_task(&mut self, init_time: usize, task_builder: tasks::TaskBuilder) {
@@ -264,8 +407,16 @@ impl Wusel {
         }
     }
 
+    /// Abort all tasks, which match the given expression (also the ongoing).
+    /// Returns how many tasks were aborted.
+    pub fn abort_tasks_with(&mut self, task_matcher: &dyn Fn(&tasks::Task) -> bool) -> usize {
+        let count = self.tasklist.len();
+        self.tasklist.retain(|task| !task_matcher(task));
+        count - self.tasklist.len()
+    }
+
     /// * Check if tasklist contains a task with a given passive part.
-    /// (supportive, not for the user.)
+    ///   (supportive, not for the user.)
     pub fn has_task_with(&self, task_tag: &tasks::TaskTag) -> bool {
         let index = self
             .get_next_task_index_with(&|task: &tasks::Task| task.get_passive_part() == *task_tag);
@@ -273,7 +424,7 @@ impl Wusel {
     }
 
     /// * Check if tasklist contains a matching the given expression.
-    /// (supportive, not for the user.)
+    ///   (supportive, not for the user.)
     pub fn get_next_task_index_with(
         &self,
         task_matcher: &dyn Fn(&tasks::Task) -> bool,
@@ -281,7 +432,7 @@ impl Wusel {
         self.tasklist
             .iter()
             .rev()
-            .position(|task| task_matcher(task))
+            .position(task_matcher)
             .map(|index| self.tasklist.len() - 1 - index) // re-reverse
     }
 
@@ -311,8 +462,16 @@ impl Wusel {
         }
     }
 
-    /// * Drop last task.
+    /// Update the pre-calculated route of the ongoing task.
     /// (supportive, not for the user.)
+    pub fn set_ongoing_task_route(&mut self, route: Option<tasks::Route>) {
+        if let Some(ongoing) = self.tasklist.last_mut() {
+            ongoing.set_route(route);
+        }
+    }
+
+    /// * Drop last task.
+    ///   (supportive, not for the user.)
     pub fn pop_ongoing_task(&mut self) -> Option<tasks::Task> {
         self.tasklist.pop()
     }
@@ -332,7 +491,7 @@ impl Wusel {
     }
 
     pub fn is_pregnant(&self) -> bool {
-        self.pregnancy != None
+        self.pregnancy.is_some()
     }
 
     pub fn set_pregnancy(
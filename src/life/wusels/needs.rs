@@ -4,7 +4,9 @@
 use crate::life;
 
 /// A need, the Wusel needs to satisfy to survive.
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, PartialOrd)]
+#[derive(
+    Debug, Copy, Clone, Eq, Hash, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
 pub enum Need {
     WATER,
     FOOD,
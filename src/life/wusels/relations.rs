@@ -1,5 +1,5 @@
 /// Pair of Wusels which may have a relation.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Relation {
     officially: String,    // officially known state (Friends, Spouse, etc..)
     friendship: i32,       // shared friendship between both.
@@ -51,8 +51,9 @@ impl std::fmt::Display for Relation {
             kinship = match self.kindred_distance {
                 -1 => "",
                 0 => " Self?",
-                1 => " Siblings|Parents|Kids",
-                _ => "Related",
+                1 => " Parents|Kids",
+                2 => " Siblings|Grandparents|Grandkids",
+                _ => " Related",
             }
         )
     }
@@ -82,4 +83,33 @@ impl Relation {
     pub fn update_friendship(&mut self, change: i32) {
         self.friendship += change;
     }
+
+    pub fn get_romance(&self) -> i32 {
+        self.romance
+    }
+
+    pub fn get_friendship(&self) -> i32 {
+        self.friendship
+    }
+
+    /// Get the blood relation: 1 for parents and their kids, more for further relatives.
+    /// Returns None, if they are not kindred.
+    pub fn get_kindred_distance(&self) -> Option<u32> {
+        match self.kindred_distance {
+            distance if distance < 0 => None,
+            distance => Some(distance as u32),
+        }
+    }
+
+    /// Set the blood relation, see [get_kindred_distance](Self::get_kindred_distance).
+    pub fn set_kindred_distance(&mut self, distance: u32) {
+        self.kindred_distance = u32::min(distance, i32::MAX as u32) as i32;
+        if self.officially == "Strangers" {
+            self.officially = String::from("Family");
+        }
+    }
+
+    pub fn get_officially(&self) -> &str {
+        &self.officially
+    }
 }
@@ -0,0 +1,119 @@
+//! # Life Stages
+//!
+//! A wusel grows up by the days they lived: From a baby to an elder.
+//! Each stage changes, how fast their needs decay, how fast they learn and what they may do.
+//! The days, when a stage begins, are given by the world (see [LifeStages]).
+//!
+//! ## Author
+//! Ngoc (Nox) Le <noxsense@gmail.com>
+
+use crate::life::wusels::needs;
+use crate::life::wusels::tasks;
+
+/// A stage of a wusel's life.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum LifeStage {
+    Baby,
+    Child,
+    Teen,
+    Adult,
+    Elder,
+}
+
+impl LifeStage {
+    pub const VALUES: [Self; 5] = [
+        Self::Baby,
+        Self::Child,
+        Self::Teen,
+        Self::Adult,
+        Self::Elder,
+    ];
+
+    /// How many times faster than default the need decays in this stage.
+    pub fn get_need_decay_factor(&self, need: needs::Need) -> u32 {
+        match (self, need) {
+            (Self::Baby, needs::Need::FOOD | needs::Need::SLEEP) => 2, // eat and sleep a lot.
+            (Self::Child, needs::Need::FOOD | needs::Need::FUN) => 2,  // grow and play a lot.
+            (Self::Teen, needs::Need::SLEEP) => 2,                     // sleep long.
+            (Self::Elder, needs::Need::SLEEP) => 2,                    // tire easily.
+            _ => 1,
+        }
+    }
+
+    /// How much an ability improves by practising it once in this stage.
+    pub fn get_learning_rate(&self) -> u32 {
+        match self {
+            Self::Child => 3,
+            Self::Teen => 2,
+            Self::Baby | Self::Adult | Self::Elder => 1,
+        }
+    }
+
+    /// Check if a wusel in this stage may do a task.
+    /// Babies cannot craft (eg. cook), handle doors or store things, only adults and elders romance.
+    pub fn may_do(&self, task_tag: &tasks::TaskTag) -> bool {
+        match task_tag {
+            tasks::TaskTag::Craft(_)
+            | tasks::TaskTag::UseDoor(_, _)
+            | tasks::TaskTag::StoreObject(_, _) => *self != Self::Baby,
+            tasks::TaskTag::Romance(_) => self.may_romance(),
+            _ => true,
+        }
+    }
+
+    /// Check if a wusel in this stage may romance (see [LifeStage::may_do]).
+    pub fn may_romance(&self) -> bool {
+        matches!(self, Self::Adult | Self::Elder)
+    }
+
+    /// Check if a wusel in this stage may conceive a child (carry or impregnate).
+    pub fn may_conceive(&self) -> bool {
+        *self == Self::Adult
+    }
+}
+
+/// The days lived, when each life stage begins (a wusel is born as baby),
+/// and when a wusel may die of old age.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LifeStages {
+    pub child: u32,
+    pub teen: u32,
+    pub adult: u32,
+    pub elder: u32,
+    pub old_age: u32, // from then on, every day may be the last.
+}
+
+impl Default for LifeStages {
+    fn default() -> Self {
+        Self {
+            child: 3,
+            teen: 10,
+            adult: 16,
+            elder: 60,
+            old_age: 80,
+        }
+    }
+}
+
+impl LifeStages {
+    /// Get the life stage of a wusel, who lived the given days.
+    pub fn stage_for(&self, lived_days: u32) -> LifeStage {
+        LifeStage::VALUES
+            .iter()
+            .rev()
+            .find(|&&stage| lived_days >= self.first_day_of(stage))
+            .copied()
+            .unwrap_or(LifeStage::Baby)
+    }
+
+    /// Get the first day of the life stage.
+    pub fn first_day_of(&self, stage: LifeStage) -> u32 {
+        match stage {
+            LifeStage::Baby => 0,
+            LifeStage::Child => self.child,
+            LifeStage::Teen => self.teen,
+            LifeStage::Adult => self.adult,
+            LifeStage::Elder => self.elder,
+        }
+    }
+}
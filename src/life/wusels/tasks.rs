@@ -42,25 +42,74 @@ impl TaskBuilder {
     }
 
     /// Create a new Task Builder, preset for meeting.
-    pub fn meet_with(passive: wusels::WuselId, friendly: bool, romantically: bool) -> Self {
+    pub fn meet_with(passive: wusels::WuselHandle, friendly: bool, romantically: bool) -> Self {
         Self {
             name: "Meeting".to_string(),
             duration: 1,
-            passive_part: TaskTag::MeetWith(passive, friendly, romantically),
+            passive_part: TaskTag::MeetWith(passive.get_id(), friendly, romantically),
+        }
+    }
+
+    /// Create a new Task Builder, preset for a romantic time, which may lead to a pregnancy.
+    pub fn romance_with(passive: wusels::WuselHandle) -> Self {
+        Self {
+            name: "Romancing".to_string(),
+            duration: 1,
+            passive_part: TaskTag::Romance(passive.get_id()),
         }
     }
 
     /// Create a new Task Builder, preset for working on a workbench.
-    pub fn use_object(object_id: objects::ObjectId, action_id: ActionId) -> Self {
+    pub fn use_object(object: objects::ObjectHandle, action_id: ActionId) -> Self {
+        Self {
+            name: format!("Use[{}] Object[{:?}]", action_id, object.get_id()),
+            duration: 1,
+            passive_part: TaskTag::UseObject(object.get_id(), action_id),
+        }
+    }
+
+    /// Create a new Task Builder, preset for putting an object into a storage.
+    pub fn store_object(object: objects::ObjectHandle, storage: objects::ObjectHandle) -> Self {
+        Self {
+            name: format!(
+                "Store Object[{:?}] in [{:?}]",
+                object.get_id(),
+                storage.get_id()
+            ),
+            duration: 1,
+            passive_part: TaskTag::StoreObject(object.get_id(), storage.get_id()),
+        }
+    }
+
+    /// Create a new Task Builder, preset for opening, closing or (un)locking a door.
+    pub fn use_door(door_id: world::items::ConstructionId, door_action: DoorAction) -> Self {
         Self {
-            name: format!("Use[{}] Object[{:?}]", action_id, object_id),
+            name: format!("{:?} Door[{}]", door_action, door_id),
             duration: 1,
-            passive_part: TaskTag::UseObject(object_id, action_id),
+            passive_part: TaskTag::UseDoor(door_id, door_action),
+        }
+    }
+
+    /// Create a new Task Builder, preset for resting (to recover sleep).
+    pub fn rest(duration: usize) -> Self {
+        Self {
+            name: "Resting".to_string(),
+            duration,
+            passive_part: TaskTag::Rest,
+        }
+    }
+
+    /// Create a new Task Builder, preset for crafting a product of a blueprint in its steps.
+    pub fn craft(blueprint_id: world::items::BlueprintId, steps: usize) -> Self {
+        Self {
+            name: format!("Craft[{}]", blueprint_id),
+            duration: steps,
+            passive_part: TaskTag::Craft(blueprint_id),
         }
     }
 
-    /// Create a new Task Builder, preset for being met.
-    pub fn be_met_from(active: wusels::WuselId) -> Self {
+    /// Create a new Task Builder, preset for being met (only given by the world to the met wusel).
+    pub(crate) fn be_met_from(active: wusels::WuselId) -> Self {
         Self {
             name: "Being Met".to_string(),
             duration: 1,
@@ -110,6 +159,8 @@ impl TaskBuilder {
 
             active_actor_id: actor.get_id(),
             passive_part: self.passive_part,
+
+            route: None,
         }
     }
 }
@@ -118,22 +169,51 @@ impl TaskBuilder {
 ///
 /// Such as social interactions, object interactions or walking situations.
 /// Also for just very tasks such as waiting or reading.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum TaskTag {
     WaitLike,
     MoveToPos(world::areas::Position),
 
     UseObject(objects::ObjectId, ActionId), // object_id, and action_id
+    StoreObject(objects::ObjectId, objects::ObjectId), // object_id, and storage_id
 
     MeetWith(wusels::WuselId, bool, bool), // commute with another wusel (ID)
     BeMetFrom(wusels::WuselId),            // be met by another wusel (ID)
+    Romance(wusels::WuselId),              // romantically meet another wusel (ID), maybe conceive.
+
+    UseDoor(world::items::ConstructionId, DoorAction), // door_id, and what to do with it
+
+    Rest, // recover sleep, wherever the wusel is.
+
+    Craft(world::items::BlueprintId), // craft the product of the blueprint (ID).
+}
+
+impl TaskTag {
+    /// Get the other wusel, the task is done with (like a meeting), if there is one.
+    pub fn get_other_wusel(&self) -> Option<wusels::WuselId> {
+        match self {
+            Self::MeetWith(other_id, _, _)
+            | Self::BeMetFrom(other_id)
+            | Self::Romance(other_id) => Some(*other_id),
+            _ => None,
+        }
+    }
+}
+
+/// What a wusel can do with a door.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum DoorAction {
+    Open,
+    Close,
+    Lock,
+    Unlock,
 }
 
 /// Task, a Wusel can do.
 ///
 /// A task can contain multiple steps. This task struct also is stateful and
 /// keeps track of its progress.
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct Task {
     name: String,
     started: bool,
@@ -143,6 +223,8 @@ pub struct Task {
 
     active_actor_id: wusels::WuselId, // wusel ID.
     passive_part: TaskTag,            // position | object-to-be | object | wusel | nothing.
+
+    route: Option<Route>, // pre-calculated path, if the task needs walking.
 }
 
 impl Task {
@@ -193,13 +275,93 @@ impl Task {
     pub fn get_passive_part(&self) -> TaskTag {
         self.passive_part.clone()
     }
+
+    /// Get the pre-calculated route of the task, if the task involved walking yet.
+    pub fn get_route(&self) -> Option<&Route> {
+        self.route.as_ref()
+    }
+
+    /// Set or unset the pre-calculated route of this task.
+    pub fn set_route(&mut self, route: Option<Route>) {
+        self.route = route;
+    }
+}
+
+/// Pre-calculated walking route towards a goal.
+///
+/// The steps are stored like the tasklist: the next step is the last one.
+/// The route also remembers the world's topology revision it was checked against,
+/// to only check it again, when the world changed.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Route {
+    goal: world::areas::Position,
+    steps: Vec<world::areas::Position>,
+    revision: usize,
+    waited: usize, // ticks waited for a taken next step.
 }
 
-// TODO (2021-11-21) improve type
-/// Relation between the usage of an [Object](objects::ObjectId) and [Wusel Needs](wusel::Need).
-pub type ActionAffect = (
-    objects::ObjectId,               // affected object
-    usize,                           // object subtype
-    &'static str,                    // name
-    Vec<(wusels::needs::Need, i16)>, // affected needs
-);
+impl Route {
+    /// Create a new route from the steps given in walking order.
+    pub fn new(
+        goal: world::areas::Position,
+        mut steps: Vec<world::areas::Position>,
+        revision: usize,
+    ) -> Self {
+        steps.reverse(); // next step last.
+        Self {
+            goal,
+            steps,
+            revision,
+            waited: 0,
+        }
+    }
+
+    /// Get the position this route was calculated for.
+    pub fn get_goal(&self) -> world::areas::Position {
+        self.goal
+    }
+
+    /// Get the topology revision, this route was (last) checked against.
+    pub fn get_revision(&self) -> usize {
+        self.revision
+    }
+
+    /// Mark the route as checked against the given topology revision.
+    pub fn set_revision(&mut self, revision: usize) {
+        self.revision = revision;
+    }
+
+    /// Get how many ticks the walker already waited for their next step.
+    pub fn get_waited(&self) -> usize {
+        self.waited
+    }
+
+    /// Wait another tick for the next step.
+    pub fn wait(&mut self) -> usize {
+        self.waited += 1;
+        self.waited
+    }
+
+    /// Check if all remaining steps (but the goal) are still passable.
+    pub fn is_walkable(&self, is_passable: &dyn Fn(&world::areas::Position) -> bool) -> bool {
+        self.steps
+            .iter()
+            .all(|step| *step == self.goal || is_passable(step))
+    }
+
+    /// Get the count of the remaining steps.
+    pub fn get_steps_left(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Get the next step without walking it.
+    pub fn peek_next_step(&self) -> Option<world::areas::Position> {
+        self.steps.last().copied()
+    }
+
+    /// Walk the next step: Remove it from the route, the wait for it is over.
+    pub fn pop_next_step(&mut self) -> Option<world::areas::Position> {
+        self.waited = 0;
+        self.steps.pop()
+    }
+}
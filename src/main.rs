@@ -7,6 +7,7 @@
 //! ## Author
 //! Ngoc (Nox) Le <noxsense@gmail.com>
 
+pub mod cli;
 pub mod life;
 pub mod tui;
 pub mod util;
@@ -15,57 +16,288 @@ pub mod util;
 fn main() -> Result<(), std::io::Error> {
     env_logger::init(); // initiate the logger.
 
-    let args: Vec<String> = std::env::args().collect();
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    let iterations: usize = match args.get(1) {
-        Some(arg_str) => arg_str.parse().unwrap_or(10) as usize,
-        None => 10usize,
+    let mut options = match cli::Options::parse(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            std::process::exit(2);
+        }
     };
 
-    let arg_steps_per_second: u64 = match args.get(2) {
-        Some(arg_str) => arg_str.parse().unwrap_or(4),
-        None => 8,
+    if options.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // A replay shows the recorded world for the recorded ticks.
+    let started = match options.recording.clone() {
+        Some(path) => life::world::save::Recording::load(&path)
+            .and_then(|recording| {
+                options.iterations = recording.get_ticks();
+                recording.get_start()
+            })
+            .map_err(|error| format!("Cannot replay '{}': {}", path.display(), error)),
+        None => create_world(&options),
     };
 
-    let render: bool = match args.get(3) {
-        Some(arg_str) => arg_str != "no-render",
-        None => true,
+    let mut world = match started {
+        Ok(world) => world,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
     };
 
-    let clear_on_exit: bool = match args.get(4) {
-        Some(arg_str) => arg_str == "clear",
-        None => false,
+    if options.level >= world.get_height() {
+        eprintln!(
+            "Invalid value '{}' for --level: the world has only {} level(s).\n\n{}",
+            options.level,
+            world.get_height(),
+            cli::USAGE
+        );
+        std::process::exit(2);
+    }
+
+    let mut recording = match &options.record {
+        Some(_) => Some(life::world::save::Recording::start(&world)?),
+        None => None,
     };
 
-    return run(iterations, arg_steps_per_second, render, clear_on_exit);
+    match options.mode {
+        cli::Mode::Inspect => inspect(&mut world),
+        cli::Mode::Headless => run_headless(&mut world, &options, recording.as_mut()),
+        cli::Mode::Run | cli::Mode::Replay => run(&mut world, &options, recording.as_mut())?,
+    }
+
+    if let Some(path) = &options.save {
+        world.save(path)?;
+    }
+
+    if let (Some(path), Some(recording)) = (&options.record, &recording) {
+        recording.save(path)?;
+    }
+
+    Ok(())
 }
 
-fn run(
-    iterations: usize,
-    arg_steps_per_second: u64,
-    render: bool,
-    clear_on_exit: bool,
-) -> Result<(), std::io::Error> {
-    //clear on start.
-    tui::screen::render::clear_all();
+/// Scenario, that is played, if no other is given.
+const EXAMPLE_SCENARIO: &str = include_str!("../scenarios/example.json");
+
+/// Create the world from the save, the scenario or the example scenario.
+/// On failure, explain which file could not be used and why.
+fn create_world(options: &cli::Options) -> Result<life::world::World, String> {
+    if let Some(path) = &options.load {
+        let mut world = life::world::World::load(path)
+            .map_err(|error| format!("Cannot load '{}': {}", path.display(), error))?;
+        if let Some(seed) = options.seed {
+            world.set_seed(seed);
+        }
+        return Ok(world);
+    }
 
-    let (screen_width, screen_height) = match termion::terminal_size() {
-        Ok((w, h)) => (w, h),
-        Err(e) => return Err(e),
+    let source = match &options.scenario {
+        Some(path) => format!("scenario '{}'", path.display()),
+        None => "the example scenario".to_string(),
     };
 
-    let width: u32 = screen_width as u32 - (2 * 3);
-    let height: u32 = (screen_height as u32) - (2 * 3) - 8; // minus gap for time.
-
-    let mut world: life::world::World = life::world::World::new(width, height);
+    let world = match &options.scenario {
+        Some(path) => life::world::scenario::Scenario::load(path),
+        None => life::world::scenario::Scenario::parse(EXAMPLE_SCENARIO),
+    }
+    .and_then(|mut scenario| {
+        scenario.set_size(options.width, options.depth, options.height);
+        scenario.set_default_size(cli::DEFAULT_WIDTH, cli::DEFAULT_DEPTH);
+        scenario.seed = options.seed.or(scenario.seed);
+        scenario.build()
+    })
+    .map_err(|error| format!("Cannot start {}: {}", source, error))?;
     log::debug!(
-        "Created a new world: w:{w}, h:{h}",
+        "Created a new world: w:{w}, d:{d}, h:{h}",
         w = world.get_width(),
-        h = world.get_depth()
+        d = world.get_depth(),
+        h = world.get_height()
     );
+    Ok(world)
+}
+
+/// Print a summary of the world, without letting time pass.
+fn inspect(world: &mut life::world::World) {
+    let (width, depth, height) = world.get_dimensions();
+    println!("World: {} x {} x {}", width, depth, height);
+    println!("Time: {}", world.get_time());
+    println!("Seed: {}", world.get_seed());
+
+    println!("Wusels: {}", world.wusel_count());
+    for wusel in world.wusel_get_all_alive() {
+        println!(
+            "* {name} (w{wusel}, {gender}) at {position:?}",
+            name = world
+                .wusel_get_name(wusel)
+                .unwrap_or_else(|| "No Name".to_string()),
+            gender = world
+                .wusel_get_gender(wusel)
+                .unwrap_or(life::wusels::WuselGender::Undefined)
+                .to_char(),
+            position = world.wusel_get_position(wusel),
+        );
+        let needs: Vec<String> = life::wusels::needs::Need::VALUES
+            .iter()
+            .map(|&need| format!("{}: {}", need.get_name(), world.wusel_get_need(wusel, need)))
+            .collect();
+        println!("  * needs: {}", needs.join(", "));
+        println!(
+            "  * tasks: {}",
+            world.wusel_get_tasklist_names(wusel).join(", ")
+        );
+    }
+
+    let objects = world.object_get_all();
+    println!("Objects: {}", objects.len());
+    for object in objects {
+        println!(
+            "* {name} ({object_type:?}) at {position:?}",
+            name = world.object_get_name(object).unwrap_or_default(),
+            object_type = world.object_get_type(object),
+            position = world.object_get_position(object),
+        );
+        if let Some(age) = world.object_get_age(object) {
+            println!(
+                "  * age: {} days{}",
+                age,
+                if world.object_is_spoiled(object) {
+                    " (spoiled)"
+                } else {
+                    ""
+                }
+            );
+        }
+        if let Some(storage) = world.object_get_storage(object) {
+            println!("  * stored in: {}", storage);
+        }
+        if let Some(holder) = world.object_get_holder(object) {
+            println!("  * held by: {}", holder);
+        }
+        let contents = world.object_list_contents(object);
+        if !contents.is_empty() {
+            println!(
+                "  * contains: {:?}",
+                contents
+                    .iter()
+                    .map(|content| content.get_id())
+                    .collect::<Vec<_>>()
+            );
+        }
+        for advertisement in world.object_get_advertisements(object) {
+            let satisfies: Vec<&str> = life::wusels::needs::Need::VALUES
+                .iter()
+                .filter(|need| advertisement.satisfies(**need))
+                .map(|need| need.get_name())
+                .collect();
+            println!(
+                "  * action {action}: satisfies {satisfies}",
+                action = advertisement.get_action_id(),
+                satisfies = satisfies.join(", "),
+            );
+        }
+    }
+
+    let blueprints = world.blueprint_get_all();
+    println!("Blueprints: {}", blueprints.len());
+    for (blueprint_id, blueprint) in blueprints.iter().enumerate() {
+        println!(
+            "* [{blueprint_id}] {name}: {components:?} at {workstation:?} in {steps} steps",
+            name = blueprint.get_name(),
+            components = blueprint.get_components(),
+            workstation = blueprint.get_workstation(),
+            steps = blueprint.get_steps(),
+        );
+    }
+
+    let doors = world.door_get_all();
+    println!("Doors: {}", doors.len());
+    for door_id in doors {
+        println!(
+            "* door {door_id} at {position:?}: open: {open}, locked: {locked}, owner: {owner:?}",
+            position = world.door_get_position(door_id),
+            open = world.door_is_open(door_id).unwrap_or(false),
+            locked = world.door_is_locked(door_id).unwrap_or(false),
+            owner = world.door_get_owner(door_id).map(|owner| owner.get_id()),
+        );
+    }
+
+    let rooms = world.room_get_all();
+    println!("Rooms: {}", rooms.len());
+    for room_id in rooms {
+        println!(
+            "* {name} (r{room_id}): {size} positions, wusels: {wusels:?}, objects: {objects:?}",
+            name = world.room_get_name(room_id).unwrap_or_default(),
+            size = world.room_get_positions(room_id).len(),
+            wusels = world
+                .room_get_wusels(room_id)
+                .iter()
+                .map(|wusel| wusel.get_id())
+                .collect::<Vec<_>>(),
+            objects = world
+                .room_get_objects(room_id)
+                .iter()
+                .map(|object| object.get_id())
+                .collect::<Vec<_>>(),
+        );
+    }
+}
+
+/// Let the wusels live without rendering, print the world state per tick.
+/// The ticks are counted to the recording, if the simulation is recorded.
+fn run_headless(
+    world: &mut life::world::World,
+    options: &cli::Options,
+    mut recording: Option<&mut life::world::save::Recording>,
+) {
+    println!("Seed: {}", world.get_seed());
+
+    for _ in 0usize..options.iterations {
+        println!("World Time: {}", world.get_time());
+        for &wusel in world.wusel_get_all_alive().iter() {
+            println!(
+                "* {wusel_name} (w{wusel})",
+                wusel_name = world
+                    .wusel_get_name(wusel)
+                    .unwrap_or_else(|| "No Name".to_string()),
+            );
+
+            print!("  * tasks: ");
+            for task in world.wusel_get_tasklist_names(wusel).iter() {
+                print!(" {task}, ");
+            }
+            println!("...")
+        }
+        println!();
+
+        world.tick();
+        if let Some(recording) = recording.as_mut() {
+            recording.record_tick();
+        }
 
-    setup_world_example(&mut world);
+        for (time, event) in world.events_take() {
+            println!("Event at {}: {}", time, event);
+        }
+    }
+}
+
+/// Watch the wusels live in the terminal.
+/// The ticks are counted to the recording, if the simulation is recorded.
+fn run(
+    world: &mut life::world::World,
+    options: &cli::Options,
+    mut recording: Option<&mut life::world::save::Recording>,
+) -> Result<(), std::io::Error> {
+    let iterations = options.iterations;
 
+    let (screen_width, screen_height) = termion::terminal_size()?;
+
+    //clear on start.
     tui::screen::render::clear_all();
 
     // Draw the field and make some real automation.
@@ -89,146 +321,98 @@ fn run(
 
     // frame game field
     let frame_colour = termion::color::Rgb(100, 100, 100);
-    if render {
-        tui::screen::render::rectangle(
-            &tui::screen::Pos { x: 1, y: 1 },
-            &tui::screen::Pos {
-                x: w as u16 + 2,
-                y: h as u16 + 2,
-            },
-            &format!("{}-", termion::color::Fg(frame_colour)),
-            &format!("{}|", termion::color::Fg(frame_colour)),
-            &format!("{}+", termion::color::Fg(frame_colour)),
-        );
+    tui::screen::render::rectangle(
+        &tui::screen::Pos { x: 1, y: 1 },
+        &tui::screen::Pos {
+            x: w as u16 + 2,
+            y: h as u16 + 2,
+        },
+        &format!("{}-", termion::color::Fg(frame_colour)),
+        &format!("{}|", termion::color::Fg(frame_colour)),
+        &format!("{}+", termion::color::Fg(frame_colour)),
+    );
 
-        // frame need panel
-        let yellow = termion::color::Rgb(255, 255, 0);
-        tui::screen::render::rectangle(
-            &tui::screen::Pos {
-                x: need_panel_position.x - 1,
-                y: need_panel_position.y - 1,
-            },
-            &tui::screen::Pos {
-                x: need_panel_position.x + 9 + need_bar_width,
-                y: need_panel_position.y + 7,
-            },
-            &format!("{}-", termion::color::Fg(yellow)),
-            &format!("{}|", termion::color::Fg(yellow)),
-            &format!("{}+", termion::color::Fg(yellow)),
-        );
-    }
+    // frame need panel
+    let yellow = termion::color::Rgb(255, 255, 0);
+    tui::screen::render::rectangle(
+        &tui::screen::Pos {
+            x: need_panel_position.x - 1,
+            y: need_panel_position.y - 1,
+        },
+        &tui::screen::Pos {
+            x: need_panel_position.x + 9 + need_bar_width,
+            y: need_panel_position.y + 7,
+        },
+        &format!("{}-", termion::color::Fg(yellow)),
+        &format!("{}|", termion::color::Fg(yellow)),
+        &format!("{}+", termion::color::Fg(yellow)),
+    );
 
     // time od the simulation.
-    let steps_per_second = arg_steps_per_second;
+    let steps_per_second = options.steps_per_second;
     let step_sleep = std::time::Duration::from_millis(1000 / steps_per_second);
 
     for i in 0usize..iterations {
-        if render {
-            // world.positions_recalculate_grid();
-            tui::world_view::render_field(w, world.positions_for_all_placetakers());
-
-            // Tick the world, show time.
-            tui::world_view::render_time(time_position, i, world.get_time());
-            tui::screen::render::progres_bar(
-                timebar_position,
-                h as u16 + 3,
-                false,
-                iterations as u32,
-                i as u32 + 1,
-                None,
-                false,
-            );
+        // world.positions_recalculate_grid();
+        tui::world_view::render_field(w, world.positions_for_level(options.level));
+
+        // Tick the world, show time.
+        tui::world_view::render_time(time_position, i, world.get_time());
+        tui::screen::render::progres_bar(
+            timebar_position,
+            h as u16 + 3,
+            false,
+            iterations as u32,
+            i as u32 + 1,
+            None,
+            false,
+        );
 
-            // Draw selected wusel's needs (right position below field).
-
-            for (wusel_offset, &wusel_id) in world.wusel_get_all_alive().iter().enumerate() {
-                // TODO
-
-                let x_offset = wusel_offset as u16 * 23;
-
-                if need_panel_position.x + x_offset + 20 < screen_width {
-                    tui::screen::render::cursor_set(
-                        &(*need_panel_position + (x_offset, 0u16) - (0u16, 2u16)),
-                    );
-                    print!(
-                        "| {} ({})",
-                        world
-                            .wusel_get_name(wusel_id as usize)
-                            .unwrap_or_else(|| "No Name".to_string()),
-                        world
-                            .wusel_get_gender(wusel_id as usize)
-                            .unwrap_or(life::wusels::WuselGender::Undefined)
-                            .to_char(),
-                    );
-
-                    tui::world_view::render_wusel_tasklist(
-                        *need_panel_position + (x_offset, 0u16) - (0, 1),
-                        world.wusel_get_tasklist_names(wusel_id as usize),
-                    );
-
-                    let needs: Vec<(life::wusels::needs::Need, u32, u32)> =
-                        life::wusels::needs::Need::VALUES
-                            .iter()
-                            .map(|need| {
-                                (
-                                    *need,
-                                    need.get_full(),
-                                    world.wusel_get_need(wusel_id, *need),
-                                )
-                            })
-                            .collect();
-
-                    tui::world_view::render_wusel_need_bar(
-                        *need_panel_position + (x_offset, 0u16),
-                        need_bar_width,
-                        need_panel_show_percentage,
-                        needs,
-                    );
-                }
-            }
-        } else {
-            println!("World Time: {}", world.get_time());
-            for (_, &wusel_id) in world.wusel_get_all_alive().iter().enumerate() {
-                println!(
-                    "* {wusel_name} (w{wusel_id})",
-                    wusel_name = world
-                        .wusel_get_name(wusel_id as usize)
+        // Draw selected wusel's needs (right position below field).
+
+        for (wusel_offset, &wusel) in world.wusel_get_all_alive().iter().enumerate() {
+            // TODO
+
+            let x_offset = wusel_offset as u16 * 23;
+
+            if need_panel_position.x + x_offset + 20 < screen_width {
+                tui::screen::render::cursor_set(
+                    &(*need_panel_position + (x_offset, 0u16) - (0u16, 2u16)),
+                );
+                print!(
+                    "| {} ({})",
+                    world
+                        .wusel_get_name(wusel)
                         .unwrap_or_else(|| "No Name".to_string()),
+                    world
+                        .wusel_get_gender(wusel)
+                        .unwrap_or(life::wusels::WuselGender::Undefined)
+                        .to_char(),
                 );
 
-                print!("  * tasks: ");
-                for task in world.wusel_get_tasklist_names(wusel_id as usize).iter() {
-                    print!(" {task}, ");
-                }
-                println!("...")
+                tui::world_view::render_wusel_tasklist(
+                    *need_panel_position + (x_offset, 0u16) - (0, 1),
+                    world.wusel_get_tasklist_names(wusel),
+                );
+
+                let needs: Vec<(life::wusels::needs::Need, u32, u32)> =
+                    life::wusels::needs::Need::VALUES
+                        .iter()
+                        .map(|need| (*need, need.get_full(), world.wusel_get_need(wusel, *need)))
+                        .collect();
+
+                tui::world_view::render_wusel_need_bar(
+                    *need_panel_position + (x_offset, 0u16),
+                    need_bar_width,
+                    need_panel_show_percentage,
+                    needs,
+                );
             }
-            println!("");
         }
 
         world.tick();
-
-        // Give some unbusy wusels the task to move around.
-        let unbusy = world.wusel_get_all_unbusy();
-        let wusel_len = world.wusel_count();
-        for widx in unbusy {
-            let r = rand::random::<usize>() % (4 * wusel_len);
-            match r {
-                i if i < wusel_len && i != widx => {
-                    // Meet randomly with someone: Let [widx] meet [i], if i in [0..|w|).
-                    world.wusel_assign_to_task(
-                        widx,
-                        life::wusels::tasks::TaskBuilder::meet_with(i, true, true).set_duration(10),
-                    );
-                }
-                i if i >= wusel_len && i < 2 * wusel_len => {
-                    // Walk randomly somewhere, if i not an wusel index.
-                    world.wusel_assign_to_task(
-                        widx,
-                        life::wusels::tasks::TaskBuilder::move_to(world.position_random()),
-                    );
-                }
-                _ => {} // do nothing randomly.
-            }
+        if let Some(recording) = recording.as_mut() {
+            recording.record_tick();
         }
 
         std::thread::sleep(step_sleep); // wait.
@@ -240,51 +424,10 @@ fn run(
         });
     }
 
-    if clear_on_exit {
+    if options.clear_on_exit {
         tui::screen::render::reset(&tui::screen::Pos::START); // clear whole field.
     }
 
     tui::screen::render::cursor_set(&(tui::screen::Pos::START + (0u16, screen_height)));
     Ok(())
 }
-
-// mut world: life::world::World
-fn setup_world_example(world: &mut life::world::World) {
-    // create random wusels
-    for _ in 0..rand::random::<u8>() % 10 + 2 {
-        world.wusel_new_random(util::more_strings::name_gen(
-            rand::random::<usize>() % 13 + 2,
-        ));
-    }
-
-    // Transportable bibimbap (korean food)
-    let bibimbap = world.food_new("Bibimbap", 10);
-    let bibimbap_id = bibimbap;
-    world.object_set_position(bibimbap_id, world.position_random());
-
-    // create fixed construction
-    world.construction_new(
-        life::world::items::ConstructionType::Wall(life::world::items::WALL_UD, 10),
-        life::world::areas::Position { x: 10, y: 10, z: 0 },
-    );
-
-    world.construction_new(
-        life::world::items::ConstructionType::Wall(life::world::items::WALL_LR, 20),
-        life::world::areas::Position { x: 11, y: 19, z: 0 },
-    );
-
-    world.construction_new(
-        life::world::items::ConstructionType::Wall(life::world::items::WALL_UD, 10),
-        life::world::areas::Position { x: 30, y: 10, z: 0 },
-    );
-
-    world.construction_new(
-        life::world::items::ConstructionType::Door(life::world::items::DOOR_OPEN),
-        life::world::areas::Position { x: 20, y: 10, z: 0 },
-    );
-
-    world.construction_new(
-        life::world::items::ConstructionType::Wall(life::world::items::WALL_LR, 20),
-        life::world::areas::Position { x: 10, y: 10, z: 0 },
-    );
-}
@@ -71,10 +71,10 @@ impl std::convert::From<(u16, u16)> for Pos {
     }
 }
 
-impl std::convert::Into<(u16, u16)> for Pos {
-    /// Create a new Screen Position from a tuple.
-    fn into(self) -> (u16, u16) {
-        (self.x, self.y)
+impl std::convert::From<Pos> for (u16, u16) {
+    /// Create a tuple from a Screen Position.
+    fn from(position: Pos) -> Self {
+        (position.x, position.y)
     }
 }
 
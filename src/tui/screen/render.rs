@@ -307,7 +307,7 @@ pub fn progres_bar(
     draw_horizontal: bool,
 ) -> f32 {
     let percentage_pre: f32 = current_value as f32 / max_value as f32 * 100f32;
-    let percentage: f32 = f32::min(100.0, f32::max(0.0, percentage_pre));
+    let percentage: f32 = percentage_pre.clamp(0.0, 100.0);
 
     progres_bar_from_percent(
         position,
@@ -8,6 +8,9 @@
 use crate::life;
 use crate::tui::screen;
 
+/// Background of indoor positions (with a floor).
+const FLOOR_COLOUR: screen::Rgb = screen::Rgb(240, 225, 200);
+
 /// Get a styled char for a placeholder in the world.
 fn get_render_for_position(
     c: Option<&life::world::PlaceTaker>,
@@ -45,6 +48,26 @@ fn get_render_for_position(
             _,
         )) => ('+', None, None, None), // construction, eg. wall
 
+        Some(life::world::PlaceTaker::Construction(
+            life::world::items::ConstructionType::Stairs(true),
+            _,
+        )) => ('^', None, None, None),
+
+        Some(life::world::PlaceTaker::Construction(
+            life::world::items::ConstructionType::Stairs(false),
+            _,
+        )) => ('v', None, None, None),
+
+        Some(life::world::PlaceTaker::Construction(
+            life::world::items::ConstructionType::Window,
+            _,
+        )) => ('=', Some(screen::Rgb(60, 120, 200)), None, None),
+
+        Some(life::world::PlaceTaker::Construction(
+            life::world::items::ConstructionType::Floor,
+            _,
+        )) => (' ', None, Some(FLOOR_COLOUR), None),
+
         Some(life::world::PlaceTaker::Object(_, life::objects::ObjectType::Furniture(_))) => {
             ('m', Some(screen::Rgb(99, 67, 14)), None, None)
         }
@@ -79,22 +102,39 @@ pub fn render_field(w: usize, positions: Vec<Vec<life::world::PlaceTaker>>) {
         x = (p % w) as u16 + 2;
         y = (p / w) as u16 + 2;
 
+        let is_floor = |p: &life::world::PlaceTaker| {
+            matches!(
+                p,
+                &life::world::PlaceTaker::Construction(
+                    life::world::items::ConstructionType::Floor,
+                    _
+                )
+            )
+        };
+
         let most_important = on_pos
             .iter()
             .find(|&p| {
                 matches!(
                     p,
                     &life::world::PlaceTaker::Construction(
-                        life::world::items::ConstructionType::Door(_),
+                        life::world::items::ConstructionType::Door(_)
+                            | life::world::items::ConstructionType::Window,
                         _
                     )
                 )
             })
-            .or_else(|| on_pos.get(0));
+            .or_else(|| on_pos.iter().find(|&p| !is_floor(p)))
+            .or_else(|| on_pos.first());
 
         let render_data = get_render_for_position(most_important);
 
-        let (render_char, render_fg, render_bg, render_styles) = render_data;
+        let (render_char, render_fg, mut render_bg, render_styles) = render_data;
+
+        // Things indoors are drawn on the floor.
+        if render_bg.is_none() && on_pos.iter().any(is_floor) {
+            render_bg = Some(FLOOR_COLOUR);
+        }
 
         // Draw position symbol.
         screen::render::spot(
@@ -116,10 +156,7 @@ pub fn render_field(w: usize, positions: Vec<Vec<life::world::PlaceTaker>>) {
 /// time .. time of the world.
 pub fn render_time(position: &screen::Pos, tick: usize, time: usize) {
     screen::render::cursor_set(position);
-    print!(
-        "{formatted_time}",
-        formatted_time = format!("Step Counter: {} => Time: {}", tick, time)
-    );
+    print!("Step Counter: {} => Time: {}", tick, time);
 }
 
 /// Render the task list of a wusel, given by string names.
@@ -7,35 +7,39 @@ const CONSONANTS: [char; 20] = [
     'z',
 ];
 
-fn random_vocal_or_constant(probability_of_vocal: usize) -> char {
-    if rand::random::<usize>() % 100 < probability_of_vocal {
+fn random_vocal_or_constant<R: rand::Rng + ?Sized>(
+    probability_of_vocal: usize,
+    rng: &mut R,
+) -> char {
+    if rng.gen_range(0..100) < probability_of_vocal {
         // pick vocal
-        VOCALS[rand::random::<usize>() % VOCALS.len()]
+        VOCALS[rng.gen_range(0..VOCALS.len())]
     } else {
         // pick consonant
-        CONSONANTS[rand::random::<usize>() % CONSONANTS.len()]
+        CONSONANTS[rng.gen_range(0..CONSONANTS.len())]
     }
 }
 
 /// Generate a name by using a capital alphabetic char
 /// and adding up to the given _lenght_ more chars.
 /// Vocals after Consonants should be more common than another Consonants.
-pub fn name_gen(length: usize) -> String {
+/// All chars are picked by the given random generator.
+pub fn name_gen<R: rand::Rng + ?Sized>(length: usize, rng: &mut R) -> String {
     // TODO (2021-12-11) better weighted chars. maybe markov chain. (also less visible.)
 
     let mut chars: Vec<char> = vec![
-        ((rand::random::<u8>() % 26) + b'A') as char, // first letter.
+        (rng.gen_range(0..26u8) + b'A') as char, // first letter.
     ];
 
     for i in 1usize..length {
         if VOCALS.contains(chars.get(i - 1).unwrap()) {
             // last char is VOCAL
             // => higher percentage of consonants (66%)
-            chars.push(random_vocal_or_constant(34usize));
+            chars.push(random_vocal_or_constant(34usize, rng));
         } else {
             // last char is constant.
             // => higher percentage of vocals (70%)
-            chars.push(random_vocal_or_constant(70usize));
+            chars.push(random_vocal_or_constant(70usize, rng));
         }
     }
 
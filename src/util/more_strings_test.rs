@@ -3,7 +3,8 @@
 #[test]
 fn test_random_names() {
     // property testing like.
+    let mut rng = rand::thread_rng();
     for size in 1usize..100 {
-        assert_eq!(size, super::more_strings::name_gen(size).len());
+        assert_eq!(size, super::more_strings::name_gen(size, &mut rng).len());
     }
 }